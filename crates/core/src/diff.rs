@@ -0,0 +1,931 @@
+//! Compatibility checks between two versions of the same interfaces.
+//!
+//! Changes are classified by whether they change the binary representation described in `docs/specs/ABI.md`,
+//! i.e. whether a guest and a host built from different versions can still talk to each other.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use wit_parser::{Event, Function, FunctionKind, Interface, Type, TypeDef, TypeDefKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compatibility {
+    /// Peers built from either version keep working together.
+    Compatible,
+    /// Peers built from different versions can no longer decode each others messages.
+    Breaking,
+}
+
+impl std::fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compatibility::Compatible => f.write_str("compatible"),
+            Compatibility::Breaking => f.write_str("breaking"),
+        }
+    }
+}
+
+/// A single difference between two versions of an interface.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Change {
+    pub compatibility: Compatibility,
+    /// The changed item, e.g. `interface::type` or `interface::resource::method`.
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}: {}", self.compatibility, self.path, self.message)
+    }
+}
+
+/// Compares two versions of a set of interfaces, interfaces are matched up by name.
+#[must_use]
+pub fn diff(old: &[Interface], new: &[Interface]) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for old in old {
+        match new.iter().find(|new| new.ident == old.ident) {
+            Some(new) => changes.extend(diff_interface(old, new)),
+            None => changes.push(Change {
+                compatibility: Compatibility::Breaking,
+                path: old.ident.clone(),
+                message: "interface was removed".to_string(),
+            }),
+        }
+    }
+
+    for new in new {
+        if !old.iter().any(|old| old.ident == new.ident) {
+            changes.push(Change {
+                compatibility: Compatibility::Compatible,
+                path: new.ident.clone(),
+                message: "interface was added".to_string(),
+            });
+        }
+    }
+
+    changes
+}
+
+/// Compares two versions of an interface.
+#[must_use]
+pub fn diff_interface(old: &Interface, new: &Interface) -> Vec<Change> {
+    let mut differ = Differ {
+        old,
+        new,
+        changes: Vec::new(),
+    };
+
+    differ.typedefs();
+    differ.functions(&old.ident, &old.functions, &new.functions);
+    differ.events(&old.ident, &old.events, &new.events);
+
+    differ.changes
+}
+
+/// What kind of members a type is made of, they differ in which additions are compatible.
+#[derive(Clone, Copy)]
+enum Members {
+    /// Record fields, no field can be added.
+    Fields,
+    /// Variant, enum and union cases, identified by their tag.
+    Cases,
+    /// Flags, identified by their bit.
+    Flags,
+}
+
+impl Members {
+    fn noun(self) -> &'static str {
+        match self {
+            Members::Fields => "field",
+            Members::Cases => "case",
+            Members::Flags => "flag",
+        }
+    }
+}
+
+struct Differ<'a> {
+    old: &'a Interface,
+    new: &'a Interface,
+    changes: Vec<Change>,
+}
+
+impl<'a> Differ<'a> {
+    fn push(&mut self, compatibility: Compatibility, path: &str, message: String) {
+        self.changes.push(Change {
+            compatibility,
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn typedefs(&mut self) {
+        let new_typedefs: HashMap<_, _> = self
+            .new
+            .typedefs
+            .iter()
+            .map(|(_, typedef)| (typedef.ident.as_str(), typedef))
+            .collect();
+
+        for (_, old) in self.old.typedefs.iter() {
+            let path = format!("{}::{}", self.old.ident, old.ident);
+
+            match new_typedefs.get(old.ident.as_str()) {
+                Some(new) => self.typedef(&path, old, new),
+                // functions that used the type changed too, and are reported on their own
+                None => self.push(
+                    Compatibility::Compatible,
+                    &path,
+                    "type was removed".to_string(),
+                ),
+            }
+        }
+
+        for (_, new) in self.new.typedefs.iter() {
+            if !self
+                .old
+                .typedefs
+                .iter()
+                .any(|(_, old)| old.ident == new.ident)
+            {
+                let path = format!("{}::{}", self.new.ident, new.ident);
+
+                self.push(
+                    Compatibility::Compatible,
+                    &path,
+                    "type was added".to_string(),
+                );
+            }
+        }
+    }
+
+    fn typedef(&mut self, path: &str, old: &TypeDef, new: &TypeDef) {
+        match (&old.kind, &new.kind) {
+            (TypeDefKind::Alias(old), TypeDefKind::Alias(new)) => {
+                if !self.wire_eq(old, new) {
+                    let message = format!(
+                        "type changed from {} to {}",
+                        self.old_name(old),
+                        self.new_name(new)
+                    );
+                    self.push(Compatibility::Breaking, path, message);
+                }
+            }
+            (TypeDefKind::Record(old), TypeDefKind::Record(new)) => {
+                let old = old.iter().map(|f| (f.id.as_str(), Some(&f.ty))).collect();
+                let new = new.iter().map(|f| (f.id.as_str(), Some(&f.ty))).collect();
+
+                self.members(path, Members::Fields, old, new);
+            }
+            (TypeDefKind::Variant(old), TypeDefKind::Variant(new)) => {
+                let old = old.iter().map(|c| (c.id.as_str(), c.ty.as_ref())).collect();
+                let new = new.iter().map(|c| (c.id.as_str(), c.ty.as_ref())).collect();
+
+                self.members(path, Members::Cases, old, new);
+            }
+            (TypeDefKind::Enum(old), TypeDefKind::Enum(new)) => {
+                let old = old.iter().map(|c| (c.id.as_str(), None)).collect();
+                let new = new.iter().map(|c| (c.id.as_str(), None)).collect();
+
+                self.members(path, Members::Cases, old, new);
+            }
+            (TypeDefKind::Flags(old), TypeDefKind::Flags(new)) => {
+                let old = old.iter().map(|f| (f.id.as_str(), None)).collect();
+                let new = new.iter().map(|f| (f.id.as_str(), None)).collect();
+
+                self.members(path, Members::Flags, old, new);
+            }
+            (TypeDefKind::Union(old), TypeDefKind::Union(new)) => self.union(path, old, new),
+            (TypeDefKind::Resource(old), TypeDefKind::Resource(new)) => {
+                self.functions(path, old, new);
+            }
+            (old, new) => {
+                let message = format!("type changed from {} to {}", kind_name(old), kind_name(new));
+                self.push(Compatibility::Breaking, path, message);
+            }
+        }
+    }
+
+    /// Compares the members of a record, variant, enum or flags, which are encoded in declaration order.
+    ///
+    /// Members are matched up by name, members at the same position whose names only exist in one version are renames.
+    fn members(
+        &mut self,
+        path: &str,
+        kind: Members,
+        old: Vec<(&str, Option<&Type>)>,
+        new: Vec<(&str, Option<&Type>)>,
+    ) {
+        let noun = kind.noun();
+        let old_has = |name: &str| old.iter().any(|(old, _)| *old == name);
+        let new_has = |name: &str| new.iter().any(|(new, _)| *new == name);
+
+        // the names old members have in the new version
+        let mut renamed: Vec<&str> = old.iter().map(|(name, _)| *name).collect();
+        for (i, ((old_name, _), (new_name, _))) in old.iter().zip(&new).enumerate() {
+            if old_name != new_name && !new_has(old_name) && !old_has(new_name) {
+                renamed[i] = new_name;
+
+                let message = format!("{noun} `{old_name}` was renamed to `{new_name}`");
+                self.push(Compatibility::Compatible, path, message);
+            }
+        }
+
+        let old_order: Vec<_> = renamed
+            .iter()
+            .copied()
+            .filter(|name| new.iter().any(|(new, _)| new == name))
+            .collect();
+        let new_order: Vec<_> = new
+            .iter()
+            .map(|(name, _)| *name)
+            .filter(|name| renamed.contains(name))
+            .collect();
+
+        if old_order != new_order {
+            let message = match kind {
+                Members::Fields => "fields were reordered".to_string(),
+                Members::Cases => "cases were reordered, which changes their tags".to_string(),
+                Members::Flags => "flags were reordered, which changes their bits".to_string(),
+            };
+            self.push(Compatibility::Breaking, path, message);
+        }
+
+        for (name, (_, old_ty)) in renamed.iter().zip(&old) {
+            let Some((_, new_ty)) = new.iter().find(|(new, _)| new == name) else {
+                let message = format!("{noun} `{name}` was removed");
+                self.push(Compatibility::Breaking, path, message);
+                continue;
+            };
+
+            let changed = match (old_ty, new_ty) {
+                (Some(old), Some(new)) => !self.wire_eq(old, new),
+                (None, None) => false,
+                _ => true,
+            };
+
+            if changed {
+                let message = format!(
+                    "{noun} `{name}` changed from {} to {}",
+                    old_ty.map_or_else(|| "no payload".to_string(), |ty| self.old_name(ty)),
+                    new_ty.map_or_else(|| "no payload".to_string(), |ty| self.new_name(ty)),
+                );
+                self.push(Compatibility::Breaking, path, message);
+            }
+        }
+
+        for (i, (name, _)) in new.iter().enumerate() {
+            if renamed.contains(name) {
+                continue;
+            }
+
+            let trailing = i >= old.len();
+            let (compatibility, message) = match kind {
+                // records aren't length prefixed, so neither side can skip or default a field
+                Members::Fields => (Compatibility::Breaking, format!("field `{name}` was added")),
+                Members::Cases | Members::Flags if trailing => (
+                    Compatibility::Compatible,
+                    format!("{noun} `{name}` was added"),
+                ),
+                Members::Cases => (
+                    Compatibility::Breaking,
+                    format!(
+                        "case `{name}` was inserted, which changes the tags of the following cases"
+                    ),
+                ),
+                Members::Flags => (
+                    Compatibility::Breaking,
+                    format!(
+                        "flag `{name}` was inserted, which changes the bits of the following flags"
+                    ),
+                ),
+            };
+            self.push(compatibility, path, message);
+        }
+
+        self.repr(path, kind, old.len(), new.len());
+    }
+
+    fn union(&mut self, path: &str, old: &[wit_parser::UnionCase], new: &[wit_parser::UnionCase]) {
+        for (i, (old, new)) in old.iter().zip(new).enumerate() {
+            if !self.wire_eq(&old.ty, &new.ty) {
+                let message = format!(
+                    "case {i} changed from {} to {}",
+                    self.old_name(&old.ty),
+                    self.new_name(&new.ty)
+                );
+                self.push(Compatibility::Breaking, path, message);
+            }
+        }
+
+        for old in old.iter().skip(new.len()) {
+            let message = format!("case {} was removed", self.old_name(&old.ty));
+            self.push(Compatibility::Breaking, path, message);
+        }
+
+        for new in new.iter().skip(old.len()) {
+            let message = format!("case {} was added", self.new_name(&new.ty));
+            self.push(Compatibility::Compatible, path, message);
+        }
+
+        self.repr(path, Members::Cases, old.len(), new.len());
+    }
+
+    /// Reports changes to the size of variant tags and flags bitfields.
+    fn repr(&mut self, path: &str, kind: Members, old: usize, new: usize) {
+        let (old, new, what) = match kind {
+            Members::Fields => return,
+            Members::Cases => (tag_bits(old), tag_bits(new), "tag"),
+            Members::Flags => (flags_bits(old), flags_bits(new), "bitfield"),
+        };
+
+        if old != new {
+            let message = format!("the {what} changed from u{old} to u{new}");
+            self.push(Compatibility::Breaking, path, message);
+        }
+    }
+
+    fn functions(&mut self, path: &str, old: &[Function], new: &[Function]) {
+        for old in old {
+            let path = format!("{path}::{}", old.id);
+
+            let Some(new) = new.iter().find(|new| new.id == old.id) else {
+                self.push(
+                    Compatibility::Breaking,
+                    &path,
+                    "function was removed".to_string(),
+                );
+                continue;
+            };
+
+            self.function(&path, old, new);
+        }
+
+        for new in new {
+            if !old.iter().any(|old| old.id == new.id) {
+                let path = format!("{path}::{}", new.id);
+
+                self.push(
+                    Compatibility::Compatible,
+                    &path,
+                    "function was added".to_string(),
+                );
+            }
+        }
+    }
+
+    fn events(&mut self, path: &str, old: &[Event], new: &[Event]) {
+        for old in old {
+            let path = format!("{path}::{}", old.id);
+
+            let Some(new) = new.iter().find(|new| new.id == old.id) else {
+                self.push(
+                    Compatibility::Breaking,
+                    &path,
+                    "event was removed".to_string(),
+                );
+                continue;
+            };
+
+            self.event(&path, old, new);
+        }
+
+        for new in new {
+            if !old.iter().any(|old| old.id == new.id) {
+                let path = format!("{path}::{}", new.id);
+
+                self.push(
+                    Compatibility::Compatible,
+                    &path,
+                    "event was added".to_string(),
+                );
+            }
+        }
+    }
+
+    /// Payloads are decoded by the guest like results, so any change to their types is breaking.
+    fn event(&mut self, path: &str, old: &Event, new: &Event) {
+        let payload_eq = old.payload.len() == new.payload.len()
+            && old
+                .payload
+                .iter()
+                .zip(&new.payload)
+                .all(|((_, old), (_, new))| self.wire_eq(old, new));
+
+        if !payload_eq {
+            let old: Vec<_> = old
+                .payload
+                .iter()
+                .map(|(_, ty)| self.old_name(ty))
+                .collect();
+            let new: Vec<_> = new
+                .payload
+                .iter()
+                .map(|(_, ty)| self.new_name(ty))
+                .collect();
+
+            let message = format!(
+                "payload changed from ({}) to ({})",
+                old.join(", "),
+                new.join(", ")
+            );
+            self.push(Compatibility::Breaking, path, message);
+            return;
+        }
+
+        for ((old_name, _), (new_name, _)) in old.payload.iter().zip(&new.payload) {
+            if old_name != new_name {
+                let message = format!("payload field `{old_name}` was renamed to `{new_name}`");
+                self.push(Compatibility::Compatible, path, message);
+            }
+        }
+    }
+
+    fn function(&mut self, path: &str, old: &Function, new: &Function) {
+        if old.kind != new.kind {
+            let message = format!(
+                "function changed from {} to {}",
+                function_kind_name(old.kind),
+                function_kind_name(new.kind)
+            );
+            self.push(Compatibility::Breaking, path, message);
+            return;
+        }
+
+        for (i, ((old_name, old_ty), (new_name, new_ty))) in
+            old.params.iter().zip(&new.params).enumerate()
+        {
+            if !self.wire_eq(old_ty, new_ty) {
+                let message = format!(
+                    "parameter {i} changed from {} to {}",
+                    self.old_name(old_ty),
+                    self.new_name(new_ty)
+                );
+                self.push(Compatibility::Breaking, path, message);
+            } else if old_name != new_name {
+                let message = format!("parameter `{old_name}` was renamed to `{new_name}`");
+                self.push(Compatibility::Compatible, path, message);
+            }
+        }
+
+        for (name, _) in old.params.iter().skip(new.params.len()) {
+            let message = format!("parameter `{name}` was removed");
+            self.push(Compatibility::Breaking, path, message);
+        }
+
+        // the parameters are sent as a tuple, which isn't length prefixed either
+        for (name, _) in new.params.iter().skip(old.params.len()) {
+            let message = format!("parameter `{name}` was added");
+            self.push(Compatibility::Breaking, path, message);
+        }
+
+        let old_results: Vec<_> = old.result.iter().flat_map(|res| res.types()).collect();
+        let new_results: Vec<_> = new.result.iter().flat_map(|res| res.types()).collect();
+
+        let results_eq = old_results.len() == new_results.len()
+            && old_results
+                .iter()
+                .zip(&new_results)
+                .all(|(old, new)| self.wire_eq(old, new));
+
+        if !results_eq {
+            let old: Vec<_> = old_results.iter().map(|ty| self.old_name(ty)).collect();
+            let new: Vec<_> = new_results.iter().map(|ty| self.new_name(ty)).collect();
+
+            let message = format!(
+                "result changed from ({}) to ({})",
+                old.join(", "),
+                new.join(", ")
+            );
+            self.push(Compatibility::Breaking, path, message);
+        }
+    }
+
+    /// Whether values of the two types have the same binary representation.
+    ///
+    /// Named types with the same name are considered equal, changes to them are reported on the type itself.
+    fn wire_eq(&self, old: &Type, new: &Type) -> bool {
+        let old = self.old_alias_target(old);
+        let new = self.new_alias_target(new);
+
+        match (old, new) {
+            (Type::List(old), Type::List(new)) | (Type::Option(old), Type::Option(new)) => {
+                self.wire_eq(old, new)
+            }
+            (Type::Tuple(old), Type::Tuple(new)) => {
+                old.len() == new.len()
+                    && old.iter().zip(new).all(|(old, new)| self.wire_eq(old, new))
+            }
+            (
+                Type::Result {
+                    ok: old_ok,
+                    err: old_err,
+                },
+                Type::Result {
+                    ok: new_ok,
+                    err: new_err,
+                },
+            ) => {
+                let eq = |old: &Option<Box<Type>>, new: &Option<Box<Type>>| match (old, new) {
+                    (Some(old), Some(new)) => self.wire_eq(old, new),
+                    (None, None) => true,
+                    _ => false,
+                };
+
+                eq(old_ok, new_ok) && eq(old_err, new_err)
+            }
+            // handing over a handle instead of lending it changes who may use it afterwards
+            (Type::Own(old), Type::Own(new)) | (Type::Borrow(old), Type::Borrow(new)) => {
+                self.old.typedefs[*old].ident == self.new.typedefs[*new].ident
+            }
+            (Type::Id(old), Type::Id(new)) => {
+                let (old, new) = (&self.old.typedefs[*old], &self.new.typedefs[*new]);
+
+                old.ident == new.ident || self.typedef_wire_eq(old, new)
+            }
+            (old, new) => old == new,
+        }
+    }
+
+    /// Structural comparison of differently named types.
+    fn typedef_wire_eq(&self, old: &TypeDef, new: &TypeDef) -> bool {
+        let payloads_eq = |old: &[Option<&Type>], new: &[Option<&Type>]| {
+            old.len() == new.len()
+                && old.iter().zip(new).all(|pair| match pair {
+                    (Some(old), Some(new)) => self.wire_eq(old, new),
+                    (None, None) => true,
+                    _ => false,
+                })
+        };
+
+        match (&old.kind, &new.kind) {
+            (TypeDefKind::Record(old), TypeDefKind::Record(new)) => {
+                let old: Vec<_> = old.iter().map(|f| Some(&f.ty)).collect();
+                let new: Vec<_> = new.iter().map(|f| Some(&f.ty)).collect();
+
+                payloads_eq(&old, &new)
+            }
+            (TypeDefKind::Variant(old), TypeDefKind::Variant(new)) => {
+                let old: Vec<_> = old.iter().map(|c| c.ty.as_ref()).collect();
+                let new: Vec<_> = new.iter().map(|c| c.ty.as_ref()).collect();
+
+                payloads_eq(&old, &new)
+            }
+            (TypeDefKind::Union(old), TypeDefKind::Union(new)) => {
+                let old: Vec<_> = old.iter().map(|c| Some(&c.ty)).collect();
+                let new: Vec<_> = new.iter().map(|c| Some(&c.ty)).collect();
+
+                payloads_eq(&old, &new)
+            }
+            (TypeDefKind::Enum(old), TypeDefKind::Enum(new)) => old.len() == new.len(),
+            (TypeDefKind::Flags(old), TypeDefKind::Flags(new)) => old.len() == new.len(),
+            (TypeDefKind::Resource(_), TypeDefKind::Resource(_)) => true,
+            _ => false,
+        }
+    }
+
+    fn old_alias_target<'t>(&'t self, ty: &'t Type) -> &'t Type {
+        alias_target(self.old, ty)
+    }
+
+    fn new_alias_target<'t>(&'t self, ty: &'t Type) -> &'t Type {
+        alias_target(self.new, ty)
+    }
+
+    fn old_name(&self, ty: &Type) -> String {
+        type_name(self.old, ty)
+    }
+
+    fn new_name(&self, ty: &Type) -> String {
+        type_name(self.new, ty)
+    }
+}
+
+fn alias_target<'a>(iface: &'a Interface, mut ty: &'a Type) -> &'a Type {
+    while let Type::Id(id) = ty {
+        match &iface.typedefs[*id].kind {
+            TypeDefKind::Alias(target) => ty = target,
+            _ => break,
+        }
+    }
+
+    ty
+}
+
+/// Prints a type the way it is written in `*.wit` files.
+fn type_name(iface: &Interface, ty: &Type) -> String {
+    match ty {
+        Type::Bool => "bool".to_string(),
+        Type::U8 => "u8".to_string(),
+        Type::U16 => "u16".to_string(),
+        Type::U32 => "u32".to_string(),
+        Type::U64 => "u64".to_string(),
+        Type::U128 => "u128".to_string(),
+        Type::S8 => "s8".to_string(),
+        Type::S16 => "s16".to_string(),
+        Type::S32 => "s32".to_string(),
+        Type::S64 => "s64".to_string(),
+        Type::S128 => "s128".to_string(),
+        Type::Float32 => "float32".to_string(),
+        Type::Float64 => "float64".to_string(),
+        Type::Char => "char".to_string(),
+        Type::String => "string".to_string(),
+        Type::List(ty) => format!("list<{}>", type_name(iface, ty)),
+        Type::Tuple(types) => {
+            let types: Vec<_> = types.iter().map(|ty| type_name(iface, ty)).collect();
+
+            format!("tuple<{}>", types.join(", "))
+        }
+        Type::Option(ty) => format!("option<{}>", type_name(iface, ty)),
+        Type::Result { ok, err } => match (ok, err) {
+            (None, None) => "result".to_string(),
+            (Some(ok), None) => format!("result<{}>", type_name(iface, ok)),
+            (None, Some(err)) => format!("result<_, {}>", type_name(iface, err)),
+            (Some(ok), Some(err)) => {
+                format!(
+                    "result<{}, {}>",
+                    type_name(iface, ok),
+                    type_name(iface, err)
+                )
+            }
+        },
+        Type::Own(id) => format!("own<{}>", iface.typedefs[*id].ident),
+        Type::Borrow(id) => format!("borrow<{}>", iface.typedefs[*id].ident),
+        Type::Id(id) => iface.typedefs[*id].ident.clone(),
+    }
+}
+
+fn kind_name(kind: &TypeDefKind) -> &'static str {
+    match kind {
+        TypeDefKind::Alias(_) => "an alias",
+        TypeDefKind::Record(_) => "a record",
+        TypeDefKind::Flags(_) => "flags",
+        TypeDefKind::Variant(_) => "a variant",
+        TypeDefKind::Enum(_) => "an enum",
+        TypeDefKind::Union(_) => "a union",
+        TypeDefKind::Resource(_) => "a resource",
+    }
+}
+
+fn function_kind_name(kind: FunctionKind) -> &'static str {
+    match kind {
+        FunctionKind::Freestanding => "a function",
+        FunctionKind::Method => "a method",
+        FunctionKind::Static => "a static function",
+        FunctionKind::Constructor => "a constructor",
+    }
+}
+
+/// The size of the tag of a variant with `cases` cases.
+fn tag_bits(cases: usize) -> u32 {
+    match u64::try_from(cases).unwrap_or(u64::MAX) {
+        n if n <= 1 << 8 => 8,
+        n if n <= 1 << 16 => 16,
+        n if n <= 1 << 32 => 32,
+        _ => 64,
+    }
+}
+
+/// The size of the bitfield of flags with `fields` fields.
+fn flags_bits(fields: usize) -> u32 {
+    match fields {
+        n if n <= 8 => 8,
+        n if n <= 16 => 16,
+        n if n <= 32 => 32,
+        n if n <= 64 => 64,
+        _ => 128,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn changes(old: &str, new: &str) -> Vec<(Compatibility, String)> {
+        let old = wit_parser::parse_and_resolve_str(old, |_| false).unwrap();
+        let new = wit_parser::parse_and_resolve_str(new, |_| false).unwrap();
+
+        diff_interface(&old, &new)
+            .into_iter()
+            .map(|change| {
+                (
+                    change.compatibility,
+                    format!("{}: {}", change.path, change.message),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn functions() {
+        let changes = changes(
+            "interface a {
+                func f(x: u32)
+                func g() -> string
+                func h(x: u32)
+            }",
+            "interface a {
+                func f(y: u32, z: option<u8>)
+                func g() -> list<u8>
+                func i()
+            }",
+        );
+
+        assert_eq!(
+            changes,
+            [
+                (
+                    Compatibility::Compatible,
+                    "a::f: parameter `x` was renamed to `y`".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::f: parameter `z` was added".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::g: result changed from (string) to (list<u8>)".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::h: function was removed".to_string()
+                ),
+                (
+                    Compatibility::Compatible,
+                    "a::i: function was added".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn resource_functions() {
+        let changes = changes(
+            "interface a {
+                resource r {
+                    func f()
+                    func g()
+                }
+                func x() -> r
+                func y(a: borrow<r>)
+            }",
+            "interface a {
+                resource r {
+                    constructor()
+                    static func f()
+                    func g()
+                }
+                func x() -> r
+                func y(a: own<r>)
+            }",
+        );
+
+        assert_eq!(
+            changes,
+            [
+                (
+                    Compatibility::Breaking,
+                    "a::r::f: function changed from a method to a static function".to_string()
+                ),
+                (
+                    Compatibility::Compatible,
+                    "a::r::constructor: function was added".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::y: parameter 0 changed from borrow<r> to own<r>".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn events() {
+        let changes = changes(
+            "interface a {
+                event e(x: u32)
+                event f(x: u32)
+                event g()
+            }",
+            "interface a {
+                event e(y: u32)
+                event f(x: string)
+                event h()
+            }",
+        );
+
+        assert_eq!(
+            changes,
+            [
+                (
+                    Compatibility::Compatible,
+                    "a::e: payload field `x` was renamed to `y`".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::f: payload changed from (u32) to (string)".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::g: event was removed".to_string()
+                ),
+                (
+                    Compatibility::Compatible,
+                    "a::h: event was added".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn records() {
+        let changes = changes(
+            "interface a {
+                record r { x: u32, y: u32 }
+                record s { x: u32 }
+                func f(r: r, s: s)
+            }",
+            "interface a {
+                record r { y: u32, x: u32, z: option<u8> }
+                record s { x: u32, y: u32 }
+                func f(r: r, s: s)
+            }",
+        );
+
+        assert_eq!(
+            changes,
+            [
+                (
+                    Compatibility::Breaking,
+                    "a::r: fields were reordered".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::r: field `z` was added".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::s: field `y` was added".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn variants() {
+        let changes = changes(
+            "interface a {
+                variant v { a(u32), b }
+                enum e { a, b }
+                flags f { a, b, c, d, e, f, g, h }
+                func f(v: v, e: e, f: f)
+            }",
+            "interface a {
+                variant v { a(string), b, c }
+                enum e { a, c, b }
+                flags f { a, b, c, d, e, f, g, h, i }
+                func f(v: v, e: e, f: f)
+            }",
+        );
+
+        assert_eq!(
+            changes,
+            [
+                (
+                    Compatibility::Breaking,
+                    "a::v: case `a` changed from u32 to string".to_string()
+                ),
+                (
+                    Compatibility::Compatible,
+                    "a::v: case `c` was added".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::e: case `c` was inserted, which changes the tags of the following cases"
+                        .to_string()
+                ),
+                (
+                    Compatibility::Compatible,
+                    "a::f: flag `i` was added".to_string()
+                ),
+                (
+                    Compatibility::Breaking,
+                    "a::f: the bitfield changed from u8 to u16".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn identical() {
+        let source = "interface a {
+            record r { x: u32 }
+            resource res { func m(r: r) }
+            func f(r: r) -> res
+        }";
+
+        assert!(changes(source, source).is_empty());
+    }
+}
@@ -1,7 +1,12 @@
+pub mod diff;
+
+pub use wit_parser::names;
+
+use miette::{Diagnostic, NamedSource, SourceSpan};
 use proc_macro2::TokenStream;
 use std::{collections::HashMap, ops::Index, path::PathBuf};
 use wit_parser::{
-    FlagsField, Function, FunctionResult, Int, Interface, Type, TypeDefArena, TypeDefId,
+    Event, FlagsField, Function, FunctionResult, Int, Interface, Type, TypeDefArena, TypeDefId,
     TypeDefKind, UnionCase,
 };
 
@@ -16,6 +21,92 @@ pub trait Generate {
     }
 }
 
+#[derive(Debug, thiserror::Error, Diagnostic)]
+pub enum Error {
+    /// Flags are represented as a bitmask in the generated code, the widest supported integer is 64 bits.
+    #[error("flags {ident} has {count} fields, but at most 64 are supported")]
+    #[diagnostic(code(tauri_bindgen::too_many_flags))]
+    TooManyFlags {
+        ident: String,
+        count: usize,
+        #[source_code]
+        source_code: Option<NamedSource<String>>,
+        #[label("these flags cannot be represented as a 64 bit integer")]
+        location: Option<SourceSpan>,
+    },
+    /// The host resolves owned handles only in function parameters, inside a named type they'd stay raw ids.
+    #[error(
+        "{ident} contains an owned handle, but `own` is only supported in function parameters"
+    )]
+    #[diagnostic(
+        code(tauri_bindgen::own_in_named_type),
+        help("pass the handle as a separate `own` parameter")
+    )]
+    OwnInNamedType {
+        ident: String,
+        #[source_code]
+        source_code: Option<NamedSource<String>>,
+        #[label("this type contains an owned handle")]
+        location: Option<SourceSpan>,
+    },
+}
+
+/// Checks that bindings can be generated for `interface`.
+///
+/// # Errors
+///
+/// Returns an error pointing into the `*.wit` file when the interface contains constructs generators can't represent.
+// this is only called once per interface, so the size of the error doesn't matter
+#[allow(clippy::result_large_err)]
+pub fn check_interface(interface: &Interface) -> Result<(), Error> {
+    for (_, typedef) in &interface.typedefs {
+        let span = typedef.span.as_ref();
+
+        if let TypeDefKind::Flags(fields) = &typedef.kind {
+            if fields.len() > 64 {
+                return Err(Error::TooManyFlags {
+                    ident: typedef.ident.clone(),
+                    count: fields.len(),
+                    source_code: span.and_then(|span| interface.source_map.named_source(span)),
+                    location: span.map(Into::into),
+                });
+            }
+        }
+
+        let has_own = match &typedef.kind {
+            TypeDefKind::Alias(ty) => contains_own(ty),
+            TypeDefKind::Record(fields) => fields.iter().any(|field| contains_own(&field.ty)),
+            TypeDefKind::Variant(cases) => cases
+                .iter()
+                .filter_map(|case| case.ty.as_ref())
+                .any(contains_own),
+            TypeDefKind::Union(cases) => cases.iter().any(|case| contains_own(&case.ty)),
+            TypeDefKind::Flags(_) | TypeDefKind::Enum(_) | TypeDefKind::Resource(_) => false,
+        };
+
+        if has_own {
+            return Err(Error::OwnInNamedType {
+                ident: typedef.ident.clone(),
+                source_code: span.and_then(|span| interface.source_map.named_source(span)),
+                location: span.map(Into::into),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Named types are checked on their own, so this doesn't follow `Type::Id`.
+fn contains_own(ty: &Type) -> bool {
+    match ty {
+        Type::Own(_) => true,
+        Type::List(ty) | Type::Option(ty) => contains_own(ty),
+        Type::Tuple(types) => types.iter().any(contains_own),
+        Type::Result { ok, err } => ok.iter().chain(err).any(|ty| contains_own(ty)),
+        _ => false,
+    }
+}
+
 use std::{
     ffi::OsStr,
     io::{Read, Write},
@@ -63,6 +154,11 @@ bitflags::bitflags! {
         const RESULT = 0b0000_0010;
         /// Whether or not this type (transitively) has a list.
         const HAS_LIST = 0b0000_1000;
+        /// Whether or not this type (transitively) contains a resource handle that isn't handed over with `own`.
+        /// Not set for the resource itself.
+        const HAS_RESOURCE = 0b0001_0000;
+        /// Whether or not this type (transitively) hands over a resource handle with `own`.
+        const HAS_OWN = 0b0010_0000;
     }
 }
 
@@ -104,6 +200,15 @@ impl TypeInfos {
         this
     }
 
+    /// Marks the types of event payloads, which flow from the host to the guest like results.
+    pub fn collect_from_events(&mut self, typedefs: &TypeDefArena, events: &[Event]) {
+        for event in events {
+            for (_, ty) in &event.payload {
+                self.collect_type_info(typedefs, ty, TypeInfo::RESULT);
+            }
+        }
+    }
+
     fn collect_typedef_info(
         &mut self,
         typedefs: &TypeDefArena,
@@ -154,7 +259,16 @@ impl TypeInfos {
     ) -> TypeInfo {
         match ty {
             Type::String => base_info | TypeInfo::HAS_LIST,
-            Type::List(ty) => self.collect_type_info(typedefs, ty, base_info) | TypeInfo::HAS_LIST,
+            Type::List(ty) => {
+                let info = self.collect_type_info(typedefs, ty, base_info);
+
+                // lists that hand over resources are moved into the call instead of borrowed
+                if info.contains(TypeInfo::HAS_OWN) {
+                    info
+                } else {
+                    info | TypeInfo::HAS_LIST
+                }
+            }
             Type::Option(ty) => self.collect_type_info(typedefs, ty, base_info),
             Type::Tuple(types) => {
                 let mut info = base_info;
@@ -173,7 +287,24 @@ impl TypeInfos {
                 }
                 info
             }
-            Type::Id(id) => base_info | self.collect_typedef_info(typedefs, *id, base_info),
+            // owned handles are passed by value, only borrowed ones need a lifetime
+            Type::Own(id) => {
+                base_info | self.collect_typedef_info(typedefs, *id, base_info) | TypeInfo::HAS_OWN
+            }
+            Type::Borrow(id) => {
+                base_info
+                    | self.collect_typedef_info(typedefs, *id, base_info)
+                    | TypeInfo::HAS_RESOURCE
+            }
+            Type::Id(id) => {
+                let info = base_info | self.collect_typedef_info(typedefs, *id, base_info);
+
+                if let TypeDefKind::Resource(_) = typedefs[*id].kind {
+                    info | TypeInfo::HAS_RESOURCE
+                } else {
+                    info
+                }
+            }
             _ => base_info,
         }
     }
@@ -222,6 +353,7 @@ fn type_ident(typedefs: &TypeDefArena, ty: &Type) -> String {
         Type::Tuple(_) => "Tuple".to_string(),
         Type::Option(ty) => format!("Optional{}", type_ident(typedefs, ty)),
         Type::Result { .. } => "Result".to_string(),
+        Type::Own(_) | Type::Borrow(_) => "Resource".to_string(),
         Type::Id(id) => match &typedefs[*id].kind {
             TypeDefKind::Alias(ty) => type_ident(typedefs, ty),
             TypeDefKind::Record(_) => "Record".to_string(),
@@ -283,3 +415,43 @@ pub fn union_case_names(typedefs: &TypeDefArena, cases: &[UnionCase]) -> Vec<Str
 
     case_names
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check(source: &str) -> Option<Error> {
+        let iface = wit_parser::parse_and_resolve_str(source, |_| false).unwrap();
+
+        check_interface(&iface).err()
+    }
+
+    #[test]
+    fn own_in_named_types() {
+        assert!(check(
+            "interface a {
+                resource r {}
+                record s { x: borrow<r> }
+                func f(x: own<r>, y: option<list<own<r>>>, z: s)
+            }",
+        )
+        .is_none());
+
+        for ty in [
+            "record s { x: own<r> }",
+            "variant s { x(list<own<r>>) }",
+            "union s { u32, option<own<r>> }",
+            "type s = own<r>",
+        ] {
+            let err = check(&format!(
+                "interface a {{ resource r {{}} {ty} func f(x: s) }}"
+            ))
+            .unwrap();
+
+            assert!(
+                matches!(&err, Error::OwnInNamedType { ident, location: Some(_), .. } if ident == "s"),
+                "{ty}: {err:?}"
+            );
+        }
+    }
+}
@@ -1,11 +1,14 @@
 #![allow(clippy::must_use_candidate, clippy::unused_self)]
 
-use heck::{ToKebabCase, ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
+use heck::{ToKebabCase, ToSnakeCase};
 use std::fmt::Write;
 use std::path::PathBuf;
-use tauri_bindgen_core::{postprocess, Generate, GeneratorBuilder, TypeInfo, TypeInfos};
+use tauri_bindgen_core::{
+    names::{js_ident, js_type_name},
+    postprocess, Generate, GeneratorBuilder, TypeInfo, TypeInfos,
+};
 use tauri_bindgen_gen_js::{JavaScriptGenerator, SerdeUtils};
-use wit_parser::{Function, FunctionResult, Interface, Type, TypeDefKind};
+use wit_parser::{Event, Function, FunctionKind, FunctionResult, Interface, Type, TypeDefKind};
 
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "clap", derive(clap::Args))]
@@ -36,13 +39,16 @@ impl GeneratorBuilder for Builder {
             })
             .flatten();
 
-        let infos = TypeInfos::collect_from_functions(
+        let mut infos = TypeInfos::collect_from_functions(
             &interface.typedefs,
             interface.functions.iter().chain(methods),
         );
+        infos.collect_from_events(&interface.typedefs, &interface.events);
 
         let serde_utils =
-            SerdeUtils::collect_from_functions(&interface.typedefs, &interface.functions);
+            SerdeUtils::collect_from_functions(&interface.typedefs, &interface.functions)
+                | SerdeUtils::collect_from_resources(&interface.typedefs)
+                | SerdeUtils::collect_from_events(&interface.typedefs, &interface.events);
 
         Box::new(JavaScript {
             opts: self,
@@ -64,10 +70,12 @@ pub struct JavaScript {
 impl JavaScript {
     fn print_function(&self, intf_name: &str, func: &Function) -> String {
         let docs = self.print_docs(func);
-        let ident = func.id.to_lower_camel_case();
+        let ident = js_ident(&func.id);
         let name = func.id.to_snake_case();
         let params = print_function_params(&func.params);
 
+        let transfer = self.print_transfer_params(&func.params);
+
         let deserialize_result = func
             .result
             .as_ref()
@@ -77,7 +85,7 @@ impl JavaScript {
         let serialize_params = func
             .params
             .iter()
-            .map(|(ident, ty)| self.print_serialize_ty(&ident.to_lower_camel_case(), ty))
+            .map(|(ident, ty)| self.print_serialize_ty(&js_ident(ident), ty))
             .collect::<Vec<_>>()
             .join(";\n");
 
@@ -88,7 +96,47 @@ export async function {ident} ({params}) {{
     const out = []
     {serialize_params}
 
-    return fetch('ipc://localhost/{intf_name}/{name}', {{ method: "POST", body: Uint8Array.from(out), headers: {{ 'Content-Type': 'application/octet-stream' }} }}){deserialize_result}
+    return fetch('ipc://localhost/{intf_name}/{name}', {{ method: "POST", body: Uint8Array.from(out), headers: {{ 'Content-Type': 'application/octet-stream' }} }})
+        .then(checkResponse){transfer}{deserialize_result}
+}}
+"#
+        )
+    }
+
+    fn print_event(&self, intf_name: &str, event: &Event) -> String {
+        let docs = event.docs.lines().fold(String::new(), |mut str, line| {
+            let _ = writeln!(str, " * {line} \n");
+            str
+        });
+        let ident = format!("on{}", js_type_name(&event.id));
+        let name = event.id.to_snake_case();
+
+        let params = event
+            .payload
+            .iter()
+            .map(|(ident, ty)| format!("{}: {}", js_ident(ident), self.print_ty(ty)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let deserialize_payload = event
+            .payload
+            .iter()
+            .map(|(_, ty)| self.print_deserialize_ty(ty))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            r#"
+/**
+{docs}* @param {{({params}) => void}} handler
+* @returns {{Promise<() => void>}} A function that stops listening
+*/
+export async function {ident} (handler) {{
+    return listen('{intf_name}::{name}', (event) => {{
+        const de = new Deserializer(Uint8Array.from(event.payload))
+
+        handler({deserialize_payload})
+    }})
 }}
 "#
         )
@@ -102,19 +150,30 @@ export async function {ident} ({params}) {{
         functions: &[Function],
         info: TypeInfo,
     ) -> String {
-        let ident = ident.to_upper_camel_case();
+        let ident = js_type_name(ident);
+        let module = format!(
+            "{}::resource::{}",
+            mod_ident.to_snake_case(),
+            ident.to_snake_case()
+        );
 
         let functions: String = functions
             .iter()
             .fold(String::new(), |mut str, func| {
                 let docs = self.print_docs(func);
-                let mod_ident = mod_ident.to_snake_case();
-                let resource_ident = ident.to_snake_case();
-                let ident = func.id.to_lower_camel_case();
+                let name = func.id.to_snake_case();
+
+                let (head, serialize_id) = match func.kind {
+                    FunctionKind::Constructor => ("static async create".to_string(), ""),
+                    FunctionKind::Static => (format!("static async {}", js_ident(&func.id)), ""),
+                    _ => (format!("async {}", js_ident(&func.id)), "serializeU64(out, this.#id);"),
+                };
 
                 let params = print_function_params(&func.params);
 
-                let deserialize_result = func
+                let transfer = self.print_transfer_params(&func.params);
+
+        let deserialize_result = func
                     .result
                     .as_ref()
                     .map(|res| self.print_deserialize_function_result(res))
@@ -123,18 +182,19 @@ export async function {ident} ({params}) {{
                 let serialize_params = func
                     .params
                     .iter()
-                    .map(|(ident, ty)| self.print_serialize_ty(&ident.to_lower_camel_case(), ty))
+                    .map(|(ident, ty)| self.print_serialize_ty(&js_ident(ident), ty))
                     .collect::<Vec<_>>()
                     .join(";\n");
 
                 let _ = write!(str,
                     r#"{docs}
-async {ident} ({params}) {{
+{head} ({params}) {{
     const out = []
-    serializeU32(out, this.#id);
+    {serialize_id}
     {serialize_params}
 
-    await fetch('ipc://localhost/{mod_ident}::resource::{resource_ident}/{ident}', {{ method: "POST", body: Uint8Array.from(out), headers: {{ 'Content-Type': 'application/octet-stream' }} }}){deserialize_result}
+    return fetch('ipc://localhost/{module}/{name}', {{ method: "POST", body: Uint8Array.from(out), headers: {{ 'Content-Type': 'application/octet-stream' }} }})
+        .then(checkResponse){transfer}{deserialize_result}
 }}
 "#
                 );
@@ -142,12 +202,27 @@ async {ident} ({params}) {{
                 str
             });
 
+        let serialize = if info.contains(TypeInfo::PARAM) {
+            format!(
+                "serialize(out) {{
+    serializeU64(out, this.#id)
+}}
+/**
+* Gives up the handle after the host took it over in a call, it can't be used afterwards.
+*/
+transfer() {{
+    {ident}.#registry.unregister(this)
+    this.#id = undefined
+}}"
+            )
+        } else {
+            String::new()
+        };
+
         let deserialize = if info.contains(TypeInfo::RESULT) {
             format!(
                 "static deserialize(de) {{
-    const self = new {ident}();
-    self.#id = deserializeU32(de);
-    return self
+    return {ident}.#from(deserializeU64(de))
 }}"
             )
         } else {
@@ -155,11 +230,38 @@ async {ident} ({params}) {{
         };
 
         format!(
-            "{docs}\nexport class {ident} {{
+            r#"{docs}
+export class {ident} {{
             #id;
+            static #registry = new FinalizationRegistry((id) => {ident}.#drop(id).catch(console.error))
+
+            static #from(id) {{
+                const self = new {ident}()
+                self.#id = id
+                {ident}.#registry.register(self, id, self)
+                return self
+            }}
+
+            static async #drop(id) {{
+                const out = []
+                serializeU64(out, id)
+
+                await fetch('ipc://localhost/{module}/drop', {{ method: "POST", body: Uint8Array.from(out), headers: {{ 'Content-Type': 'application/octet-stream' }} }})
+                    .then(checkResponse)
+            }}
+
             {functions}
+            /**
+            * Releases the resource without waiting for it to be garbage collected.
+            */
+            async dispose () {{
+                if ({ident}.#registry.unregister(this)) {{
+                    await {ident}.#drop(this.#id)
+                }}
+            }}
+            {serialize}
             {deserialize}
-        }}"
+        }}"#
         )
     }
 
@@ -173,7 +275,7 @@ async {ident} ({params}) {{
             .params
             .iter()
             .fold(String::new(), |mut str, (name, ty)| {
-                let ident = &name.to_lower_camel_case();
+                let ident = &js_ident(name);
                 let ty = self.print_ty(ty);
 
                 let _ = writeln!(str, "* @param {{{ty}}} {ident}");
@@ -241,7 +343,9 @@ async {ident} ({params}) {{
 
                 format!("Result<{ok}, {err}>")
             }
-            Type::Id(id) => self.interface.typedefs[*id].ident.to_upper_camel_case(),
+            Type::Own(id) | Type::Borrow(id) | Type::Id(id) => {
+                js_type_name(&self.interface.typedefs[*id].ident)
+            }
         }
     }
 
@@ -268,6 +372,8 @@ async {ident} ({params}) {{
             | Type::List(_)
             | Type::Option(_)
             | Type::Result { .. }
+            | Type::Own(_)
+            | Type::Borrow(_)
             | Type::Char
             | Type::String => None,
         }
@@ -323,6 +429,19 @@ impl Generate for JavaScript {
             .map(|func| self.print_function(&self.interface.ident.to_snake_case(), func))
             .collect();
 
+        let events: String = self
+            .interface
+            .events
+            .iter()
+            .map(|event| self.print_event(&self.interface.ident.to_snake_case(), event))
+            .collect();
+
+        let imports = if self.interface.events.is_empty() {
+            ""
+        } else {
+            "import { listen } from '@tauri-apps/api/event'\n"
+        };
+
         let resources: String = self
             .interface
             .typedefs
@@ -345,8 +464,9 @@ impl Generate for JavaScript {
 
         let serde_utils = self.serde_utils.to_string();
 
-        let mut contents =
-            format!("{serde_utils}{deserializers}{serializers}\n{functions}\n{resources}");
+        let mut contents = format!(
+            "{imports}{serde_utils}{deserializers}{serializers}\n{functions}{events}\n{resources}"
+        );
 
         if self.opts.prettier {
             postprocess(&mut contents, "prettier", ["--parser=babel"])
@@ -370,7 +490,7 @@ impl Generate for JavaScript {
 fn print_function_params(params: &[(String, Type)]) -> String {
     params
         .iter()
-        .map(|(name, _)| name.to_lower_camel_case())
+        .map(|(name, _)| js_ident(name))
         .collect::<Vec<_>>()
         .join(", ")
 }
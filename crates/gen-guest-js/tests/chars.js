@@ -109,11 +109,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -137,6 +135,49 @@ function serializeChar(out, val) {
 }
 const __text_decoder = new TextDecoder('utf-8');
 const __text_encoder = new TextEncoder();
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 
 
 /**
@@ -149,6 +190,7 @@ export async function takeChar (x) {
     serializeChar(out, x)
 
     return fetch('ipc://localhost/chars/take_char', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -161,6 +203,7 @@ export async function returnChar () {
     
 
     return fetch('ipc://localhost/chars/return_char', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
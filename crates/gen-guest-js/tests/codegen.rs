@@ -254,3 +254,17 @@ fn variants() {
     assert_eq!(filename, "variants.js");
     assert_eq!(contents, include_str!("./variants.js"));
 }
+
+#[test]
+fn events() {
+    let opts = Builder {
+        prettier: false,
+        romefmt: false,
+    };
+
+    let (filename, contents) =
+        gen_interface(opts, "events", include_str!("../../../wit/events.wit"));
+
+    assert_eq!(filename, "events.js");
+    assert_eq!(contents, include_str!("./events.js"));
+}
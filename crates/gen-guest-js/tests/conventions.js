@@ -53,11 +53,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -73,6 +71,55 @@ function serializeU32(out, val) {
 function serializeU64(out, val) {
   return ser_varint_big(out, 64, BigInt(val))
 }
+function serializeString(out, val) {
+    serializeU64(out, val.length);
+
+    out.push(...__text_encoder.encode(val))
+}
+const __text_encoder = new TextEncoder();
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 function serializeLudicrousSpeed(out, val) {
     serializeU32(out, val.how_fast_are_you_going),
 serializeU64(out, val.i_am_going_extremely_slow)
@@ -85,6 +132,7 @@ export async function kebabCase () {
     
 
     return fetch('ipc://localhost/conventions/kebab_case', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -95,6 +143,7 @@ export async function foo (x) {
     serializeLudicrousSpeed(out, x)
 
     return fetch('ipc://localhost/conventions/foo', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -104,6 +153,7 @@ export async function functionWithUnderscores () {
     
 
     return fetch('ipc://localhost/conventions/function_with_underscores', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -113,6 +163,7 @@ export async function functionWithNoWeirdCharacters () {
     
 
     return fetch('ipc://localhost/conventions/function_with_no_weird_characters', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -122,6 +173,7 @@ export async function apple () {
     
 
     return fetch('ipc://localhost/conventions/apple', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -131,6 +183,7 @@ export async function applePear () {
     
 
     return fetch('ipc://localhost/conventions/apple_pear', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -140,6 +193,7 @@ export async function applePearGrape () {
     
 
     return fetch('ipc://localhost/conventions/apple_pear_grape', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -149,6 +203,7 @@ export async function a0 () {
     
 
     return fetch('ipc://localhost/conventions/a0', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -158,6 +213,7 @@ export async function isXml () {
     
 
     return fetch('ipc://localhost/conventions/is_xml', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -167,6 +223,7 @@ export async function explicit () {
     
 
     return fetch('ipc://localhost/conventions/explicit', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -176,6 +233,7 @@ export async function explicitSnake () {
     
 
     return fetch('ipc://localhost/conventions/explicit_snake', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -185,5 +243,19 @@ export async function bool () {
     
 
     return fetch('ipc://localhost/conventions/bool', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
+}
+
+/**
+* @param {number} self
+* @param {string} class_
+*/
+export async function type (self, class_) {
+    const out = []
+    serializeU32(out, self);
+serializeString(out, class_)
+
+    return fetch('ipc://localhost/conventions/type', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
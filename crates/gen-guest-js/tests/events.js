@@ -0,0 +1,195 @@
+import { listen } from '@tauri-apps/api/event'
+class Deserializer {
+    source
+    offset
+    
+    constructor(bytes) {
+        this.source = bytes
+        this.offset = 0
+    }
+
+    pop() {
+        return this.source[this.offset++]
+    }
+
+    try_take_n(len) {
+        const out = this.source.slice(this.offset, this.offset + len)
+        this.offset += len
+        return out
+    }
+}
+// function varint_max(bits) {
+//   const BITS_PER_BYTE = 8;
+//   const BITS_PER_VARINT_BYTE = 7;
+
+//   const roundup_bits = bits + (BITS_PER_BYTE - 1);
+
+//   return Math.floor(roundup_bits / BITS_PER_VARINT_BYTE);
+// }
+
+const varint_max = {
+  16: 3,
+  32: 5,
+  64: 10,
+  128: 19
+}
+function max_of_last_byte(type) {
+  let extra_bits = type % 7;
+  return (1 << extra_bits) - 1;
+}
+
+function de_varint(de, bits) {
+  let out = 0;
+
+  for (let i = 0; i < varint_max[bits]; i++) {
+    const val = de.pop();
+    const carry = val & 0x7F;
+    out |= carry << (7 * i);
+
+    if ((val & 0x80) === 0) {
+      if (i === varint_max[bits] - 1 && val > max_of_last_byte(bits)) {
+        throw new Error('deserialize bad variant')
+      } else {
+        return out
+      }
+    }
+  }
+
+  throw new Error('deserialize bad variant')
+}
+
+function de_varint_big(de, bits) {
+  let out = 0n;
+
+  for (let i = 0; i < varint_max[bits]; i++) {
+    const val = de.pop();
+    const carry = BigInt(val) & 0x7Fn;
+    out |= carry << (7n * BigInt(i));
+
+    if ((val & 0x80) === 0) {
+      if (i === varint_max[bits] - 1 && val > max_of_last_byte(bits)) {
+        throw new Error('deserialize bad variant')
+      } else {
+        return out
+      }
+    }
+  }
+
+  throw new Error('deserialize bad variant')
+}
+function deserializeU32(de) {
+    return de_varint(de, 32)
+}
+function deserializeU64(de) {
+  return de_varint_big(de, 64)
+}
+function deserializeString(de) {
+    const sz = deserializeU64(de);
+
+    let bytes = de.try_take_n(Number(sz));
+
+    return __text_decoder.decode(bytes);
+}
+function deserializeOption(de, inner) {
+    const tag = de.pop()
+
+    switch (tag) {
+        case 0:
+            return null
+        case 1: 
+            return inner(de)
+        default:
+            throw new Error(`Deserialize bad option ${tag}`)
+    }
+}
+const __text_decoder = new TextDecoder('utf-8');
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
+function deserializeProgress(de) {
+    return {
+        done: deserializeU32(de),
+total: deserializeU32(de)
+    }
+}
+
+/**
+ * Starts a task that reports its progress through events. 
+
+*/
+export async function start () {
+    const out = []
+    
+
+    return fetch('ipc://localhost/events/start', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
+}
+
+/**
+ * Emitted whenever the task made progress. 
+
+* @param {(progress: Progress) => void} handler
+* @returns {Promise<() => void>} A function that stops listening
+*/
+export async function onProgressChanged (handler) {
+    return listen('events::progress_changed', (event) => {
+        const de = new Deserializer(Uint8Array.from(event.payload))
+
+        handler(deserializeProgress(de))
+    })
+}
+
+/**
+ * Emitted once the task finished, with an error message if it failed. 
+
+* @param {(task: string, error: string | null) => void} handler
+* @returns {Promise<() => void>} A function that stops listening
+*/
+export async function onFinished (handler) {
+    return listen('events::finished', (event) => {
+        const de = new Deserializer(Uint8Array.from(event.payload))
+
+        handler(deserializeString(de), deserializeOption(de, (de) => deserializeString(de)))
+    })
+}
+
@@ -53,6 +53,49 @@ function serializeF64(out, val) {
 
     out.push(...new Uint8Array(buf))
 }
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 
 
 /**
@@ -63,6 +106,7 @@ export async function float32Param (x) {
     serializeF32(out, x)
 
     return fetch('ipc://localhost/floats/float32_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -73,6 +117,7 @@ export async function float64Param (x) {
     serializeF64(out, x)
 
     return fetch('ipc://localhost/floats/float64_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -83,6 +128,7 @@ export async function float32Result () {
     
 
     return fetch('ipc://localhost/floats/float32_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -99,6 +145,7 @@ export async function float64Result () {
     
 
     return fetch('ipc://localhost/floats/float64_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
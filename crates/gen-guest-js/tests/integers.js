@@ -140,11 +140,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -186,6 +184,49 @@ function serializeS128(out, val) {
   val = BigInt(val)
   ser_varint_big(out, 128, (val << 1n) ^ (val >> 127n))
 }
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 
 
 /**
@@ -196,6 +237,7 @@ export async function a1 (x) {
     serializeU8(out, x)
 
     return fetch('ipc://localhost/integers/a1', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -206,6 +248,7 @@ export async function a2 (x) {
     serializeS8(out, x)
 
     return fetch('ipc://localhost/integers/a2', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -216,6 +259,7 @@ export async function a3 (x) {
     serializeU16(out, x)
 
     return fetch('ipc://localhost/integers/a3', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -226,6 +270,7 @@ export async function a4 (x) {
     serializeS16(out, x)
 
     return fetch('ipc://localhost/integers/a4', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -236,6 +281,7 @@ export async function a5 (x) {
     serializeU32(out, x)
 
     return fetch('ipc://localhost/integers/a5', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -246,6 +292,7 @@ export async function a6 (x) {
     serializeS32(out, x)
 
     return fetch('ipc://localhost/integers/a6', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -256,6 +303,7 @@ export async function a7 (x) {
     serializeU64(out, x)
 
     return fetch('ipc://localhost/integers/a7', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -266,6 +314,7 @@ export async function a8 (x) {
     serializeS64(out, x)
 
     return fetch('ipc://localhost/integers/a8', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -276,6 +325,7 @@ export async function a9 (x) {
     serializeU128(out, x)
 
     return fetch('ipc://localhost/integers/a9', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -286,6 +336,7 @@ export async function a10 (x) {
     serializeS128(out, x)
 
     return fetch('ipc://localhost/integers/a10', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -314,6 +365,7 @@ serializeU128(out, p9);
 serializeS128(out, p10)
 
     return fetch('ipc://localhost/integers/a11', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -324,6 +376,7 @@ export async function r1 () {
     
 
     return fetch('ipc://localhost/integers/r1', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -340,6 +393,7 @@ export async function r2 () {
     
 
     return fetch('ipc://localhost/integers/r2', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -356,6 +410,7 @@ export async function r3 () {
     
 
     return fetch('ipc://localhost/integers/r3', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -372,6 +427,7 @@ export async function r4 () {
     
 
     return fetch('ipc://localhost/integers/r4', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -388,6 +444,7 @@ export async function r5 () {
     
 
     return fetch('ipc://localhost/integers/r5', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -404,6 +461,7 @@ export async function r6 () {
     
 
     return fetch('ipc://localhost/integers/r6', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -420,6 +478,7 @@ export async function r7 () {
     
 
     return fetch('ipc://localhost/integers/r7', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -436,6 +495,7 @@ export async function r8 () {
     
 
     return fetch('ipc://localhost/integers/r8', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -452,6 +512,7 @@ export async function r9 () {
     
 
     return fetch('ipc://localhost/integers/r9', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -468,6 +529,7 @@ export async function r10 () {
     
 
     return fetch('ipc://localhost/integers/r10', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -484,6 +546,7 @@ export async function pairRet () {
     
 
     return fetch('ipc://localhost/integers/pair_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -194,11 +194,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -282,6 +280,49 @@ function serializeList(out, inner, val) {
 }
 const __text_decoder = new TextDecoder('utf-8');
 const __text_encoder = new TextEncoder();
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 function deserializeOtherRecord(de) {
     return {
         a1: deserializeU32(de),
@@ -387,6 +428,7 @@ export async function listU8Param (x) {
     serializeBytes(out, x)
 
     return fetch('ipc://localhost/lists/list_u8_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -397,6 +439,7 @@ export async function listU16Param (x) {
     serializeList(out, (out, v) => serializeU16(out, v), x)
 
     return fetch('ipc://localhost/lists/list_u16_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -407,6 +450,7 @@ export async function listU32Param (x) {
     serializeList(out, (out, v) => serializeU32(out, v), x)
 
     return fetch('ipc://localhost/lists/list_u32_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -417,6 +461,7 @@ export async function listU64Param (x) {
     serializeList(out, (out, v) => serializeU64(out, v), x)
 
     return fetch('ipc://localhost/lists/list_u64_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -427,6 +472,7 @@ export async function listU128Param (x) {
     serializeList(out, (out, v) => serializeU128(out, v), x)
 
     return fetch('ipc://localhost/lists/list_u128_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -437,6 +483,7 @@ export async function listS8Param (x) {
     serializeList(out, (out, v) => serializeS8(out, v), x)
 
     return fetch('ipc://localhost/lists/list_s8_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -447,6 +494,7 @@ export async function listS16Param (x) {
     serializeList(out, (out, v) => serializeS16(out, v), x)
 
     return fetch('ipc://localhost/lists/list_s16_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -457,6 +505,7 @@ export async function listS32Param (x) {
     serializeList(out, (out, v) => serializeS32(out, v), x)
 
     return fetch('ipc://localhost/lists/list_s32_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -467,6 +516,7 @@ export async function listS64Param (x) {
     serializeList(out, (out, v) => serializeS64(out, v), x)
 
     return fetch('ipc://localhost/lists/list_s64_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -477,6 +527,7 @@ export async function listS128Param (x) {
     serializeList(out, (out, v) => serializeS128(out, v), x)
 
     return fetch('ipc://localhost/lists/list_s128_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -487,6 +538,7 @@ export async function listFloat32Param (x) {
     serializeList(out, (out, v) => serializeF32(out, v), x)
 
     return fetch('ipc://localhost/lists/list_float32_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -497,6 +549,7 @@ export async function listFloat64Param (x) {
     serializeList(out, (out, v) => serializeF64(out, v), x)
 
     return fetch('ipc://localhost/lists/list_float64_param', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -507,6 +560,7 @@ export async function listU8Ret () {
     
 
     return fetch('ipc://localhost/lists/list_u8_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -523,6 +577,7 @@ export async function listU16Ret () {
     
 
     return fetch('ipc://localhost/lists/list_u16_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -539,6 +594,7 @@ export async function listU32Ret () {
     
 
     return fetch('ipc://localhost/lists/list_u32_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -555,6 +611,7 @@ export async function listU64Ret () {
     
 
     return fetch('ipc://localhost/lists/list_u64_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -571,6 +628,7 @@ export async function listU128Ret () {
     
 
     return fetch('ipc://localhost/lists/list_u128_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -587,6 +645,7 @@ export async function listS8Ret () {
     
 
     return fetch('ipc://localhost/lists/list_s8_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -603,6 +662,7 @@ export async function listS16Ret () {
     
 
     return fetch('ipc://localhost/lists/list_s16_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -619,6 +679,7 @@ export async function listS32Ret () {
     
 
     return fetch('ipc://localhost/lists/list_s32_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -635,6 +696,7 @@ export async function listS64Ret () {
     
 
     return fetch('ipc://localhost/lists/list_s64_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -651,6 +713,7 @@ export async function listS128Ret () {
     
 
     return fetch('ipc://localhost/lists/list_s128_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -667,6 +730,7 @@ export async function listFloat32Ret () {
     
 
     return fetch('ipc://localhost/lists/list_float32_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -683,6 +747,7 @@ export async function listFloat64Ret () {
     
 
     return fetch('ipc://localhost/lists/list_float64_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -700,6 +765,7 @@ export async function tupleList (x) {
     serializeList(out, (out, v) => {serializeU8(out, v[0]);serializeS8(out, v[1])}, x)
 
     return fetch('ipc://localhost/lists/tuple_list', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -716,6 +782,7 @@ export async function stringListArg (a) {
     serializeList(out, (out, v) => serializeString(out, v), a)
 
     return fetch('ipc://localhost/lists/string_list_arg', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -726,6 +793,7 @@ export async function stringListRet () {
     
 
     return fetch('ipc://localhost/lists/string_list_ret', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -743,6 +811,7 @@ export async function tupleStringList (x) {
     serializeList(out, (out, v) => {serializeU8(out, v[0]);serializeString(out, v[1])}, x)
 
     return fetch('ipc://localhost/lists/tuple_string_list', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -760,6 +829,7 @@ export async function stringList (x) {
     serializeList(out, (out, v) => serializeString(out, v), x)
 
     return fetch('ipc://localhost/lists/string_list', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -777,6 +847,7 @@ export async function recordList (x) {
     serializeList(out, (out, v) => serializeSomeRecord(out, v), x)
 
     return fetch('ipc://localhost/lists/record_list', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -794,6 +865,7 @@ export async function recordListReverse (x) {
     serializeList(out, (out, v) => serializeOtherRecord(out, v), x)
 
     return fetch('ipc://localhost/lists/record_list_reverse', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -811,6 +883,7 @@ export async function variantList (x) {
     serializeList(out, (out, v) => serializeSomeVariant(out, v), x)
 
     return fetch('ipc://localhost/lists/variant_list', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -828,6 +901,7 @@ export async function loadStoreEverything (a) {
     serializeLoadStoreAllSizes(out, a)
 
     return fetch('ipc://localhost/lists/load_store_everything', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
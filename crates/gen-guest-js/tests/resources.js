@@ -79,6 +79,151 @@ function de_varint_big(de, bits) {
 function deserializeU32(de) {
     return de_varint(de, 32)
 }
+function deserializeU64(de) {
+  return de_varint_big(de, 64)
+}
+function deserializeOption(de, inner) {
+    const tag = de.pop()
+
+    switch (tag) {
+        case 0:
+            return null
+        case 1: 
+            return inner(de)
+        default:
+            throw new Error(`Deserialize bad option ${tag}`)
+    }
+}
+function deserializeResult(de, ok, err) {
+    const tag = de.pop()
+
+    switch (tag) {
+        case 0:
+            return { tag: 'ok', val: ok(de) }
+        case 1: 
+            return { tag: 'err', val: err(de) }
+        default:
+            throw new Error(`Deserialize bad result ${tag}`)
+    }
+}
+function deserializeList(de, inner) {
+    const len = deserializeU64(de);
+
+    let out = [];
+
+    for (let i = 0; i < len; i++) {
+        out.push(inner(de));   
+    }
+
+    return out;
+}
+function ser_varint(out, bits, val) {
+  let buf = []
+  for (let i = 0; i < varint_max[bits]; i++) {
+    const buffer = new ArrayBuffer(bits / 8);
+    const view = new DataView(buffer);
+    view.setInt16(0, val, true);
+    buf[i] = view.getUint8(0);
+    if (val < 128) {
+      out.push(...buf)
+      return;
+    }
+
+    buf[i] |= 0x80;
+    val >>= 7;
+  }
+  out.push(...buf)
+}
+
+function ser_varint_big(out, bits, val) {
+  let buf = []
+  for (let i = 0; i < varint_max[bits]; i++) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
+      out.push(...buf)
+      return;
+    }
+
+    buf[i] |= 0x80;
+    val >>= 7n;
+  }
+  out.push(...buf)
+}
+function serializeU32(out, val) {
+    return ser_varint(out, 32, val)
+}
+function serializeU64(out, val) {
+  return ser_varint_big(out, 64, BigInt(val))
+}
+function serializeOption(out, inner, val) {
+    serializeU8(out, !!val ? 1 : 0)
+    if (val) {
+        inner(out, val)
+    }
+}
+function serializeResult(out, ok, err, val) {
+    if (val.Ok) {
+        serializeU8(out, 0);
+        return ok(out, val.Ok);
+    }
+
+    if (val.Err) {
+        serializeU8(out, 1);
+        return err(out, val.Err);
+    }
+
+    throw new Error(`Serialize bad result ${val}`);
+}
+function serializeList(out, inner, val) {
+    serializeU64(out, val.length)
+    for (const el of val) {
+        inner(out, el)
+    }
+}
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 
 
 /**
@@ -89,6 +234,7 @@ export async function constructorA () {
     
 
     return fetch('ipc://localhost/resources/constructor_a', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -105,6 +251,7 @@ export async function constructorB () {
     
 
     return fetch('ipc://localhost/resources/constructor_b', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -113,27 +260,97 @@ export async function constructorB () {
         })
 }
 
+/**
+* @param {A} x
+*/
+export async function consume (x) {
+    const out = []
+    x.serialize(out)
+
+    return fetch('ipc://localhost/resources/consume', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
+        .then(r => {
+            x.transfer();
+            return r
+        })
+}
+
 
 export class A {
             #id;
+            static #registry = new FinalizationRegistry((id) => A.#drop(id).catch(console.error))
+
+            static #from(id) {
+                const self = new A()
+                self.#id = id
+                A.#registry.register(self, id, self)
+                return self
+            }
+
+            static async #drop(id) {
+                const out = []
+                serializeU64(out, id)
+
+                await fetch('ipc://localhost/resources::resource::a/drop', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+                    .then(checkResponse)
+            }
+
             /**
+* @param {number} a
+* @returns {Promise<A>} 
+*/
+static async create (a) {
+    const out = []
+    
+    serializeU32(out, a)
+
+    return fetch('ipc://localhost/resources::resource::a/constructor', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
+        .then(r => r.arrayBuffer())
+        .then(bytes => {
+            const de = new Deserializer(new Uint8Array(bytes))
+
+            return A.deserialize(de)
+        })
+}
+/**
+* @param {number} a
+* @returns {Promise<A | null>} 
+*/
+static async f0 (a) {
+    const out = []
+    
+    serializeU32(out, a)
+
+    return fetch('ipc://localhost/resources::resource::a/f0', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
+        .then(r => r.arrayBuffer())
+        .then(bytes => {
+            const de = new Deserializer(new Uint8Array(bytes))
+
+            return deserializeOption(de, (de) => A.deserialize(de))
+        })
+}
+/**
 */
 async f1 () {
     const out = []
-    serializeU32(out, this.#id);
+    serializeU64(out, this.#id);
     
 
-    await fetch('ipc://localhost/resources::resource::a/f1', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+    return fetch('ipc://localhost/resources::resource::a/f1', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 /**
 * @param {number} a
 */
 async f2 (a) {
     const out = []
-    serializeU32(out, this.#id);
+    serializeU64(out, this.#id);
     serializeU32(out, a)
 
-    await fetch('ipc://localhost/resources::resource::a/f2', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+    return fetch('ipc://localhost/resources::resource::a/f2', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 /**
 * @param {number} a
@@ -141,30 +358,65 @@ async f2 (a) {
 */
 async f3 (a, b) {
     const out = []
-    serializeU32(out, this.#id);
+    serializeU64(out, this.#id);
     serializeU32(out, a);
 serializeU32(out, b)
 
-    await fetch('ipc://localhost/resources::resource::a/f3', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+    return fetch('ipc://localhost/resources::resource::a/f3', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
+            /**
+            * Releases the resource without waiting for it to be garbage collected.
+            */
+            async dispose () {
+                if (A.#registry.unregister(this)) {
+                    await A.#drop(this.#id)
+                }
+            }
+            serialize(out) {
+    serializeU64(out, this.#id)
+}
+/**
+* Gives up the handle after the host took it over in a call, it can't be used afterwards.
+*/
+transfer() {
+    A.#registry.unregister(this)
+    this.#id = undefined
+}
             static deserialize(de) {
-    const self = new A();
-    self.#id = deserializeU32(de);
-    return self
+    return A.#from(deserializeU64(de))
 }
         }
 export class B {
             #id;
+            static #registry = new FinalizationRegistry((id) => B.#drop(id).catch(console.error))
+
+            static #from(id) {
+                const self = new B()
+                self.#id = id
+                B.#registry.register(self, id, self)
+                return self
+            }
+
+            static async #drop(id) {
+                const out = []
+                serializeU64(out, id)
+
+                await fetch('ipc://localhost/resources::resource::b/drop', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+                    .then(checkResponse)
+            }
+
             /**
 * @returns {Promise<A>} 
 */
 async f1 () {
     const out = []
-    serializeU32(out, this.#id);
+    serializeU64(out, this.#id);
     
 
-    await fetch('ipc://localhost/resources::resource::b/f1', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+    return fetch('ipc://localhost/resources::resource::b/f1', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -178,10 +430,11 @@ async f1 () {
 */
 async f2 (x) {
     const out = []
-    serializeU32(out, this.#id);
+    serializeU64(out, this.#id);
     x.serialize(out)
 
-    await fetch('ipc://localhost/resources::resource::b/f2', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+    return fetch('ipc://localhost/resources::resource::b/f2', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -195,10 +448,11 @@ async f2 (x) {
 */
 async f3 (x) {
     const out = []
-    serializeU32(out, this.#id);
+    serializeU64(out, this.#id);
     serializeOption(out, (out, v) => serializeList(out, (out, v) => v.serialize(out), v), x)
 
-    await fetch('ipc://localhost/resources::resource::b/f3', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+    return fetch('ipc://localhost/resources::resource::b/f3', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -206,10 +460,34 @@ async f3 (x) {
             return deserializeResult(de, (de) => A.deserialize(de), () => {})
         })
 }
+/**
+* @param {A} x
+* @param {A[]} y
+*/
+async f4 (x, y) {
+    const out = []
+    serializeU64(out, this.#id);
+    x.serialize(out);
+serializeList(out, (out, v) => v.serialize(out), y)
+
+    return fetch('ipc://localhost/resources::resource::b/f4', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
+        .then(r => {
+            y.forEach(v => { v.transfer(); });
+            return r
+        })
+}
 
+            /**
+            * Releases the resource without waiting for it to be garbage collected.
+            */
+            async dispose () {
+                if (B.#registry.unregister(this)) {
+                    await B.#drop(this.#id)
+                }
+            }
+            
             static deserialize(de) {
-    const self = new B();
-    self.#id = deserializeU32(de);
-    return self
+    return B.#from(deserializeU64(de))
 }
         }
\ No newline at end of file
@@ -187,11 +187,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -269,6 +267,49 @@ function serializeResult(out, ok, err, val) {
 }
 const __text_decoder = new TextDecoder('utf-8');
 const __text_encoder = new TextEncoder();
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 function deserializeE1(de) {
     const tag = deserializeU32(de)
 
@@ -563,6 +604,7 @@ export async function e1Arg (x) {
     serializeE1(out, x)
 
     return fetch('ipc://localhost/variants/e1_arg', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -573,6 +615,7 @@ export async function e1Result () {
     
 
     return fetch('ipc://localhost/variants/e1_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -589,6 +632,7 @@ export async function u1Arg (x) {
     serializeU1(out, x)
 
     return fetch('ipc://localhost/variants/u1_arg', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -599,6 +643,7 @@ export async function u1Result () {
     
 
     return fetch('ipc://localhost/variants/u1_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -615,6 +660,7 @@ export async function v1Arg (x) {
     serializeV1(out, x)
 
     return fetch('ipc://localhost/variants/v1_arg', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -625,6 +671,7 @@ export async function v1Result () {
     
 
     return fetch('ipc://localhost/variants/v1_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -641,6 +688,7 @@ export async function boolArg (x) {
     serializeBool(out, x)
 
     return fetch('ipc://localhost/variants/bool_arg', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -651,6 +699,7 @@ export async function boolResult () {
     
 
     return fetch('ipc://localhost/variants/bool_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -679,6 +728,7 @@ serializeOption(out, (out, v) => serializeU1(out, v), f);
 serializeOption(out, (out, v) => serializeOption(out, (out, v) => serializeBool(out, v), v), g)
 
     return fetch('ipc://localhost/variants/option_arg', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -689,6 +739,7 @@ export async function optionResult () {
     
 
     return fetch('ipc://localhost/variants/option_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -716,6 +767,7 @@ serializeCasts5(out, e);
 serializeCasts6(out, f)
 
     return fetch('ipc://localhost/variants/casts', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -742,6 +794,7 @@ serializeResult(out, (out, v) => serializeU32(out, v), (out, v) => serializeV1(o
 serializeResult(out, (out, v) => serializeString(out, v), (out, v) => serializeBytes(out, v), f)
 
     return fetch('ipc://localhost/variants/result_arg', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -752,6 +805,7 @@ export async function resultResult () {
     
 
     return fetch('ipc://localhost/variants/result_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -768,6 +822,7 @@ export async function returnResultSugar () {
     
 
     return fetch('ipc://localhost/variants/return_result_sugar', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -784,6 +839,7 @@ export async function returnResultSugar2 () {
     
 
     return fetch('ipc://localhost/variants/return_result_sugar2', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -800,6 +856,7 @@ export async function returnResultSugar3 () {
     
 
     return fetch('ipc://localhost/variants/return_result_sugar3', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -816,6 +873,7 @@ export async function returnResultSugar4 () {
     
 
     return fetch('ipc://localhost/variants/return_result_sugar4', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -832,6 +890,7 @@ export async function returnOptionSugar () {
     
 
     return fetch('ipc://localhost/variants/return_option_sugar', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -848,6 +907,7 @@ export async function returnOptionSugar2 () {
     
 
     return fetch('ipc://localhost/variants/return_option_sugar2', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -864,6 +924,7 @@ export async function resultSimple () {
     
 
     return fetch('ipc://localhost/variants/result_simple', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -880,6 +941,7 @@ export async function isCloneArg (a) {
     serializeIsClone(out, a)
 
     return fetch('ipc://localhost/variants/is_clone_arg', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
 }
 
 /**
@@ -890,6 +952,7 @@ export async function isCloneReturn () {
     
 
     return fetch('ipc://localhost/variants/is_clone_return', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -906,6 +969,7 @@ export async function returnNamedOption () {
     
 
     return fetch('ipc://localhost/variants/return_named_option', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -922,6 +986,7 @@ export async function returnNamedResult () {
     
 
     return fetch('ipc://localhost/variants/return_named_result', { method: "POST", body: Uint8Array.from(out), headers: { 'Content-Type': 'application/octet-stream' } })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
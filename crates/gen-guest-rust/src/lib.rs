@@ -8,14 +8,16 @@ use proc_macro2::TokenStream;
 use quote::format_ident;
 use quote::quote;
 use syn::parse_quote;
+use tauri_bindgen_core::names::{rust_ident, rust_type_name};
+use tauri_bindgen_core::union_case_names;
 use tauri_bindgen_core::Generate;
 use tauri_bindgen_core::GeneratorBuilder;
 use tauri_bindgen_core::TypeInfo;
 use tauri_bindgen_core::TypeInfos;
 use tauri_bindgen_gen_rust::FnSig;
-use tauri_bindgen_gen_rust::{BorrowMode, RustGenerator};
-use wit_parser::TypeDefKind;
-use wit_parser::{Function, Interface};
+use tauri_bindgen_gen_rust::{has_own, BorrowMode, RustGenerator};
+use wit_parser::{Event, Function, FunctionKind, Interface, Type};
+use wit_parser::{TypeDefArena, TypeDefId, TypeDefKind};
 
 #[derive(Default, Debug, Clone)]
 #[cfg_attr(feature = "clap", derive(clap::Args))]
@@ -24,12 +26,12 @@ pub struct Builder {
     #[cfg_attr(feature = "clap", clap(long))]
     pub fmt: bool,
 
-    /// Whether or not the bindings assume interface values are always
-    /// well-formed or whether checks are performed.
+    /// Whether or not the bindings assume calls always succeed, panicking when they don't,
+    /// instead of returning a `Result` with the error.
     #[cfg_attr(feature = "clap", clap(long))]
     pub unchecked: bool,
 
-    /// If true, code generation should avoid any features that depend on `std`.
+    /// If true, the generated code only uses `core` and `alloc`, for use with the runtime's `no_std` feature.
     #[cfg_attr(feature = "clap", clap(long))]
     pub no_std: bool,
 }
@@ -48,10 +50,11 @@ impl GeneratorBuilder for Builder {
             })
             .flatten();
 
-        let infos = TypeInfos::collect_from_functions(
+        let mut infos = TypeInfos::collect_from_functions(
             &interface.typedefs,
             interface.functions.iter().chain(methods),
         );
+        infos.collect_from_events(&interface.typedefs, &interface.events);
 
         Box::new(RustWasm {
             opts: self,
@@ -74,6 +77,7 @@ impl RustWasm {
             unsafe_: false,
             private: false,
             self_arg: None,
+            error: self.print_error(),
             func,
         };
 
@@ -85,17 +89,207 @@ impl RustWasm {
 
         let ident = func.id.to_snake_case();
 
-        let param_idents = func
+        let param_idents = print_param_exprs(func, &self.interface.typedefs);
+        let invoke = self.print_invoke(mod_ident, &ident, quote! { (#(#param_idents),*) }, func);
+
+        quote! {
+            #sig {
+                #invoke
+            }
+        }
+    }
+
+    /// Forgets the owned handles in `expr`, returns `None` if `ty` has none.
+    fn print_forget(&self, expr: TokenStream, ty: &Type) -> Option<TokenStream> {
+        match ty {
+            Type::Own(_) => Some(quote! { ::core::mem::forget(#expr); }),
+            Type::List(ty) => {
+                let inner = self.print_forget(quote! { v }, ty)?;
+
+                Some(quote! { for v in #expr { #inner } })
+            }
+            Type::Option(ty) => {
+                let inner = self.print_forget(quote! { v }, ty)?;
+
+                Some(quote! { if let Some(v) = #expr { #inner } })
+            }
+            Type::Tuple(types) => {
+                let idents: Vec<_> = (0..types.len()).map(|i| format_ident!("v{i}")).collect();
+                let inner: Vec<_> = idents
+                    .iter()
+                    .zip(types)
+                    .filter_map(|(ident, ty)| self.print_forget(quote! { #ident }, ty))
+                    .collect();
+
+                (!inner.is_empty()).then(|| {
+                    quote! {
+                        let (#(#idents,)*) = #expr;
+                        #(#inner)*
+                    }
+                })
+            }
+            Type::Result { ok, err } => {
+                let ok = ok
+                    .as_ref()
+                    .and_then(|ty| self.print_forget(quote! { v }, ty));
+                let err = err
+                    .as_ref()
+                    .and_then(|ty| self.print_forget(quote! { v }, ty));
+
+                if ok.is_none() && err.is_none() {
+                    return None;
+                }
+
+                let ok = ok.map_or(quote! { Ok(_) => {} }, |ok| quote! { Ok(v) => { #ok } });
+                let err = err.map_or(quote! { Err(_) => {} }, |err| quote! { Err(v) => { #err } });
+
+                Some(quote! { match #expr { #ok #err } })
+            }
+            Type::Id(id) => {
+                let typedef = &self.interface.typedefs[*id];
+
+                match &typedef.kind {
+                    TypeDefKind::Alias(ty) => self.print_forget(expr, ty),
+                    TypeDefKind::Record(fields) => {
+                        let inner: Vec<_> = fields
+                            .iter()
+                            .filter_map(|field| {
+                                let ident = format_ident!("{}", rust_ident(&field.id));
+
+                                self.print_forget(quote! { #expr.#ident }, &field.ty)
+                            })
+                            .collect();
+
+                        (!inner.is_empty()).then(|| quote! { #(#inner)* })
+                    }
+                    TypeDefKind::Variant(cases) => {
+                        let names = cases.iter().map(|case| rust_type_name(&case.id));
+                        let tys = cases.iter().map(|case| case.ty.as_ref());
+
+                        self.print_forget_cases(expr, *id, names.zip(tys))
+                    }
+                    TypeDefKind::Union(cases) => {
+                        let names = union_case_names(&self.interface.typedefs, cases);
+                        let tys = cases.iter().map(|case| Some(&case.ty));
+
+                        self.print_forget_cases(expr, *id, names.into_iter().zip(tys))
+                    }
+                    TypeDefKind::Flags(_) | TypeDefKind::Enum(_) | TypeDefKind::Resource(_) => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Forgets the owned handles in the payload of the case of variant or union `id` that `expr` holds.
+    fn print_forget_cases<'a>(
+        &self,
+        expr: TokenStream,
+        id: TypeDefId,
+        cases: impl Iterator<Item = (String, Option<&'a Type>)>,
+    ) -> Option<TokenStream> {
+        let typedef = &self.interface.typedefs[id];
+        let ident = if self.uses_two_names(self.infos[id]) {
+            format_ident!("{}Param", rust_type_name(&typedef.ident))
+        } else {
+            format_ident!("{}", rust_type_name(&typedef.ident))
+        };
+
+        let mut all_cases = true;
+        let arms: Vec<_> = cases
+            .filter_map(|(name, ty)| {
+                let inner = ty.and_then(|ty| self.print_forget(quote! { v }, ty));
+                all_cases &= inner.is_some();
+                let case = format_ident!("{name}");
+
+                inner.map(|inner| quote! { #ident::#case(v) => { #inner } })
+            })
+            .collect();
+
+        if arms.is_empty() {
+            return None;
+        }
+
+        let rest = (!all_cases).then(|| quote! { _ => {} });
+
+        Some(quote! { match #expr { #(#arms)* #rest } })
+    }
+
+    /// Invokes `func` on the host, then forgets the handles it handed over with `own` if the call succeeded,
+    /// so dropping them doesn't release the resources the host owns now.
+    fn print_invoke(
+        &self,
+        mod_ident: &str,
+        func_name: &str,
+        params: TokenStream,
+        func: &Function,
+    ) -> TokenStream {
+        let unwrap = self.print_unwrap();
+        let invoke =
+            quote! { ::tauri_bindgen_guest_rust::invoke(#mod_ident, #func_name, &#params).await };
+
+        let forget: Vec<_> = func
             .params
             .iter()
-            .map(|(ident, _)| format_ident!("{}", ident));
+            .filter_map(|(ident, ty)| {
+                let ident = format_ident!("{}", rust_ident(ident));
+
+                self.print_forget(quote! { #ident }, ty)
+            })
+            .collect();
+
+        if forget.is_empty() {
+            quote! { #invoke #unwrap }
+        } else {
+            quote! {
+                let ret = #invoke;
+                if ret.is_ok() {
+                    #(#forget)*
+                }
+                ret #unwrap
+            }
+        }
+    }
+
+    pub fn print_event(&self, mod_ident: &str, event: &Event) -> TokenStream {
+        let docs = self.print_docs(&event.docs);
+        let ident = format_ident!("on_{}", event.id.to_snake_case());
+        let name = format!("{mod_ident}::{}", event.id.to_snake_case());
+
+        let tys: Vec<_> = event
+            .payload
+            .iter()
+            .map(|(_, ty)| self.print_ty(ty, &BorrowMode::Owned))
+            .collect();
+        let fields = (0..tys.len()).map(syn::Index::from);
+
+        let subscription = quote! { ::tauri_bindgen_guest_rust::Subscription };
+        let ret = match self.print_error() {
+            Some(error) => quote! { Result<#subscription, #error> },
+            None => subscription,
+        };
+        let unwrap = self.print_unwrap();
 
         quote! {
-            #sig {
-                ::tauri_bindgen_guest_rust::invoke(#mod_ident, #ident, &(#(#param_idents),*)).await.unwrap()
+            #docs
+            pub async fn #ident(handler: impl Fn(#(#tys),*) + 'static) -> #ret {
+                ::tauri_bindgen_guest_rust::listen(#name, move |payload: (#(#tys,)*)| {
+                    handler(#(payload.#fields),*)
+                })
+                .await #unwrap
             }
         }
     }
+
+    /// Checked bindings return IPC failures to the caller.
+    fn print_error(&self) -> Option<TokenStream> {
+        (!self.opts.unchecked).then(|| quote! { ::tauri_bindgen_guest_rust::Error })
+    }
+
+    /// Unchecked bindings assume calls always succeed and panic otherwise.
+    fn print_unwrap(&self) -> Option<TokenStream> {
+        self.opts.unchecked.then(|| quote! { .unwrap() })
+    }
 }
 
 impl RustGenerator for RustWasm {
@@ -131,6 +325,10 @@ impl RustGenerator for RustWasm {
         BorrowMode::AllBorrowed(parse_quote!('a))
     }
 
+    fn no_std(&self) -> bool {
+        self.opts.no_std
+    }
+
     fn print_resource(
         &self,
         mod_ident: &str,
@@ -141,13 +339,30 @@ impl RustGenerator for RustWasm {
     ) -> TokenStream {
         let docs = self.print_docs(docs);
         let additional_attrs = self.additional_attrs(&ident.to_string(), info);
+        let mod_ident = format!(
+            "{mod_ident}::resource::{}",
+            ident.to_string().to_snake_case()
+        );
+        let unwrap = self.print_unwrap();
+
         let functions = functions.iter().map(|func| {
+            // constructors become `new`, but keep their name on the wire
+            let constructor;
             let sig = FnSig {
                 async_: true,
                 unsafe_: false,
                 private: false,
-                self_arg: Some(quote!(&self)),
-                func,
+                self_arg: func.kind.is_method().then(|| quote!(&self)),
+                error: self.print_error(),
+                func: if func.kind == FunctionKind::Constructor {
+                    constructor = Function {
+                        id: "new".to_string(),
+                        ..func.clone()
+                    };
+                    &constructor
+                } else {
+                    func
+                },
             };
 
             let sig = self.print_function_signature(
@@ -156,38 +371,74 @@ impl RustGenerator for RustWasm {
                 &BorrowMode::Owned,
             );
 
-            let mod_ident = format!("{mod_ident}::resource::{}", ident.to_string().to_snake_case());
             let ident = func.id.to_snake_case();
 
-            let param_idents = func
-                .params
-                .iter()
-                .map(|(ident, _)| format_ident!("{}", ident));
+            let param_idents = print_param_exprs(func, &self.interface.typedefs);
+            let params = if func.kind.is_method() {
+                quote! { (self.0, #(#param_idents),*) }
+            } else {
+                quote! { (#(#param_idents,)*) }
+            };
+            let invoke = self.print_invoke(&mod_ident, &ident, params, func);
 
             quote! {
                 #sig {
-                    ::tauri_bindgen_guest_rust::invoke(#mod_ident, #ident, &(self.0, #(#param_idents),*)).await.unwrap()
+                    #invoke
                 }
             }
         });
 
+        let dispose_ret = self
+            .print_error()
+            .map(|error| quote! { -> Result<(), #error> });
+
         quote! {
             #docs
             #additional_attrs
-            pub struct #ident(u32);
+            #[derive(Debug, PartialEq, Eq)]
+            pub struct #ident(u64);
 
             impl #ident {
                 #(#functions)*
+
+                /// Releases the resource and waits for the host to drop it.
+                pub async fn dispose(self) #dispose_ret {
+                    let id = self.0;
+                    ::core::mem::forget(self);
+                    ::tauri_bindgen_guest_rust::invoke(#mod_ident, "drop", &(id,)).await #unwrap
+                }
+            }
+
+            impl Drop for #ident {
+                fn drop(&mut self) {
+                    ::tauri_bindgen_guest_rust::release(#mod_ident, self.0);
+                }
             }
         }
     }
 }
 
+/// The parameters as they're sent, owned handles are only borrowed for serializing so they can be forgotten afterwards.
+fn print_param_exprs(func: &Function, typedefs: &TypeDefArena) -> Vec<TokenStream> {
+    func.params
+        .iter()
+        .map(|(ident, ty)| {
+            let ident = format_ident!("{}", rust_ident(ident));
+
+            if has_own(ty, typedefs) {
+                quote! { &#ident }
+            } else {
+                quote! { #ident }
+            }
+        })
+        .collect()
+}
+
 impl tauri_bindgen_core::Generate for RustWasm {
     fn to_tokens(&mut self) -> TokenStream {
         let docs = self.print_docs(&self.interface.docs);
 
-        let ident = format_ident!("{}", self.interface.ident.to_snake_case());
+        let ident = format_ident!("{}", rust_ident(&self.interface.ident));
 
         let typedefs = self.print_typedefs(
             self.interface.typedefs.iter().map(|(id, _)| id),
@@ -200,6 +451,19 @@ impl tauri_bindgen_core::Generate for RustWasm {
             .iter()
             .map(|func| self.print_function(&self.interface.ident.to_snake_case(), func));
 
+        let events = self
+            .interface
+            .events
+            .iter()
+            .map(|event| self.print_event(&self.interface.ident.to_snake_case(), event));
+
+        let no_std_imports = self.opts.no_std.then(|| {
+            quote! {
+                use ::tauri_bindgen_guest_rust::alloc;
+                use ::core::{option::Option, result::Result};
+            }
+        });
+
         quote! {
             #docs
             #[allow(unused_imports, unused_variables, dead_code)]
@@ -208,9 +472,12 @@ impl tauri_bindgen_core::Generate for RustWasm {
             pub mod #ident {
                 use ::tauri_bindgen_guest_rust::serde;
                 use ::tauri_bindgen_guest_rust::bitflags;
+                #no_std_imports
                 #typedefs
 
                 #(#functions)*
+
+                #(#events)*
             }
         }
     }
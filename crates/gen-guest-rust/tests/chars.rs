@@ -5,11 +5,11 @@ pub mod chars {
     use ::tauri_bindgen_guest_rust::serde;
     use ::tauri_bindgen_guest_rust::bitflags;
     ///A function that accepts a character
-    pub async fn take_char(x: char) {
-        ::tauri_bindgen_guest_rust::invoke("chars", "take_char", &(x)).await.unwrap()
+    pub async fn take_char(x: char) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("chars", "take_char", &(x)).await
     }
     ///A function that returns a character
-    pub async fn return_char() -> char {
-        ::tauri_bindgen_guest_rust::invoke("chars", "return_char", &()).await.unwrap()
+    pub async fn return_char() -> Result<char, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("chars", "return_char", &()).await
     }
 }
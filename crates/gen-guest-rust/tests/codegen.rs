@@ -270,3 +270,50 @@ fn variants() {
     assert_eq!(filename, "variants.rs");
     assert_eq!(contents, include_str!("./variants.rs"));
 }
+
+#[test]
+fn unchecked() {
+    let opts = Builder {
+        fmt: true,
+        no_std: false,
+        unchecked: true,
+    };
+
+    let (filename, contents) = gen_interface(
+        opts,
+        "resources",
+        include_str!("../../../wit/resources.wit"),
+    );
+
+    assert_eq!(filename, "resources.rs");
+    assert_eq!(contents, include_str!("./unchecked/resources.rs"));
+}
+
+#[test]
+fn no_std() {
+    let opts = Builder {
+        fmt: true,
+        no_std: true,
+        unchecked: false,
+    };
+
+    let (filename, contents) = gen_interface(opts, "lists", include_str!("../../../wit/lists.wit"));
+
+    assert_eq!(filename, "lists.rs");
+    assert_eq!(contents, include_str!("./no_std/lists.rs"));
+}
+
+#[test]
+fn events() {
+    let opts = Builder {
+        fmt: true,
+        no_std: false,
+        unchecked: false,
+    };
+
+    let (filename, contents) =
+        gen_interface(opts, "events", include_str!("../../../wit/events.wit"));
+
+    assert_eq!(filename, "events.rs");
+    assert_eq!(contents, include_str!("./events.rs"));
+}
@@ -10,60 +10,64 @@ pub mod conventions {
         pub how_fast_are_you_going: u32,
         pub i_am_going_extremely_slow: u64,
     }
-    pub async fn kebab_case() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "kebab_case", &())
-            .await
-            .unwrap()
+    pub async fn kebab_case() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "kebab_case", &()).await
     }
-    pub async fn foo(x: LudicrousSpeed) {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "foo", &(x)).await.unwrap()
+    pub async fn foo(
+        x: LudicrousSpeed,
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "foo", &(x)).await
     }
-    pub async fn function_with_underscores() {
+    pub async fn function_with_underscores() -> Result<
+        (),
+        ::tauri_bindgen_guest_rust::Error,
+    > {
         ::tauri_bindgen_guest_rust::invoke(
                 "conventions",
                 "function_with_underscores",
                 &(),
             )
             .await
-            .unwrap()
     }
-    pub async fn function_with_no_weird_characters() {
+    pub async fn function_with_no_weird_characters() -> Result<
+        (),
+        ::tauri_bindgen_guest_rust::Error,
+    > {
         ::tauri_bindgen_guest_rust::invoke(
                 "conventions",
                 "function_with_no_weird_characters",
                 &(),
             )
             .await
-            .unwrap()
     }
-    pub async fn apple() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "apple", &()).await.unwrap()
+    pub async fn apple() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "apple", &()).await
     }
-    pub async fn apple_pear() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "apple_pear", &())
-            .await
-            .unwrap()
+    pub async fn apple_pear() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "apple_pear", &()).await
     }
-    pub async fn apple_pear_grape() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "apple_pear_grape", &())
-            .await
-            .unwrap()
+    pub async fn apple_pear_grape() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "apple_pear_grape", &()).await
     }
-    pub async fn a0() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "a0", &()).await.unwrap()
+    pub async fn a0() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "a0", &()).await
     }
-    pub async fn is_xml() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "is_xml", &()).await.unwrap()
+    pub async fn is_xml() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "is_xml", &()).await
     }
-    pub async fn explicit() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "explicit", &()).await.unwrap()
+    pub async fn explicit() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "explicit", &()).await
     }
-    pub async fn explicit_snake() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "explicit_snake", &())
-            .await
-            .unwrap()
+    pub async fn explicit_snake() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "explicit_snake", &()).await
+    }
+    pub async fn bool() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "bool", &()).await
     }
-    pub async fn bool() {
-        ::tauri_bindgen_guest_rust::invoke("conventions", "bool", &()).await.unwrap()
+    pub async fn r#type(
+        self_: u32,
+        class: &'_ str,
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("conventions", "type", &(self_, class)).await
     }
 }
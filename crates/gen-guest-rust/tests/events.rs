@@ -0,0 +1,47 @@
+///Events the host sends to the frontend.
+#[allow(unused_imports, unused_variables, dead_code)]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+pub mod events {
+    use ::tauri_bindgen_guest_rust::serde;
+    use ::tauri_bindgen_guest_rust::bitflags;
+    ///How far a task got.
+    #[derive(serde::Deserialize)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Progress {
+        pub done: u32,
+        pub total: u32,
+    }
+    ///Starts a task that reports its progress through events.
+    pub async fn start() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("events", "start", &()).await
+    }
+    ///Emitted whenever the task made progress.
+    pub async fn on_progress_changed(
+        handler: impl Fn(Progress) + 'static,
+    ) -> Result<
+        ::tauri_bindgen_guest_rust::Subscription,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::listen(
+                "events::progress_changed",
+                move |payload: (Progress,)| { handler(payload.0) },
+            )
+            .await
+    }
+    ///Emitted once the task finished, with an error message if it failed.
+    pub async fn on_finished(
+        handler: impl Fn(String, Option<String>) + 'static,
+    ) -> Result<
+        ::tauri_bindgen_guest_rust::Subscription,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::listen(
+                "events::finished",
+                move |payload: (String, Option<String>)| {
+                    handler(payload.0, payload.1)
+                },
+            )
+            .await
+    }
+}
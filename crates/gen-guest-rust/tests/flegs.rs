@@ -59,39 +59,39 @@ pub mod flegs {
         58; const B59 = 1 << 59; const B60 = 1 << 60; const B61 = 1 << 61; const B62 = 1
         << 62; const B63 = 1 << 63; }
     }
-    pub async fn roundtrip_flag1(x: Flag1) -> Flag1 {
-        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag1", &(x))
-            .await
-            .unwrap()
+    pub async fn roundtrip_flag1(
+        x: Flag1,
+    ) -> Result<Flag1, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag1", &(x)).await
     }
-    pub async fn roundtrip_flag2(x: Flag2) -> Flag2 {
-        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag2", &(x))
-            .await
-            .unwrap()
+    pub async fn roundtrip_flag2(
+        x: Flag2,
+    ) -> Result<Flag2, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag2", &(x)).await
     }
-    pub async fn roundtrip_flag4(x: Flag4) -> Flag4 {
-        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag4", &(x))
-            .await
-            .unwrap()
+    pub async fn roundtrip_flag4(
+        x: Flag4,
+    ) -> Result<Flag4, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag4", &(x)).await
     }
-    pub async fn roundtrip_flag8(x: Flag8) -> Flag8 {
-        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag8", &(x))
-            .await
-            .unwrap()
+    pub async fn roundtrip_flag8(
+        x: Flag8,
+    ) -> Result<Flag8, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag8", &(x)).await
     }
-    pub async fn roundtrip_flag16(x: Flag16) -> Flag16 {
-        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag16", &(x))
-            .await
-            .unwrap()
+    pub async fn roundtrip_flag16(
+        x: Flag16,
+    ) -> Result<Flag16, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag16", &(x)).await
     }
-    pub async fn roundtrip_flag32(x: Flag32) -> Flag32 {
-        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag32", &(x))
-            .await
-            .unwrap()
+    pub async fn roundtrip_flag32(
+        x: Flag32,
+    ) -> Result<Flag32, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag32", &(x)).await
     }
-    pub async fn roundtrip_flag64(x: Flag64) -> Flag64 {
-        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag64", &(x))
-            .await
-            .unwrap()
+    pub async fn roundtrip_flag64(
+        x: Flag64,
+    ) -> Result<Flag64, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("flegs", "roundtrip_flag64", &(x)).await
     }
 }
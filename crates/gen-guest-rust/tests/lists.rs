@@ -87,141 +87,158 @@ pub mod lists {
     pub type LoadStoreAllSizesResult = Vec<
         (String, u8, i8, u16, i16, u32, i32, u64, i64, f32, f64, char),
     >;
-    pub async fn list_u8_param(x: &'_ [u8]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u8_param", &(x)).await.unwrap()
-    }
-    pub async fn list_u16_param(x: &'_ [u16]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u16_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_u32_param(x: &'_ [u32]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u32_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_u64_param(x: &'_ [u64]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u64_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_u128_param(x: &'_ [u128]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u128_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_s8_param(x: &'_ [i8]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s8_param", &(x)).await.unwrap()
-    }
-    pub async fn list_s16_param(x: &'_ [i16]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s16_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_s32_param(x: &'_ [i32]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s32_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_s64_param(x: &'_ [i64]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s64_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_s128_param(x: &'_ [i128]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s128_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_float32_param(x: &'_ [f32]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_float32_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_float64_param(x: &'_ [f64]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_float64_param", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn list_u8_ret() -> Vec<u8> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u8_ret", &()).await.unwrap()
-    }
-    pub async fn list_u16_ret() -> Vec<u16> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u16_ret", &()).await.unwrap()
-    }
-    pub async fn list_u32_ret() -> Vec<u32> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u32_ret", &()).await.unwrap()
-    }
-    pub async fn list_u64_ret() -> Vec<u64> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u64_ret", &()).await.unwrap()
-    }
-    pub async fn list_u128_ret() -> Vec<u128> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_u128_ret", &()).await.unwrap()
-    }
-    pub async fn list_s8_ret() -> Vec<i8> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s8_ret", &()).await.unwrap()
-    }
-    pub async fn list_s16_ret() -> Vec<i16> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s16_ret", &()).await.unwrap()
-    }
-    pub async fn list_s32_ret() -> Vec<i32> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s32_ret", &()).await.unwrap()
-    }
-    pub async fn list_s64_ret() -> Vec<i64> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s64_ret", &()).await.unwrap()
-    }
-    pub async fn list_s128_ret() -> Vec<i128> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_s128_ret", &()).await.unwrap()
-    }
-    pub async fn list_float32_ret() -> Vec<f32> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_float32_ret", &())
-            .await
-            .unwrap()
-    }
-    pub async fn list_float64_ret() -> Vec<f64> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "list_float64_ret", &())
-            .await
-            .unwrap()
-    }
-    pub async fn tuple_list(x: &'_ [(u8, i8)]) -> Vec<(i64, u32)> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "tuple_list", &(x)).await.unwrap()
-    }
-    pub async fn string_list_arg(a: &'_ [&'_ str]) {
-        ::tauri_bindgen_guest_rust::invoke("lists", "string_list_arg", &(a))
-            .await
-            .unwrap()
-    }
-    pub async fn string_list_ret() -> Vec<String> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "string_list_ret", &())
-            .await
-            .unwrap()
-    }
-    pub async fn tuple_string_list(x: &'_ [(u8, &'_ str)]) -> Vec<(String, u8)> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "tuple_string_list", &(x))
-            .await
-            .unwrap()
-    }
-    pub async fn string_list(x: &'_ [&'_ str]) -> Vec<String> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "string_list", &(x)).await.unwrap()
-    }
-    pub async fn record_list(x: &'_ [SomeRecordParam<'_>]) -> Vec<OtherRecordResult> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "record_list", &(x)).await.unwrap()
+    pub async fn list_u8_param(
+        x: &'_ [u8],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u8_param", &(x)).await
+    }
+    pub async fn list_u16_param(
+        x: &'_ [u16],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u16_param", &(x)).await
+    }
+    pub async fn list_u32_param(
+        x: &'_ [u32],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u32_param", &(x)).await
+    }
+    pub async fn list_u64_param(
+        x: &'_ [u64],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u64_param", &(x)).await
+    }
+    pub async fn list_u128_param(
+        x: &'_ [u128],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u128_param", &(x)).await
+    }
+    pub async fn list_s8_param(
+        x: &'_ [i8],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s8_param", &(x)).await
+    }
+    pub async fn list_s16_param(
+        x: &'_ [i16],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s16_param", &(x)).await
+    }
+    pub async fn list_s32_param(
+        x: &'_ [i32],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s32_param", &(x)).await
+    }
+    pub async fn list_s64_param(
+        x: &'_ [i64],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s64_param", &(x)).await
+    }
+    pub async fn list_s128_param(
+        x: &'_ [i128],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s128_param", &(x)).await
+    }
+    pub async fn list_float32_param(
+        x: &'_ [f32],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_float32_param", &(x)).await
+    }
+    pub async fn list_float64_param(
+        x: &'_ [f64],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_float64_param", &(x)).await
+    }
+    pub async fn list_u8_ret() -> Result<Vec<u8>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u8_ret", &()).await
+    }
+    pub async fn list_u16_ret() -> Result<Vec<u16>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u16_ret", &()).await
+    }
+    pub async fn list_u32_ret() -> Result<Vec<u32>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u32_ret", &()).await
+    }
+    pub async fn list_u64_ret() -> Result<Vec<u64>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u64_ret", &()).await
+    }
+    pub async fn list_u128_ret() -> Result<
+        Vec<u128>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_u128_ret", &()).await
+    }
+    pub async fn list_s8_ret() -> Result<Vec<i8>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s8_ret", &()).await
+    }
+    pub async fn list_s16_ret() -> Result<Vec<i16>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s16_ret", &()).await
+    }
+    pub async fn list_s32_ret() -> Result<Vec<i32>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s32_ret", &()).await
+    }
+    pub async fn list_s64_ret() -> Result<Vec<i64>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s64_ret", &()).await
+    }
+    pub async fn list_s128_ret() -> Result<
+        Vec<i128>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_s128_ret", &()).await
+    }
+    pub async fn list_float32_ret() -> Result<
+        Vec<f32>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_float32_ret", &()).await
+    }
+    pub async fn list_float64_ret() -> Result<
+        Vec<f64>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("lists", "list_float64_ret", &()).await
+    }
+    pub async fn tuple_list(
+        x: &'_ [(u8, i8)],
+    ) -> Result<Vec<(i64, u32)>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "tuple_list", &(x)).await
+    }
+    pub async fn string_list_arg(
+        a: &'_ [&'_ str],
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "string_list_arg", &(a)).await
+    }
+    pub async fn string_list_ret() -> Result<
+        Vec<String>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("lists", "string_list_ret", &()).await
+    }
+    pub async fn tuple_string_list(
+        x: &'_ [(u8, &'_ str)],
+    ) -> Result<Vec<(String, u8)>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "tuple_string_list", &(x)).await
+    }
+    pub async fn string_list(
+        x: &'_ [&'_ str],
+    ) -> Result<Vec<String>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "string_list", &(x)).await
+    }
+    pub async fn record_list(
+        x: &'_ [SomeRecordParam<'_>],
+    ) -> Result<Vec<OtherRecordResult>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "record_list", &(x)).await
     }
     pub async fn record_list_reverse(
         x: &'_ [OtherRecordParam<'_>],
-    ) -> Vec<SomeRecordResult> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "record_list_reverse", &(x))
-            .await
-            .unwrap()
+    ) -> Result<Vec<SomeRecordResult>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "record_list_reverse", &(x)).await
     }
-    pub async fn variant_list(x: &'_ [SomeVariant<'_>]) -> Vec<OtherVariantResult> {
-        ::tauri_bindgen_guest_rust::invoke("lists", "variant_list", &(x)).await.unwrap()
+    pub async fn variant_list(
+        x: &'_ [SomeVariant<'_>],
+    ) -> Result<Vec<OtherVariantResult>, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "variant_list", &(x)).await
     }
     pub async fn load_store_everything(
         a: LoadStoreAllSizesParam<'_>,
-    ) -> LoadStoreAllSizesResult {
-        ::tauri_bindgen_guest_rust::invoke("lists", "load_store_everything", &(a))
-            .await
-            .unwrap()
+    ) -> Result<LoadStoreAllSizesResult, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("lists", "load_store_everything", &(a)).await
     }
 }
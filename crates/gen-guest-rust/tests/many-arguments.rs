@@ -45,18 +45,17 @@ pub mod many_arguments {
         a14: u64,
         a15: u64,
         a16: u64,
-    ) {
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
         ::tauri_bindgen_guest_rust::invoke(
                 "many_arguments",
                 "many_args",
                 &(a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15, a16),
             )
             .await
-            .unwrap()
     }
-    pub async fn big_argument(x: BigStruct<'_>) {
-        ::tauri_bindgen_guest_rust::invoke("many_arguments", "big_argument", &(x))
-            .await
-            .unwrap()
+    pub async fn big_argument(
+        x: BigStruct<'_>,
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("many_arguments", "big_argument", &(x)).await
     }
 }
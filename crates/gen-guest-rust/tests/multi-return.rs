@@ -4,19 +4,19 @@
 pub mod multi_return {
     use ::tauri_bindgen_guest_rust::serde;
     use ::tauri_bindgen_guest_rust::bitflags;
-    pub async fn mra() {
-        ::tauri_bindgen_guest_rust::invoke("multi_return", "mra", &()).await.unwrap()
+    pub async fn mra() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("multi_return", "mra", &()).await
     }
-    pub async fn mrb() {
-        ::tauri_bindgen_guest_rust::invoke("multi_return", "mrb", &()).await.unwrap()
+    pub async fn mrb() -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("multi_return", "mrb", &()).await
     }
-    pub async fn mrc() -> u32 {
-        ::tauri_bindgen_guest_rust::invoke("multi_return", "mrc", &()).await.unwrap()
+    pub async fn mrc() -> Result<u32, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("multi_return", "mrc", &()).await
     }
-    pub async fn mrd() -> u32 {
-        ::tauri_bindgen_guest_rust::invoke("multi_return", "mrd", &()).await.unwrap()
+    pub async fn mrd() -> Result<u32, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("multi_return", "mrd", &()).await
     }
-    pub async fn mre() -> (u32, f32) {
-        ::tauri_bindgen_guest_rust::invoke("multi_return", "mre", &()).await.unwrap()
+    pub async fn mre() -> Result<(u32, f32), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("multi_return", "mre", &()).await
     }
 }
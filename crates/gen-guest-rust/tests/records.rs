@@ -52,45 +52,56 @@ All of the fields are bool*/
     }
     pub type IntTypedef = i32;
     pub type TupleTypedef2 = (IntTypedef,);
-    pub async fn tuple_arg(x: (char, u32)) {
-        ::tauri_bindgen_guest_rust::invoke("records", "tuple_arg", &(x)).await.unwrap()
+    pub async fn tuple_arg(
+        x: (char, u32),
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("records", "tuple_arg", &(x)).await
     }
-    pub async fn tuple_result() -> (char, u32) {
-        ::tauri_bindgen_guest_rust::invoke("records", "tuple_result", &()).await.unwrap()
+    pub async fn tuple_result() -> Result<
+        (char, u32),
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("records", "tuple_result", &()).await
     }
-    pub async fn empty_arg(x: Empty) {
-        ::tauri_bindgen_guest_rust::invoke("records", "empty_arg", &(x)).await.unwrap()
+    pub async fn empty_arg(x: Empty) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("records", "empty_arg", &(x)).await
     }
-    pub async fn empty_result() -> Empty {
-        ::tauri_bindgen_guest_rust::invoke("records", "empty_result", &()).await.unwrap()
+    pub async fn empty_result() -> Result<Empty, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("records", "empty_result", &()).await
     }
-    pub async fn scalar_arg(x: Scalars) {
-        ::tauri_bindgen_guest_rust::invoke("records", "scalar_arg", &(x)).await.unwrap()
+    pub async fn scalar_arg(
+        x: Scalars,
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("records", "scalar_arg", &(x)).await
     }
-    pub async fn scalar_result() -> Scalars {
-        ::tauri_bindgen_guest_rust::invoke("records", "scalar_result", &())
-            .await
-            .unwrap()
+    pub async fn scalar_result() -> Result<Scalars, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("records", "scalar_result", &()).await
     }
-    pub async fn flags_arg(x: ReallyFlags) {
-        ::tauri_bindgen_guest_rust::invoke("records", "flags_arg", &(x)).await.unwrap()
+    pub async fn flags_arg(
+        x: ReallyFlags,
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("records", "flags_arg", &(x)).await
     }
-    pub async fn flags_result() -> ReallyFlags {
-        ::tauri_bindgen_guest_rust::invoke("records", "flags_result", &()).await.unwrap()
+    pub async fn flags_result() -> Result<
+        ReallyFlags,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("records", "flags_result", &()).await
     }
-    pub async fn aggregate_arg(x: AggregatesParam<'_>) {
-        ::tauri_bindgen_guest_rust::invoke("records", "aggregate_arg", &(x))
-            .await
-            .unwrap()
+    pub async fn aggregate_arg(
+        x: AggregatesParam<'_>,
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("records", "aggregate_arg", &(x)).await
     }
-    pub async fn aggregate_result() -> AggregatesResult {
-        ::tauri_bindgen_guest_rust::invoke("records", "aggregate_result", &())
-            .await
-            .unwrap()
+    pub async fn aggregate_result() -> Result<
+        AggregatesResult,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("records", "aggregate_result", &()).await
     }
-    pub async fn typedef_inout(e: TupleTypedef2) -> i32 {
-        ::tauri_bindgen_guest_rust::invoke("records", "typedef_inout", &(e))
-            .await
-            .unwrap()
+    pub async fn typedef_inout(
+        e: TupleTypedef2,
+    ) -> Result<i32, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("records", "typedef_inout", &(e)).await
     }
 }
@@ -10,9 +10,10 @@ pub mod small_anonymous {
         Success,
         Failure,
     }
-    pub async fn option_test() -> Result<Option<String>, Error> {
-        ::tauri_bindgen_guest_rust::invoke("small_anonymous", "option_test", &())
-            .await
-            .unwrap()
+    pub async fn option_test() -> Result<
+        Result<Option<String>, Error>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("small_anonymous", "option_test", &()).await
     }
 }
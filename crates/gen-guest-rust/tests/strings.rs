@@ -4,13 +4,16 @@
 pub mod strings {
     use ::tauri_bindgen_guest_rust::serde;
     use ::tauri_bindgen_guest_rust::bitflags;
-    pub async fn a(x: &'_ str) {
-        ::tauri_bindgen_guest_rust::invoke("strings", "a", &(x)).await.unwrap()
+    pub async fn a(x: &'_ str) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("strings", "a", &(x)).await
     }
-    pub async fn b() -> String {
-        ::tauri_bindgen_guest_rust::invoke("strings", "b", &()).await.unwrap()
+    pub async fn b() -> Result<String, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("strings", "b", &()).await
     }
-    pub async fn c(a: &'_ str, b: &'_ str) -> String {
-        ::tauri_bindgen_guest_rust::invoke("strings", "c", &(a, b)).await.unwrap()
+    pub async fn c(
+        a: &'_ str,
+        b: &'_ str,
+    ) -> Result<String, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("strings", "c", &(a, b)).await
     }
 }
@@ -0,0 +1,143 @@
+#[allow(unused_imports, unused_variables, dead_code)]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+pub mod resources {
+    use ::tauri_bindgen_guest_rust::serde;
+    use ::tauri_bindgen_guest_rust::bitflags;
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct A(u64);
+    impl A {
+        pub async fn new(a: u32) -> A {
+            ::tauri_bindgen_guest_rust::invoke(
+                    "resources::resource::a",
+                    "constructor",
+                    &(a,),
+                )
+                .await
+                .unwrap()
+        }
+        pub async fn f0(a: u32) -> Option<A> {
+            ::tauri_bindgen_guest_rust::invoke("resources::resource::a", "f0", &(a,))
+                .await
+                .unwrap()
+        }
+        pub async fn f1(&self) {
+            ::tauri_bindgen_guest_rust::invoke(
+                    "resources::resource::a",
+                    "f1",
+                    &(self.0,),
+                )
+                .await
+                .unwrap()
+        }
+        pub async fn f2(&self, a: u32) {
+            ::tauri_bindgen_guest_rust::invoke(
+                    "resources::resource::a",
+                    "f2",
+                    &(self.0, a),
+                )
+                .await
+                .unwrap()
+        }
+        pub async fn f3(&self, a: u32, b: u32) {
+            ::tauri_bindgen_guest_rust::invoke(
+                    "resources::resource::a",
+                    "f3",
+                    &(self.0, a, b),
+                )
+                .await
+                .unwrap()
+        }
+        /// Releases the resource and waits for the host to drop it.
+        pub async fn dispose(self) {
+            let id = self.0;
+            ::core::mem::forget(self);
+            ::tauri_bindgen_guest_rust::invoke("resources::resource::a", "drop", &(id,))
+                .await
+                .unwrap()
+        }
+    }
+    impl Drop for A {
+        fn drop(&mut self) {
+            ::tauri_bindgen_guest_rust::release("resources::resource::a", self.0);
+        }
+    }
+    #[derive(serde::Deserialize)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct B(u64);
+    impl B {
+        pub async fn f1(&self) -> A {
+            ::tauri_bindgen_guest_rust::invoke(
+                    "resources::resource::b",
+                    "f1",
+                    &(self.0,),
+                )
+                .await
+                .unwrap()
+        }
+        pub async fn f2(&self, x: &'_ A) -> Result<u32, ()> {
+            ::tauri_bindgen_guest_rust::invoke(
+                    "resources::resource::b",
+                    "f2",
+                    &(self.0, x),
+                )
+                .await
+                .unwrap()
+        }
+        pub async fn f3(&self, x: Option<&'_ [&'_ A]>) -> Result<A, ()> {
+            ::tauri_bindgen_guest_rust::invoke(
+                    "resources::resource::b",
+                    "f3",
+                    &(self.0, x),
+                )
+                .await
+                .unwrap()
+        }
+        pub async fn f4(&self, x: &'_ A, y: Vec<A>) {
+            let ret = ::tauri_bindgen_guest_rust::invoke(
+                    "resources::resource::b",
+                    "f4",
+                    &(self.0, x, &y),
+                )
+                .await;
+            if ret.is_ok() {
+                for v in y {
+                    ::core::mem::forget(v);
+                }
+            }
+            ret.unwrap()
+        }
+        /// Releases the resource and waits for the host to drop it.
+        pub async fn dispose(self) {
+            let id = self.0;
+            ::core::mem::forget(self);
+            ::tauri_bindgen_guest_rust::invoke("resources::resource::b", "drop", &(id,))
+                .await
+                .unwrap()
+        }
+    }
+    impl Drop for B {
+        fn drop(&mut self) {
+            ::tauri_bindgen_guest_rust::release("resources::resource::b", self.0);
+        }
+    }
+    pub async fn constructor_a() -> A {
+        ::tauri_bindgen_guest_rust::invoke("resources", "constructor_a", &())
+            .await
+            .unwrap()
+    }
+    pub async fn constructor_b() -> B {
+        ::tauri_bindgen_guest_rust::invoke("resources", "constructor_b", &())
+            .await
+            .unwrap()
+    }
+    pub async fn consume(x: A) {
+        let ret = ::tauri_bindgen_guest_rust::invoke("resources", "consume", &(&x))
+            .await;
+        if ret.is_ok() {
+            ::core::mem::forget(x);
+        }
+        ret.unwrap()
+    }
+}
@@ -57,71 +57,70 @@ and is treated that way in some languages*/
         ///A Signed Integer
         S64(i64),
     }
-    pub async fn add_one_integer(num: AllIntegers) -> AllIntegers {
-        ::tauri_bindgen_guest_rust::invoke("unions", "add_one_integer", &(num))
-            .await
-            .unwrap()
+    pub async fn add_one_integer(
+        num: AllIntegers,
+    ) -> Result<AllIntegers, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("unions", "add_one_integer", &(num)).await
     }
-    pub async fn add_one_float(num: AllFloats) -> AllFloats {
-        ::tauri_bindgen_guest_rust::invoke("unions", "add_one_float", &(num))
-            .await
-            .unwrap()
+    pub async fn add_one_float(
+        num: AllFloats,
+    ) -> Result<AllFloats, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("unions", "add_one_float", &(num)).await
     }
     pub async fn replace_first_char(
         text: AllTextParam<'_>,
         letter: char,
-    ) -> AllTextResult {
+    ) -> Result<AllTextResult, ::tauri_bindgen_guest_rust::Error> {
         ::tauri_bindgen_guest_rust::invoke(
                 "unions",
                 "replace_first_char",
                 &(text, letter),
             )
             .await
-            .unwrap()
     }
-    pub async fn identify_integer(num: AllIntegers) -> u8 {
-        ::tauri_bindgen_guest_rust::invoke("unions", "identify_integer", &(num))
-            .await
-            .unwrap()
+    pub async fn identify_integer(
+        num: AllIntegers,
+    ) -> Result<u8, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("unions", "identify_integer", &(num)).await
     }
-    pub async fn identify_float(num: AllFloats) -> u8 {
-        ::tauri_bindgen_guest_rust::invoke("unions", "identify_float", &(num))
-            .await
-            .unwrap()
+    pub async fn identify_float(
+        num: AllFloats,
+    ) -> Result<u8, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("unions", "identify_float", &(num)).await
     }
-    pub async fn identify_text(text: AllTextParam<'_>) -> u8 {
-        ::tauri_bindgen_guest_rust::invoke("unions", "identify_text", &(text))
-            .await
-            .unwrap()
+    pub async fn identify_text(
+        text: AllTextParam<'_>,
+    ) -> Result<u8, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("unions", "identify_text", &(text)).await
     }
-    pub async fn add_one_duplicated(num: DuplicatedS32) -> DuplicatedS32 {
-        ::tauri_bindgen_guest_rust::invoke("unions", "add_one_duplicated", &(num))
-            .await
-            .unwrap()
+    pub async fn add_one_duplicated(
+        num: DuplicatedS32,
+    ) -> Result<DuplicatedS32, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("unions", "add_one_duplicated", &(num)).await
     }
-    pub async fn identify_duplicated(num: DuplicatedS32) -> u8 {
-        ::tauri_bindgen_guest_rust::invoke("unions", "identify_duplicated", &(num))
-            .await
-            .unwrap()
+    pub async fn identify_duplicated(
+        num: DuplicatedS32,
+    ) -> Result<u8, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("unions", "identify_duplicated", &(num)).await
     }
     pub async fn add_one_distinguishable_num(
         num: DistinguishableNum,
-    ) -> DistinguishableNum {
+    ) -> Result<DistinguishableNum, ::tauri_bindgen_guest_rust::Error> {
         ::tauri_bindgen_guest_rust::invoke(
                 "unions",
                 "add_one_distinguishable_num",
                 &(num),
             )
             .await
-            .unwrap()
     }
-    pub async fn identify_distinguishable_num(num: DistinguishableNum) -> u8 {
+    pub async fn identify_distinguishable_num(
+        num: DistinguishableNum,
+    ) -> Result<u8, ::tauri_bindgen_guest_rust::Error> {
         ::tauri_bindgen_guest_rust::invoke(
                 "unions",
                 "identify_distinguishable_num",
                 &(num),
             )
             .await
-            .unwrap()
     }
 }
@@ -93,29 +93,31 @@ pub mod variants {
     pub struct IsCloneResult {
         pub v1: V1Result,
     }
-    pub async fn e1_arg(x: E1) {
-        ::tauri_bindgen_guest_rust::invoke("variants", "e1_arg", &(x)).await.unwrap()
+    pub async fn e1_arg(x: E1) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "e1_arg", &(x)).await
     }
-    pub async fn e1_result() -> E1 {
-        ::tauri_bindgen_guest_rust::invoke("variants", "e1_result", &()).await.unwrap()
+    pub async fn e1_result() -> Result<E1, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "e1_result", &()).await
     }
-    pub async fn u1_arg(x: U1) {
-        ::tauri_bindgen_guest_rust::invoke("variants", "u1_arg", &(x)).await.unwrap()
+    pub async fn u1_arg(x: U1) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "u1_arg", &(x)).await
     }
-    pub async fn u1_result() -> U1 {
-        ::tauri_bindgen_guest_rust::invoke("variants", "u1_result", &()).await.unwrap()
+    pub async fn u1_result() -> Result<U1, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "u1_result", &()).await
     }
-    pub async fn v1_arg(x: V1Param<'_>) {
-        ::tauri_bindgen_guest_rust::invoke("variants", "v1_arg", &(x)).await.unwrap()
+    pub async fn v1_arg(
+        x: V1Param<'_>,
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "v1_arg", &(x)).await
     }
-    pub async fn v1_result() -> V1Result {
-        ::tauri_bindgen_guest_rust::invoke("variants", "v1_result", &()).await.unwrap()
+    pub async fn v1_result() -> Result<V1Result, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "v1_result", &()).await
     }
-    pub async fn bool_arg(x: bool) {
-        ::tauri_bindgen_guest_rust::invoke("variants", "bool_arg", &(x)).await.unwrap()
+    pub async fn bool_arg(x: bool) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "bool_arg", &(x)).await
     }
-    pub async fn bool_result() -> bool {
-        ::tauri_bindgen_guest_rust::invoke("variants", "bool_result", &()).await.unwrap()
+    pub async fn bool_result() -> Result<bool, ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "bool_result", &()).await
     }
     pub async fn option_arg(
         a: Option<bool>,
@@ -125,27 +127,27 @@ pub mod variants {
         e: Option<f32>,
         f: Option<U1>,
         g: Option<Option<bool>>,
-    ) {
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
         ::tauri_bindgen_guest_rust::invoke(
                 "variants",
                 "option_arg",
                 &(a, b, c, d, e, f, g),
             )
             .await
-            .unwrap()
-    }
-    pub async fn option_result() -> (
-        Option<bool>,
-        Option<()>,
-        Option<u32>,
-        Option<E1>,
-        Option<f32>,
-        Option<U1>,
-        Option<Option<bool>>,
-    ) {
-        ::tauri_bindgen_guest_rust::invoke("variants", "option_result", &())
-            .await
-            .unwrap()
+    }
+    pub async fn option_result() -> Result<
+        (
+            Option<bool>,
+            Option<()>,
+            Option<u32>,
+            Option<E1>,
+            Option<f32>,
+            Option<U1>,
+            Option<Option<bool>>,
+        ),
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "option_result", &()).await
     }
     pub async fn casts(
         a: Casts1,
@@ -154,10 +156,12 @@ pub mod variants {
         d: Casts4,
         e: Casts5,
         f: Casts6,
-    ) -> (Casts1, Casts2, Casts3, Casts4, Casts5, Casts6) {
+    ) -> Result<
+        (Casts1, Casts2, Casts3, Casts4, Casts5, Casts6),
+        ::tauri_bindgen_guest_rust::Error,
+    > {
         ::tauri_bindgen_guest_rust::invoke("variants", "casts", &(a, b, c, d, e, f))
             .await
-            .unwrap()
     }
     pub async fn result_arg(
         a: Result<(), ()>,
@@ -166,76 +170,86 @@ pub mod variants {
         d: Result<(), ()>,
         e: Result<u32, V1Param<'_>>,
         f: Result<&'_ str, &'_ [u8]>,
-    ) {
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
         ::tauri_bindgen_guest_rust::invoke("variants", "result_arg", &(a, b, c, d, e, f))
             .await
-            .unwrap()
-    }
-    pub async fn result_result() -> (
-        Result<(), ()>,
-        Result<(), E1>,
-        Result<E1, ()>,
-        Result<(), ()>,
-        Result<u32, V1Result>,
-        Result<String, Vec<u8>>,
-    ) {
-        ::tauri_bindgen_guest_rust::invoke("variants", "result_result", &())
-            .await
-            .unwrap()
-    }
-    pub async fn return_result_sugar() -> Result<i32, MyErrno> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "return_result_sugar", &())
-            .await
-            .unwrap()
-    }
-    pub async fn return_result_sugar2() -> Result<(), MyErrno> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "return_result_sugar2", &())
-            .await
-            .unwrap()
-    }
-    pub async fn return_result_sugar3() -> Result<MyErrno, MyErrno> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "return_result_sugar3", &())
-            .await
-            .unwrap()
-    }
-    pub async fn return_result_sugar4() -> Result<(i32, u32), MyErrno> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "return_result_sugar4", &())
-            .await
-            .unwrap()
-    }
-    pub async fn return_option_sugar() -> Option<i32> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "return_option_sugar", &())
-            .await
-            .unwrap()
-    }
-    pub async fn return_option_sugar2() -> Option<MyErrno> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "return_option_sugar2", &())
-            .await
-            .unwrap()
-    }
-    pub async fn result_simple() -> Result<u32, i32> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "result_simple", &())
-            .await
-            .unwrap()
-    }
-    pub async fn is_clone_arg(a: IsCloneParam<'_>) {
-        ::tauri_bindgen_guest_rust::invoke("variants", "is_clone_arg", &(a))
-            .await
-            .unwrap()
-    }
-    pub async fn is_clone_return() -> IsCloneResult {
-        ::tauri_bindgen_guest_rust::invoke("variants", "is_clone_return", &())
-            .await
-            .unwrap()
     }
-    pub async fn return_named_option() -> Option<u8> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "return_named_option", &())
-            .await
-            .unwrap()
-    }
-    pub async fn return_named_result() -> Result<u8, MyErrno> {
-        ::tauri_bindgen_guest_rust::invoke("variants", "return_named_result", &())
-            .await
-            .unwrap()
+    pub async fn result_result() -> Result<
+        (
+            Result<(), ()>,
+            Result<(), E1>,
+            Result<E1, ()>,
+            Result<(), ()>,
+            Result<u32, V1Result>,
+            Result<String, Vec<u8>>,
+        ),
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "result_result", &()).await
+    }
+    pub async fn return_result_sugar() -> Result<
+        Result<i32, MyErrno>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "return_result_sugar", &()).await
+    }
+    pub async fn return_result_sugar2() -> Result<
+        Result<(), MyErrno>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "return_result_sugar2", &()).await
+    }
+    pub async fn return_result_sugar3() -> Result<
+        Result<MyErrno, MyErrno>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "return_result_sugar3", &()).await
+    }
+    pub async fn return_result_sugar4() -> Result<
+        Result<(i32, u32), MyErrno>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "return_result_sugar4", &()).await
+    }
+    pub async fn return_option_sugar() -> Result<
+        Option<i32>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "return_option_sugar", &()).await
+    }
+    pub async fn return_option_sugar2() -> Result<
+        Option<MyErrno>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "return_option_sugar2", &()).await
+    }
+    pub async fn result_simple() -> Result<
+        Result<u32, i32>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "result_simple", &()).await
+    }
+    pub async fn is_clone_arg(
+        a: IsCloneParam<'_>,
+    ) -> Result<(), ::tauri_bindgen_guest_rust::Error> {
+        ::tauri_bindgen_guest_rust::invoke("variants", "is_clone_arg", &(a)).await
+    }
+    pub async fn is_clone_return() -> Result<
+        IsCloneResult,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "is_clone_return", &()).await
+    }
+    pub async fn return_named_option() -> Result<
+        Option<u8>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "return_named_option", &()).await
+    }
+    pub async fn return_named_result() -> Result<
+        Result<u8, MyErrno>,
+        ::tauri_bindgen_guest_rust::Error,
+    > {
+        ::tauri_bindgen_guest_rust::invoke("variants", "return_named_result", &()).await
     }
 }
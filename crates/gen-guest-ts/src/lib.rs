@@ -5,14 +5,17 @@
     clippy::unused_self
 )]
 
-use heck::{ToKebabCase, ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
+use heck::{ToKebabCase, ToSnakeCase};
 use std::fmt::Write;
 use std::path::PathBuf;
-use tauri_bindgen_core::{postprocess, Generate, GeneratorBuilder, TypeInfo, TypeInfos};
+use tauri_bindgen_core::{
+    names::{js_ident, js_type_name},
+    postprocess, Generate, GeneratorBuilder, TypeInfo, TypeInfos,
+};
 use tauri_bindgen_gen_js::{JavaScriptGenerator, SerdeUtils};
 use wit_parser::{
-    EnumCase, FlagsField, Function, FunctionResult, Interface, RecordField, Type, TypeDefId,
-    TypeDefKind, UnionCase, VariantCase,
+    EnumCase, Event, FlagsField, Function, FunctionKind, FunctionResult, Interface, RecordField,
+    Type, TypeDefId, TypeDefKind, UnionCase, VariantCase,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -44,13 +47,16 @@ impl GeneratorBuilder for Builder {
             })
             .flatten();
 
-        let infos = TypeInfos::collect_from_functions(
+        let mut infos = TypeInfos::collect_from_functions(
             &interface.typedefs,
             interface.functions.iter().chain(methods),
         );
+        infos.collect_from_events(&interface.typedefs, &interface.events);
 
         let serde_utils =
-            SerdeUtils::collect_from_functions(&interface.typedefs, &interface.functions);
+            SerdeUtils::collect_from_functions(&interface.typedefs, &interface.functions)
+                | SerdeUtils::collect_from_resources(&interface.typedefs)
+                | SerdeUtils::collect_from_events(&interface.typedefs, &interface.events);
 
         Box::new(TypeScript {
             opts: self,
@@ -73,7 +79,7 @@ impl TypeScript {
     pub fn print_function(&self, intf_name: &str, func: &Function) -> String {
         let docs = print_docs(&func.docs);
 
-        let ident = func.id.to_lower_camel_case();
+        let ident = js_ident(&func.id);
         let name = func.id.to_snake_case();
 
         let params = self.print_function_params(&func.params);
@@ -85,6 +91,8 @@ impl TypeScript {
                 self.print_function_result(result)
             });
 
+        let transfer = self.print_transfer_params(&func.params);
+
         let deserialize_result = func
             .result
             .as_ref()
@@ -94,7 +102,7 @@ impl TypeScript {
         let serialize_params = func
             .params
             .iter()
-            .map(|(ident, ty)| self.print_serialize_ty(&ident.to_lower_camel_case(), ty))
+            .map(|(ident, ty)| self.print_serialize_ty(&js_ident(ident), ty))
             .collect::<Vec<_>>()
             .join(";\n");
 
@@ -111,7 +119,37 @@ export async function {ident} ({params}) : {result} {{
     const out = []
     {serialize_params}
 
-    {ret} fetch('ipc://localhost/{intf_name}/{name}', {{ method: "POST", body: Uint8Array.from(out) }}){deserialize_result} {as_ret}
+    {ret} fetch('ipc://localhost/{intf_name}/{name}', {{ method: "POST", body: Uint8Array.from(out) }})
+        .then(checkResponse){transfer}{deserialize_result} {as_ret}
+}}
+        "#
+        )
+    }
+
+    pub fn print_event(&self, intf_name: &str, event: &Event) -> String {
+        let docs = print_docs(&event.docs);
+
+        let ident = format!("on{}", js_type_name(&event.id));
+        let name = event.id.to_snake_case();
+
+        let params = self.print_function_params(&event.payload);
+
+        let deserialize_payload = event
+            .payload
+            .iter()
+            .map(|(_, ty)| self.print_deserialize_ty(ty))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            r#"
+{docs}
+export async function {ident} (handler: ({params}) => void) : Promise<() => void> {{
+    return listen('{intf_name}::{name}', (event: {{ payload: number[] }}) => {{
+        const de = new Deserializer(Uint8Array.from(event.payload))
+
+        handler({deserialize_payload})
+    }})
 }}
         "#
         )
@@ -121,7 +159,7 @@ export async function {ident} ({params}) : {result} {{
         params
             .iter()
             .map(|(ident, ty)| {
-                let ident = ident.to_lower_camel_case();
+                let ident = js_ident(ident);
                 let ty = self.print_type(ty);
 
                 format!("{ident}: {ty}")
@@ -189,13 +227,15 @@ export async function {ident} ({params}) : {result} {{
 
                 format!("Result<{ok}, {err}>")
             }
-            Type::Id(id) => self.interface.typedefs[*id].ident.to_upper_camel_case(),
+            Type::Own(id) | Type::Borrow(id) | Type::Id(id) => {
+                js_type_name(&self.interface.typedefs[*id].ident)
+            }
         }
     }
 
     fn print_typedef(&self, id: TypeDefId) -> String {
         let typedef = &self.interface.typedefs[id];
-        let ident = &typedef.ident.to_upper_camel_case();
+        let ident = &js_type_name(&typedef.ident);
         let docs = print_docs(&typedef.docs);
 
         match &typedef.kind {
@@ -205,9 +245,13 @@ export async function {ident} ({params}) : {result} {{
             TypeDefKind::Variant(cases) => self.print_variant(&docs, ident, cases),
             TypeDefKind::Enum(cases) => self.print_enum(&docs, ident, cases),
             TypeDefKind::Union(cases) => self.print_union(&docs, ident, cases),
-            TypeDefKind::Resource(functions) => {
-                self.print_resource(&self.interface.ident, &docs, ident, functions)
-            }
+            TypeDefKind::Resource(functions) => self.print_resource(
+                &self.interface.ident,
+                &docs,
+                ident,
+                functions,
+                self.infos[id],
+            ),
         }
     }
 
@@ -220,7 +264,7 @@ export async function {ident} ({params}) : {result} {{
     fn print_record(&self, docs: &str, ident: &str, fields: &[RecordField]) -> String {
         let fields = fields.iter().fold(String::new(), |mut str, field| {
             let docs = print_docs(&field.docs);
-            let ident = field.id.to_lower_camel_case();
+            let ident = js_ident(&field.id);
             let ty = self.print_type(&field.ty);
 
             let _ = write!(str, "{docs}\n{ident}: {ty},\n");
@@ -237,7 +281,7 @@ export async function {ident} ({params}) : {result} {{
             .enumerate()
             .fold(String::new(), |mut str, (i, field)| {
                 let docs = print_docs(&field.docs);
-                let ident = field.id.to_upper_camel_case();
+                let ident = js_type_name(&field.id);
                 let value: u64 = 2 << i;
 
                 let _ = write!(str, "{docs}\n{ident} = {value},\n");
@@ -255,7 +299,7 @@ export async function {ident} ({params}) : {result} {{
                 .enumerate()
                 .fold(String::new(), |mut str, (i, case)| {
                     let docs = print_docs(&case.docs);
-                    let case_ident = case.id.to_upper_camel_case();
+                    let case_ident = js_type_name(&case.id);
                     let value = case
                         .ty
                         .as_ref()
@@ -277,7 +321,7 @@ export async function {ident} ({params}) : {result} {{
             .iter()
             .map(|case| {
                 let docs = print_docs(&case.docs);
-                let case_ident = case.id.to_upper_camel_case();
+                let case_ident = js_type_name(&case.id);
 
                 format!("{docs}\n{ident}{case_ident}")
             })
@@ -290,7 +334,7 @@ export async function {ident} ({params}) : {result} {{
     fn print_enum(&self, docs: &str, ident: &str, cases: &[EnumCase]) -> String {
         let cases = cases.iter().fold(String::new(), |mut str, case| {
             let docs = print_docs(&case.docs);
-            let ident = case.id.to_upper_camel_case();
+            let ident = js_type_name(&case.id);
 
             let _ = write!(str, "{docs}\n{ident},\n");
 
@@ -321,23 +365,35 @@ export async function {ident} ({params}) : {result} {{
         docs: &str,
         ident: &str,
         functions: &[Function],
+        info: TypeInfo,
     ) -> String {
+        let module = format!(
+            "{}::resource::{}",
+            mod_ident.to_snake_case(),
+            ident.to_snake_case()
+        );
+
         let functions: String = functions
             .iter()
             .fold(String::new(),|mut str, func| {
                 let docs = print_docs(&func.docs);
+                let name = func.id.to_snake_case();
 
-                let mod_ident = mod_ident.to_snake_case();
-                let resource_ident = ident.to_snake_case();
-                let ident = func.id.to_lower_camel_case();
+                let (head, serialize_id) = match func.kind {
+                    FunctionKind::Constructor => ("static async create".to_string(), ""),
+                    FunctionKind::Static => (format!("static async {}", js_ident(&func.id)), ""),
+                    _ => (format!("async {}", js_ident(&func.id)), "serializeU64(out, this.#id);"),
+                };
 
                 let params = self.print_function_params(&func.params);
                 let result = func
                     .result
                     .as_ref()
-                    .map_or("void".to_string(), |result| self.print_function_result(result));
+                    .map_or("Promise<void>".to_string(), |result| self.print_function_result(result));
+
+                let transfer = self.print_transfer_params(&func.params);
 
-                let deserialize_result = func
+        let deserialize_result = func
                     .result
                     .as_ref()
                     .map(|res| self.print_deserialize_function_result(res))
@@ -346,18 +402,25 @@ export async function {ident} ({params}) : {result} {{
                 let serialize_params = func
                     .params
                     .iter()
-                    .map(|(ident, ty)| self.print_serialize_ty(&ident.to_lower_camel_case(), ty))
+                    .map(|(ident, ty)| self.print_serialize_ty(&js_ident(ident), ty))
                     .collect::<Vec<_>>()
                     .join(";\n");
 
+                let as_ret = if func.result.is_some() {
+                    format!("as {result}")
+                } else {
+                    String::new()
+                };
+
                 let _ = write!(str,
                     r#"{docs}
-async {ident} ({params}) : {result} {{
+{head} ({params}) : {result} {{
     const out = []
-    serializeU32(out, this.#id);
+    {serialize_id}
     {serialize_params}
 
-    await fetch('ipc://localhost/{mod_ident}::resource::{resource_ident}/{ident}', {{ method: "POST", body: Uint8Array.from(out), headers: {{ 'Content-Type': 'application/octet-stream' }} }}){deserialize_result}
+    return fetch('ipc://localhost/{module}/{name}', {{ method: "POST", body: Uint8Array.from(out), headers: {{ 'Content-Type': 'application/octet-stream' }} }})
+        .then(checkResponse){transfer}{deserialize_result} {as_ret}
 }}
 "#
                 );
@@ -365,12 +428,66 @@ async {ident} ({params}) : {result} {{
                 str
             });
 
+        let serialize = if info.contains(TypeInfo::PARAM) {
+            format!(
+                "serialize(out: number[]) {{
+    serializeU64(out, this.#id)
+}}
+/**
+* Gives up the handle after the host took it over in a call, it can't be used afterwards.
+*/
+transfer() {{
+    {ident}.#registry.unregister(this)
+    this.#id = undefined
+}}"
+            )
+        } else {
+            String::new()
+        };
+
+        let deserialize = if info.contains(TypeInfo::RESULT) {
+            format!(
+                "static deserialize(de: Deserializer): {ident} {{
+    return {ident}.#from(deserializeU64(de))
+}}"
+            )
+        } else {
+            String::new()
+        };
+
         format!(
-            "{docs}\nexport class {ident} {{
-    #id: number;
+            r#"{docs}
+export class {ident} {{
+    #id?: bigint;
+    static #registry = new FinalizationRegistry<bigint>((id) => {ident}.#drop(id).catch(console.error))
+
+    static #from(id: bigint): {ident} {{
+        const self = new {ident}()
+        self.#id = id
+        {ident}.#registry.register(self, id, self)
+        return self
+    }}
+
+    static async #drop(id: bigint): Promise<void> {{
+        const out = []
+        serializeU64(out, id)
+
+        await fetch('ipc://localhost/{module}/drop', {{ method: "POST", body: Uint8Array.from(out), headers: {{ 'Content-Type': 'application/octet-stream' }} }})
+            .then(checkResponse)
+    }}
 
     {functions}
-}}"
+    /**
+    * Releases the resource without waiting for it to be garbage collected.
+    */
+    async dispose (): Promise<void> {{
+        if ({ident}.#registry.unregister(this)) {{
+            await {ident}.#drop(this.#id!)
+        }}
+    }}
+    {serialize}
+    {deserialize}
+}}"#
         )
     }
 
@@ -397,6 +514,8 @@ async {ident} ({params}) : {result} {{
             | Type::List(_)
             | Type::Option(_)
             | Type::Result { .. }
+            | Type::Own(_)
+            | Type::Borrow(_)
             | Type::Char
             | Type::String => None,
         }
@@ -430,7 +549,21 @@ impl Generate for TypeScript {
     fn to_file(&mut self) -> (PathBuf, String) {
         let ts_nocheck = "// @ts-nocheck\n".to_string();
 
-        let result_ty = if self.interface.functions.iter().any(Function::throws) {
+        let events_throw = self.interface.events.iter().any(|event| {
+            event
+                .payload
+                .iter()
+                .any(|(_, ty)| matches!(ty, Type::Result { .. }))
+        });
+
+        let resources_throw = self.interface.typedefs.iter().any(|(_, typedef)| {
+            matches!(&typedef.kind, TypeDefKind::Resource(functions) if functions.iter().any(Function::throws))
+        });
+
+        let result_ty = if self.interface.functions.iter().any(Function::throws)
+            || events_throw
+            || resources_throw
+        {
             "export type Result<T, E> = { tag: 'ok', val: T } | { tag: 'err', val: E };\n"
         } else {
             Default::default()
@@ -482,8 +615,21 @@ impl Generate for TypeScript {
             .map(|func| self.print_function(&self.interface.ident.to_snake_case(), func))
             .collect();
 
+        let events: String = self
+            .interface
+            .events
+            .iter()
+            .map(|event| self.print_event(&self.interface.ident.to_snake_case(), event))
+            .collect();
+
+        let imports = if self.interface.events.is_empty() {
+            ""
+        } else {
+            "import { listen } from '@tauri-apps/api/event'\n"
+        };
+
         let mut contents = format!(
-            "{ts_nocheck}{result_ty}{serde_utils}{deserializers}{serializers}\n{typedefs}\n{functions}"
+            "{ts_nocheck}{imports}{result_ty}{serde_utils}{deserializers}{serializers}\n{typedefs}\n{functions}{events}"
         );
 
         if self.opts.prettier {
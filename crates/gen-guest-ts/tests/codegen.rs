@@ -238,3 +238,17 @@ fn variants() {
     assert_eq!(filename, "variants.ts");
     assert_eq!(contents, include_str!("./variants.ts"));
 }
+
+#[test]
+fn events() {
+    let opts = Builder {
+        prettier: false,
+        romefmt: false,
+    };
+
+    let (filename, contents) =
+        gen_interface(opts, "events", include_str!("../../../wit/events.wit"));
+
+    assert_eq!(filename, "events.ts");
+    assert_eq!(contents, include_str!("./events.ts"));
+}
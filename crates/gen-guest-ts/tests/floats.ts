@@ -54,6 +54,49 @@ function serializeF64(out, val) {
 
     out.push(...new Uint8Array(buf))
 }
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 
 
 
@@ -62,7 +105,8 @@ export async function float32Param (x: number) : Promise<void> {
     const out = []
     serializeF32(out, x)
 
-     fetch('ipc://localhost/floats/float32_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/floats/float32_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -70,7 +114,8 @@ export async function float64Param (x: number) : Promise<void> {
     const out = []
     serializeF64(out, x)
 
-     fetch('ipc://localhost/floats/float64_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/floats/float64_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -79,6 +124,7 @@ export async function float32Result () : Promise<number> {
     
 
     return fetch('ipc://localhost/floats/float32_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -93,6 +139,7 @@ export async function float64Result () : Promise<number> {
     
 
     return fetch('ipc://localhost/floats/float64_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -141,11 +141,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -187,6 +185,49 @@ function serializeS128(out, val) {
   val = BigInt(val)
   ser_varint_big(out, 128, (val << 1n) ^ (val >> 127n))
 }
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 
 
 
@@ -195,7 +236,8 @@ export async function a1 (x: number) : Promise<void> {
     const out = []
     serializeU8(out, x)
 
-     fetch('ipc://localhost/integers/a1', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a1', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -203,7 +245,8 @@ export async function a2 (x: number) : Promise<void> {
     const out = []
     serializeS8(out, x)
 
-     fetch('ipc://localhost/integers/a2', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a2', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -211,7 +254,8 @@ export async function a3 (x: number) : Promise<void> {
     const out = []
     serializeU16(out, x)
 
-     fetch('ipc://localhost/integers/a3', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a3', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -219,7 +263,8 @@ export async function a4 (x: number) : Promise<void> {
     const out = []
     serializeS16(out, x)
 
-     fetch('ipc://localhost/integers/a4', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a4', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -227,7 +272,8 @@ export async function a5 (x: number) : Promise<void> {
     const out = []
     serializeU32(out, x)
 
-     fetch('ipc://localhost/integers/a5', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a5', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -235,7 +281,8 @@ export async function a6 (x: number) : Promise<void> {
     const out = []
     serializeS32(out, x)
 
-     fetch('ipc://localhost/integers/a6', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a6', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -243,7 +290,8 @@ export async function a7 (x: bigint) : Promise<void> {
     const out = []
     serializeU64(out, x)
 
-     fetch('ipc://localhost/integers/a7', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a7', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -251,7 +299,8 @@ export async function a8 (x: bigint) : Promise<void> {
     const out = []
     serializeS64(out, x)
 
-     fetch('ipc://localhost/integers/a8', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a8', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -259,7 +308,8 @@ export async function a9 (x: bigint) : Promise<void> {
     const out = []
     serializeU128(out, x)
 
-     fetch('ipc://localhost/integers/a9', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a9', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -267,7 +317,8 @@ export async function a10 (x: bigint) : Promise<void> {
     const out = []
     serializeS128(out, x)
 
-     fetch('ipc://localhost/integers/a10', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a10', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -284,7 +335,8 @@ serializeS64(out, p8);
 serializeU128(out, p9);
 serializeS128(out, p10)
 
-     fetch('ipc://localhost/integers/a11', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/integers/a11', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -293,6 +345,7 @@ export async function r1 () : Promise<number> {
     
 
     return fetch('ipc://localhost/integers/r1', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -307,6 +360,7 @@ export async function r2 () : Promise<number> {
     
 
     return fetch('ipc://localhost/integers/r2', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -321,6 +375,7 @@ export async function r3 () : Promise<number> {
     
 
     return fetch('ipc://localhost/integers/r3', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -335,6 +390,7 @@ export async function r4 () : Promise<number> {
     
 
     return fetch('ipc://localhost/integers/r4', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -349,6 +405,7 @@ export async function r5 () : Promise<number> {
     
 
     return fetch('ipc://localhost/integers/r5', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -363,6 +420,7 @@ export async function r6 () : Promise<number> {
     
 
     return fetch('ipc://localhost/integers/r6', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -377,6 +435,7 @@ export async function r7 () : Promise<bigint> {
     
 
     return fetch('ipc://localhost/integers/r7', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -391,6 +450,7 @@ export async function r8 () : Promise<bigint> {
     
 
     return fetch('ipc://localhost/integers/r8', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -405,6 +465,7 @@ export async function r9 () : Promise<bigint> {
     
 
     return fetch('ipc://localhost/integers/r9', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -419,6 +480,7 @@ export async function r10 () : Promise<bigint> {
     
 
     return fetch('ipc://localhost/integers/r10', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -433,6 +495,7 @@ export async function pairRet () : Promise<[bigint, number]> {
     
 
     return fetch('ipc://localhost/integers/pair_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -195,11 +195,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -283,6 +281,49 @@ function serializeList(out, inner, val) {
 }
 const __text_decoder = new TextDecoder('utf-8');
 const __text_encoder = new TextEncoder();
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 function deserializeOtherRecord(de) {
     return {
         a1: deserializeU32(de),
@@ -445,7 +486,8 @@ export async function listU8Param (x: Uint8Array[]) : Promise<void> {
     const out = []
     serializeBytes(out, x)
 
-     fetch('ipc://localhost/lists/list_u8_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_u8_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -453,7 +495,8 @@ export async function listU16Param (x: Uint16Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeU16(out, v), x)
 
-     fetch('ipc://localhost/lists/list_u16_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_u16_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -461,7 +504,8 @@ export async function listU32Param (x: Uint32Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeU32(out, v), x)
 
-     fetch('ipc://localhost/lists/list_u32_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_u32_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -469,7 +513,8 @@ export async function listU64Param (x: BigUint64Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeU64(out, v), x)
 
-     fetch('ipc://localhost/lists/list_u64_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_u64_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -477,7 +522,8 @@ export async function listU128Param (x: bigint[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeU128(out, v), x)
 
-     fetch('ipc://localhost/lists/list_u128_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_u128_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -485,7 +531,8 @@ export async function listS8Param (x: Int8Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeS8(out, v), x)
 
-     fetch('ipc://localhost/lists/list_s8_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_s8_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -493,7 +540,8 @@ export async function listS16Param (x: Int16Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeS16(out, v), x)
 
-     fetch('ipc://localhost/lists/list_s16_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_s16_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -501,7 +549,8 @@ export async function listS32Param (x: Int32Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeS32(out, v), x)
 
-     fetch('ipc://localhost/lists/list_s32_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_s32_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -509,7 +558,8 @@ export async function listS64Param (x: BigInt64Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeS64(out, v), x)
 
-     fetch('ipc://localhost/lists/list_s64_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_s64_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -517,7 +567,8 @@ export async function listS128Param (x: bigint[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeS128(out, v), x)
 
-     fetch('ipc://localhost/lists/list_s128_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_s128_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -525,7 +576,8 @@ export async function listFloat32Param (x: Float32Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeF32(out, v), x)
 
-     fetch('ipc://localhost/lists/list_float32_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_float32_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -533,7 +585,8 @@ export async function listFloat64Param (x: Float64Array[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeF64(out, v), x)
 
-     fetch('ipc://localhost/lists/list_float64_param', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/list_float64_param', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -542,6 +595,7 @@ export async function listU8Ret () : Promise<Uint8Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_u8_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -556,6 +610,7 @@ export async function listU16Ret () : Promise<Uint16Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_u16_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -570,6 +625,7 @@ export async function listU32Ret () : Promise<Uint32Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_u32_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -584,6 +640,7 @@ export async function listU64Ret () : Promise<BigUint64Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_u64_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -598,6 +655,7 @@ export async function listU128Ret () : Promise<bigint[]> {
     
 
     return fetch('ipc://localhost/lists/list_u128_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -612,6 +670,7 @@ export async function listS8Ret () : Promise<Int8Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_s8_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -626,6 +685,7 @@ export async function listS16Ret () : Promise<Int16Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_s16_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -640,6 +700,7 @@ export async function listS32Ret () : Promise<Int32Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_s32_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -654,6 +715,7 @@ export async function listS64Ret () : Promise<BigInt64Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_s64_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -668,6 +730,7 @@ export async function listS128Ret () : Promise<bigint[]> {
     
 
     return fetch('ipc://localhost/lists/list_s128_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -682,6 +745,7 @@ export async function listFloat32Ret () : Promise<Float32Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_float32_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -696,6 +760,7 @@ export async function listFloat64Ret () : Promise<Float64Array[]> {
     
 
     return fetch('ipc://localhost/lists/list_float64_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -710,6 +775,7 @@ export async function tupleList (x: [number, number][]) : Promise<[bigint, numbe
     serializeList(out, (out, v) => {serializeU8(out, v[0]);serializeS8(out, v[1])}, x)
 
     return fetch('ipc://localhost/lists/tuple_list', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -723,7 +789,8 @@ export async function stringListArg (a: string[]) : Promise<void> {
     const out = []
     serializeList(out, (out, v) => serializeString(out, v), a)
 
-     fetch('ipc://localhost/lists/string_list_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/lists/string_list_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -732,6 +799,7 @@ export async function stringListRet () : Promise<string[]> {
     
 
     return fetch('ipc://localhost/lists/string_list_ret', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -746,6 +814,7 @@ export async function tupleStringList (x: [number, string][]) : Promise<[string,
     serializeList(out, (out, v) => {serializeU8(out, v[0]);serializeString(out, v[1])}, x)
 
     return fetch('ipc://localhost/lists/tuple_string_list', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -760,6 +829,7 @@ export async function stringList (x: string[]) : Promise<string[]> {
     serializeList(out, (out, v) => serializeString(out, v), x)
 
     return fetch('ipc://localhost/lists/string_list', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -774,6 +844,7 @@ export async function recordList (x: SomeRecord[]) : Promise<OtherRecord[]> {
     serializeList(out, (out, v) => serializeSomeRecord(out, v), x)
 
     return fetch('ipc://localhost/lists/record_list', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -788,6 +859,7 @@ export async function recordListReverse (x: OtherRecord[]) : Promise<SomeRecord[
     serializeList(out, (out, v) => serializeOtherRecord(out, v), x)
 
     return fetch('ipc://localhost/lists/record_list_reverse', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -802,6 +874,7 @@ export async function variantList (x: SomeVariant[]) : Promise<OtherVariant[]> {
     serializeList(out, (out, v) => serializeSomeVariant(out, v), x)
 
     return fetch('ipc://localhost/lists/variant_list', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -816,6 +889,7 @@ export async function loadStoreEverything (a: LoadStoreAllSizes) : Promise<LoadS
     serializeLoadStoreAllSizes(out, a)
 
     return fetch('ipc://localhost/lists/load_store_everything', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -54,11 +54,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -77,6 +75,49 @@ function serializeString(out, val) {
     out.push(...__text_encoder.encode(val))
 }
 const __text_encoder = new TextEncoder();
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 function serializeBigStruct(out, val) {
     serializeString(out, val.a1),
 serializeString(out, val.a2),
@@ -163,7 +204,8 @@ serializeU64(out, a14);
 serializeU64(out, a15);
 serializeU64(out, a16)
 
-     fetch('ipc://localhost/many_arguments/many_args', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/many_arguments/many_args', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -171,6 +213,7 @@ export async function bigArgument (x: BigStruct) : Promise<void> {
     const out = []
     serializeBigStruct(out, x)
 
-     fetch('ipc://localhost/many_arguments/big_argument', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/many_arguments/big_argument', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
\ No newline at end of file
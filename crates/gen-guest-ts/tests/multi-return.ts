@@ -90,6 +90,49 @@ function deserializeF32(de) {
 
     return view.getFloat32(0, true);
 }
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 
 
 
@@ -98,7 +141,8 @@ export async function mra () : Promise<void> {
     const out = []
     
 
-     fetch('ipc://localhost/multi_return/mra', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/multi_return/mra', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -106,7 +150,8 @@ export async function mrb () : Promise<void> {
     const out = []
     
 
-    return fetch('ipc://localhost/multi_return/mrb', { method: "POST", body: Uint8Array.from(out) }) as Promise<void>
+    return fetch('ipc://localhost/multi_return/mrb', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) as Promise<void>
 }
         
 
@@ -115,6 +160,7 @@ export async function mrc () : Promise<number> {
     
 
     return fetch('ipc://localhost/multi_return/mrc', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -129,6 +175,7 @@ export async function mrd () : Promise<number> {
     
 
     return fetch('ipc://localhost/multi_return/mrd', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -143,6 +190,7 @@ export async function mre () : Promise<[number, number]> {
     
 
     return fetch('ipc://localhost/multi_return/mre', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(Uint8Array.from(bytes))
@@ -135,11 +135,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -181,6 +179,49 @@ function serializeString(out, val) {
 }
 const __text_decoder = new TextDecoder('utf-8');
 const __text_encoder = new TextEncoder();
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 function deserializeEmpty(de) {
     return {
         
@@ -297,7 +338,8 @@ export async function tupleArg (x: [string, number]) : Promise<void> {
     const out = []
     {serializeChar(out, x[0]);serializeU32(out, x[1])}
 
-     fetch('ipc://localhost/records/tuple_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/records/tuple_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -306,6 +348,7 @@ export async function tupleResult () : Promise<[string, number]> {
     
 
     return fetch('ipc://localhost/records/tuple_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -319,7 +362,8 @@ export async function emptyArg (x: Empty) : Promise<void> {
     const out = []
     serializeEmpty(out, x)
 
-     fetch('ipc://localhost/records/empty_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/records/empty_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -328,6 +372,7 @@ export async function emptyResult () : Promise<Empty> {
     
 
     return fetch('ipc://localhost/records/empty_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -341,7 +386,8 @@ export async function scalarArg (x: Scalars) : Promise<void> {
     const out = []
     serializeScalars(out, x)
 
-     fetch('ipc://localhost/records/scalar_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/records/scalar_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -350,6 +396,7 @@ export async function scalarResult () : Promise<Scalars> {
     
 
     return fetch('ipc://localhost/records/scalar_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -363,7 +410,8 @@ export async function flagsArg (x: ReallyFlags) : Promise<void> {
     const out = []
     serializeReallyFlags(out, x)
 
-     fetch('ipc://localhost/records/flags_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/records/flags_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -372,6 +420,7 @@ export async function flagsResult () : Promise<ReallyFlags> {
     
 
     return fetch('ipc://localhost/records/flags_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -385,7 +434,8 @@ export async function aggregateArg (x: Aggregates) : Promise<void> {
     const out = []
     serializeAggregates(out, x)
 
-     fetch('ipc://localhost/records/aggregate_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/records/aggregate_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -394,6 +444,7 @@ export async function aggregateResult () : Promise<Aggregates> {
     
 
     return fetch('ipc://localhost/records/aggregate_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -408,6 +459,7 @@ export async function typedefInout (e: TupleTypedef2) : Promise<number> {
     serializeTupleTypedef2(out, e)
 
     return fetch('ipc://localhost/records/typedef_inout', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
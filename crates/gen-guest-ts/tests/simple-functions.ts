@@ -101,11 +101,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -118,6 +116,49 @@ function ser_varint_big(out, bits, val) {
 function serializeU32(out, val) {
     return ser_varint(out, 32, val)
 }
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 
 
 
@@ -126,7 +167,8 @@ export async function f1 () : Promise<void> {
     const out = []
     
 
-     fetch('ipc://localhost/simple_functions/f1', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/simple_functions/f1', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -134,7 +176,8 @@ export async function f2 (a: number) : Promise<void> {
     const out = []
     serializeU32(out, a)
 
-     fetch('ipc://localhost/simple_functions/f2', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/simple_functions/f2', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -143,7 +186,8 @@ export async function f3 (a: number, b: number) : Promise<void> {
     serializeU32(out, a);
 serializeU32(out, b)
 
-     fetch('ipc://localhost/simple_functions/f3', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/simple_functions/f3', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -152,6 +196,7 @@ export async function f4 () : Promise<number> {
     
 
     return fetch('ipc://localhost/simple_functions/f4', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -166,6 +211,7 @@ export async function f5 () : Promise<[number, number]> {
     
 
     return fetch('ipc://localhost/simple_functions/f5', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -182,6 +228,7 @@ serializeU32(out, b);
 serializeU32(out, c)
 
     return fetch('ipc://localhost/simple_functions/f6', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -189,11 +189,9 @@ function ser_varint(out, bits, val) {
 function ser_varint_big(out, bits, val) {
   let buf = []
   for (let i = 0; i < varint_max[bits]; i++) {
-    const buffer = new ArrayBuffer(bits / 8);
-    const view = new DataView(buffer);
-    view.setInt16(0, Number(val), true);
-    buf[i] = view.getUint8(0);
-    if (val < 128) {
+    // stays a BigInt, converting all of val to a Number loses the low bits past 2^53
+    buf[i] = Number(val & 0x7fn);
+    if (val < 128n) {
       out.push(...buf)
       return;
     }
@@ -271,6 +269,49 @@ function serializeResult(out, ok, err, val) {
 }
 const __text_decoder = new TextDecoder('utf-8');
 const __text_encoder = new TextEncoder();
+const __ipc_error_kinds = [undefined, 'NotFound', 'InvalidRequest', 'Handler', 'Panic', 'Forbidden']
+
+export class IpcError extends Error {
+    constructor(code, message, details) {
+        super(details == null ? message : `${message}: ${details}`)
+        this.name = 'IpcError'
+        this.code = code
+        this.kind = __ipc_error_kinds[code] ?? 'Unknown'
+        this.details = details
+    }
+}
+
+function __ipc_error_varint(de) {
+    let out = 0
+    for (let shift = 0; shift < 35; shift += 7) {
+        const byte = de.pop()
+        out += (byte & 0x7f) * 2 ** shift
+        if ((byte & 0x80) === 0) {
+            return out
+        }
+    }
+
+    throw new Error('deserialize bad variant')
+}
+
+function __ipc_error_string(de) {
+    const sz = __ipc_error_varint(de)
+
+    return new TextDecoder('utf-8').decode(de.try_take_n(sz))
+}
+
+async function checkResponse(r) {
+    if (r.ok) {
+        return r
+    }
+
+    const de = new Deserializer(new Uint8Array(await r.arrayBuffer()))
+    const code = __ipc_error_varint(de)
+    const message = __ipc_error_string(de)
+    const details = de.pop() === 1 ? __ipc_error_string(de) : null
+
+    throw new IpcError(code, message, details)
+}
 function deserializeE1(de) {
     const tag = deserializeU32(de)
 
@@ -663,7 +704,8 @@ export async function e1Arg (x: E1) : Promise<void> {
     const out = []
     serializeE1(out, x)
 
-     fetch('ipc://localhost/variants/e1_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/variants/e1_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -672,6 +714,7 @@ export async function e1Result () : Promise<E1> {
     
 
     return fetch('ipc://localhost/variants/e1_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -685,7 +728,8 @@ export async function u1Arg (x: U1) : Promise<void> {
     const out = []
     serializeU1(out, x)
 
-     fetch('ipc://localhost/variants/u1_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/variants/u1_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -694,6 +738,7 @@ export async function u1Result () : Promise<U1> {
     
 
     return fetch('ipc://localhost/variants/u1_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -707,7 +752,8 @@ export async function v1Arg (x: V1) : Promise<void> {
     const out = []
     serializeV1(out, x)
 
-     fetch('ipc://localhost/variants/v1_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/variants/v1_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -716,6 +762,7 @@ export async function v1Result () : Promise<V1> {
     
 
     return fetch('ipc://localhost/variants/v1_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -729,7 +776,8 @@ export async function boolArg (x: boolean) : Promise<void> {
     const out = []
     serializeBool(out, x)
 
-     fetch('ipc://localhost/variants/bool_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/variants/bool_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -738,6 +786,7 @@ export async function boolResult () : Promise<boolean> {
     
 
     return fetch('ipc://localhost/variants/bool_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -757,7 +806,8 @@ serializeOption(out, (out, v) => serializeF32(out, v), e);
 serializeOption(out, (out, v) => serializeU1(out, v), f);
 serializeOption(out, (out, v) => serializeOption(out, (out, v) => serializeBool(out, v), v), g)
 
-     fetch('ipc://localhost/variants/option_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/variants/option_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -766,6 +816,7 @@ export async function optionResult () : Promise<[boolean | null, [] | null, numb
     
 
     return fetch('ipc://localhost/variants/option_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -785,6 +836,7 @@ serializeCasts5(out, e);
 serializeCasts6(out, f)
 
     return fetch('ipc://localhost/variants/casts', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -803,7 +855,8 @@ serializeResult(out, (out, v) => {}, (out, v) => {}, d);
 serializeResult(out, (out, v) => serializeU32(out, v), (out, v) => serializeV1(out, v), e);
 serializeResult(out, (out, v) => serializeString(out, v), (out, v) => serializeBytes(out, v), f)
 
-     fetch('ipc://localhost/variants/result_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/variants/result_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -812,6 +865,7 @@ export async function resultResult () : Promise<[Result<null, null>, Result<null
     
 
     return fetch('ipc://localhost/variants/result_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -826,6 +880,7 @@ export async function returnResultSugar () : Promise<Result<number, MyErrno>> {
     
 
     return fetch('ipc://localhost/variants/return_result_sugar', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -840,6 +895,7 @@ export async function returnResultSugar2 () : Promise<Result<null, MyErrno>> {
     
 
     return fetch('ipc://localhost/variants/return_result_sugar2', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -854,6 +910,7 @@ export async function returnResultSugar3 () : Promise<Result<MyErrno, MyErrno>>
     
 
     return fetch('ipc://localhost/variants/return_result_sugar3', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -868,6 +925,7 @@ export async function returnResultSugar4 () : Promise<Result<[number, number], M
     
 
     return fetch('ipc://localhost/variants/return_result_sugar4', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -882,6 +940,7 @@ export async function returnOptionSugar () : Promise<number | null> {
     
 
     return fetch('ipc://localhost/variants/return_option_sugar', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -896,6 +955,7 @@ export async function returnOptionSugar2 () : Promise<MyErrno | null> {
     
 
     return fetch('ipc://localhost/variants/return_option_sugar2', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -910,6 +970,7 @@ export async function resultSimple () : Promise<Result<number, number>> {
     
 
     return fetch('ipc://localhost/variants/result_simple', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -923,7 +984,8 @@ export async function isCloneArg (a: IsClone) : Promise<void> {
     const out = []
     serializeIsClone(out, a)
 
-     fetch('ipc://localhost/variants/is_clone_arg', { method: "POST", body: Uint8Array.from(out) }) 
+     fetch('ipc://localhost/variants/is_clone_arg', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse) 
 }
         
 
@@ -932,6 +994,7 @@ export async function isCloneReturn () : Promise<IsClone> {
     
 
     return fetch('ipc://localhost/variants/is_clone_return', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -946,6 +1009,7 @@ export async function returnNamedOption () : Promise<number | null> {
     
 
     return fetch('ipc://localhost/variants/return_named_option', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -960,6 +1024,7 @@ export async function returnNamedResult () : Promise<Result<number, MyErrno>> {
     
 
     return fetch('ipc://localhost/variants/return_named_result', { method: "POST", body: Uint8Array.from(out) })
+        .then(checkResponse)
         .then(r => r.arrayBuffer())
         .then(bytes => {
             const de = new Deserializer(new Uint8Array(bytes))
@@ -6,15 +6,18 @@
 )]
 
 use heck::ToKebabCase;
-use heck::{ToSnakeCase, ToUpperCamelCase};
+use heck::ToSnakeCase;
 use proc_macro2::{Literal, TokenStream};
 use quote::format_ident;
 use quote::quote;
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
-use tauri_bindgen_core::{Generate, GeneratorBuilder, TypeInfo, TypeInfos};
+use tauri_bindgen_core::{
+    names::{resource_function_id, rust_ident, rust_type_name},
+    Generate, GeneratorBuilder, TypeInfo, TypeInfos,
+};
 use tauri_bindgen_gen_rust::{print_generics, BorrowMode, FnSig, RustGenerator};
-use wit_parser::{Function, Interface, Type, TypeDefKind};
+use wit_parser::{Event, Function, FunctionResult, Interface, Type, TypeDefId, TypeDefKind};
 
 #[derive(Default, Debug, Clone)]
 #[cfg_attr(feature = "clap", derive(clap::Args))]
@@ -23,13 +26,23 @@ pub struct Builder {
     #[cfg_attr(feature = "clap", clap(long))]
     pub fmt: bool,
 
-    /// Whether or not to emit `tracing` macro calls on function entry/exit.
+    /// Whether or not to run each routed call in a `tracing` span and record the errors it returns.
     #[cfg_attr(feature = "clap", clap(long))]
     pub tracing: bool,
 
     /// Whether or not to use async rust functions and traits.
     #[cfg_attr(feature = "clap", clap(long = "async"))]
     pub async_: bool,
+
+    /// Whether or not to emit a `COMMANDS` constant with the identifiers of all routed functions,
+    /// e.g. to validate a permission file against.
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub manifest: bool,
+
+    /// Whether or not to pass the metadata of the request, like the calling window and the app handle,
+    /// as the first argument to every trait method.
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub context: bool,
 }
 
 impl GeneratorBuilder for Builder {
@@ -46,10 +59,11 @@ impl GeneratorBuilder for Builder {
             })
             .flatten();
 
-        let infos = TypeInfos::collect_from_functions(
+        let mut infos = TypeInfos::collect_from_functions(
             &interface.typedefs,
             interface.functions.iter().chain(methods),
         );
+        infos.collect_from_events(&interface.typedefs, &interface.events);
 
         Box::new(Host {
             opts: self,
@@ -98,6 +112,36 @@ impl RustGenerator for Host {
         BorrowMode::Owned
     }
 
+    fn print_function_params(&self, params: &[(String, Type)], _mode: &BorrowMode) -> TokenStream {
+        let params = params.iter().map(|(ident, ty)| {
+            let ident = format_ident!("{}", rust_ident(ident));
+            let ty = self.print_trait_ty(ty, true);
+
+            quote! { #ident: #ty }
+        });
+
+        quote! { #(#params),* }
+    }
+
+    fn print_function_result(
+        &self,
+        result: &FunctionResult,
+        _mode: &BorrowMode,
+    ) -> Option<TokenStream> {
+        match result {
+            FunctionResult::Anon(ty) => Some(self.print_trait_ty(ty, false)),
+            FunctionResult::Named(types) if types.is_empty() => None,
+            FunctionResult::Named(types) if types.len() == 1 => {
+                Some(self.print_trait_ty(&types[0].1, false))
+            }
+            FunctionResult::Named(types) => {
+                let types = types.iter().map(|(_, ty)| self.print_trait_ty(ty, false));
+
+                Some(quote! { (#(#types),*) })
+            }
+        }
+    }
+
     fn print_resource(
         &self,
         _mod_ident: &str,
@@ -108,25 +152,25 @@ impl RustGenerator for Host {
     ) -> TokenStream {
         let docs = self.print_docs(docs);
 
-        let mut resources = HashSet::new();
-        for func in functions {
-            for (_, ty) in &func.params {
-                self.extract_resources(ty, &mut resources);
-            }
-            if let Some(result) = &func.result {
-                for ty in result.types() {
-                    self.extract_resources(ty, &mut resources);
-                }
-            }
-        }
+        // constructors and static functions are part of the interface's trait
+        let functions: Vec<_> = functions
+            .iter()
+            .filter(|func| func.kind.is_method())
+            .collect();
 
+        let resources = self.referenced_resources(functions.iter().copied());
         let resources = resources.iter().map(|r| {
-            let ident = format_ident!("{}", r.to_upper_camel_case());
+            let ident = format_ident!("{}", rust_type_name(r));
 
             quote! { type #ident: #ident; }
         });
 
-        let trait_ = self.print_trait(&ident.to_string(), functions.iter(), resources, false);
+        let trait_ = self.print_trait(
+            &ident.to_string(),
+            functions.iter().copied(),
+            resources,
+            false,
+        );
 
         quote! {
             #docs
@@ -211,6 +255,7 @@ impl RustGenerator for Host {
 
                 quote! { Result<#ok, #err> }
             }
+            Type::Own(_) | Type::Borrow(_) => quote! { ::tauri_bindgen_host::ResourceId },
             Type::Id(id) => {
                 let typedef = &self.interface().typedefs[*id];
                 let info = self.infos()[*id];
@@ -222,14 +267,14 @@ impl RustGenerator for Host {
                 let ident = if self.uses_two_names(info) {
                     match mode {
                         BorrowMode::Owned => {
-                            format_ident!("{}Result", typedef.ident.to_upper_camel_case())
+                            format_ident!("{}Result", rust_type_name(&typedef.ident))
                         }
                         BorrowMode::AllBorrowed(_) | BorrowMode::LeafBorrowed(_) => {
-                            format_ident!("{}Param", typedef.ident.to_upper_camel_case())
+                            format_ident!("{}Param", rust_type_name(&typedef.ident))
                         }
                     }
                 } else {
-                    format_ident!("{}", typedef.ident.to_upper_camel_case())
+                    format_ident!("{}", rust_type_name(&typedef.ident))
                 };
 
                 let generics = print_generics(info, mode);
@@ -248,14 +293,21 @@ impl Host {
         additional_items: impl Iterator<Item = TokenStream>,
         sized: bool,
     ) -> TokenStream {
-        let ident = format_ident!("{}", ident.to_upper_camel_case());
+        let ident = format_ident!("{}", rust_type_name(ident));
+
+        let self_arg = if self.opts.context {
+            quote!(&self, cx: &::tauri_bindgen_host::ipc_router_wip::RequestMetadata)
+        } else {
+            quote!(&self)
+        };
 
         let functions = functions.map(|func| {
             let sig = FnSig {
                 async_: self.opts.async_,
                 unsafe_: false,
                 private: true,
-                self_arg: Some(quote!(&self)),
+                self_arg: Some(self_arg.clone()),
+                error: None,
                 func,
             };
 
@@ -280,7 +332,28 @@ impl Host {
         }
     }
 
-    fn extract_resources<'a>(&'a self, ty: &Type, resources: &mut HashSet<&'a str>) {
+    /// The resources `functions` take or return, they become associated types of the functions' trait.
+    fn referenced_resources<'a>(
+        &'a self,
+        functions: impl Iterator<Item = &'a Function>,
+    ) -> BTreeSet<&'a str> {
+        let mut resources = BTreeSet::new();
+
+        for func in functions {
+            for (_, ty) in &func.params {
+                self.extract_resources(ty, &mut resources);
+            }
+            if let Some(result) = &func.result {
+                for ty in result.types() {
+                    self.extract_resources(ty, &mut resources);
+                }
+            }
+        }
+
+        resources
+    }
+
+    fn extract_resources<'a>(&'a self, ty: &Type, resources: &mut BTreeSet<&'a str>) {
         match ty {
             Type::List(ty) | Type::Option(ty) => {
                 self.extract_resources(ty, resources);
@@ -299,7 +372,7 @@ impl Host {
                     self.extract_resources(err, resources);
                 }
             }
-            Type::Id(id) => {
+            Type::Own(id) | Type::Borrow(id) | Type::Id(id) => {
                 let typedef = &self.interface().typedefs[*id];
 
                 if let TypeDefKind::Resource(_) = &typedef.kind {
@@ -310,9 +383,211 @@ impl Host {
         }
     }
 
-    fn print_router_fn_definition(&self, mod_name: &str, func: &Function) -> TokenStream {
-        let func_name = func.id.to_snake_case();
-        let func_ident = format_ident!("{}", func_name);
+    /// Prints the type a trait function takes or returns.
+    ///
+    /// Resources are passed as `Arc<Self::R>` after they were looked up, or by value when they are handed over
+    /// with `own` or returned.
+    fn print_trait_ty(&self, ty: &Type, param: bool) -> TokenStream {
+        match ty {
+            Type::Own(id) => {
+                let ident =
+                    format_ident!("{}", rust_type_name(&self.interface.typedefs[*id].ident));
+
+                quote! { Self::#ident }
+            }
+            Type::Borrow(id) => {
+                let ident =
+                    format_ident!("{}", rust_type_name(&self.interface.typedefs[*id].ident));
+
+                quote! { ::std::sync::Arc<Self::#ident> }
+            }
+            Type::Id(id) if self.is_resource(*id) => {
+                if param {
+                    self.print_trait_ty(&Type::Borrow(*id), param)
+                } else {
+                    self.print_trait_ty(&Type::Own(*id), param)
+                }
+            }
+            Type::List(ty) => {
+                let ty = self.print_trait_ty(ty, param);
+
+                quote! { Vec<#ty> }
+            }
+            Type::Option(ty) => {
+                let ty = self.print_trait_ty(ty, param);
+
+                quote! { Option<#ty> }
+            }
+            Type::Tuple(types) => {
+                let types = types.iter().map(|ty| self.print_trait_ty(ty, param));
+
+                quote! { (#(#types,)*) }
+            }
+            Type::Result { ok, err } => {
+                let ok = ok
+                    .as_ref()
+                    .map_or(quote! { () }, |ty| self.print_trait_ty(ty, param));
+                let err = err
+                    .as_ref()
+                    .map_or(quote! { () }, |ty| self.print_trait_ty(ty, param));
+
+                quote! { Result<#ok, #err> }
+            }
+            ty => self.print_ty(ty, &BorrowMode::Owned),
+        }
+    }
+
+    fn is_resource(&self, id: TypeDefId) -> bool {
+        matches!(self.interface.typedefs[id].kind, TypeDefKind::Resource(_))
+    }
+
+    /// The resource type `U::R` of the implementation, to tell the table what to downcast to.
+    fn print_resource_ty(&self, id: TypeDefId) -> TokenStream {
+        let ident = format_ident!("{}", rust_type_name(&self.interface.typedefs[id].ident));
+
+        quote! { U::#ident }
+    }
+
+    /// Turns the handles of the received parameter `expr` into the resources they refer to,
+    /// returns `None` if `ty` has none.
+    fn print_lift(&self, expr: TokenStream, ty: &Type) -> Option<TokenStream> {
+        self.print_map_handles(expr, ty, &|expr, ty| match ty {
+            Type::Own(id) => {
+                let ty = self.print_resource_ty(*id);
+
+                self.print_lookup(
+                    quote! { ctx.resource_table().take_value::<#ty>(cx.metadata(), #expr) },
+                    "failed to take resource",
+                )
+            }
+            Type::Borrow(id) | Type::Id(id) => {
+                let ty = self.print_resource_ty(*id);
+
+                self.print_lookup(
+                    quote! { ctx.resource_table().get_owned::<#ty>(cx.metadata(), #expr) },
+                    "failed to look up resource",
+                )
+            }
+            _ => unreachable!(),
+        })
+    }
+
+    /// Inserts the resources of the returned value `expr` into the table and replaces them with their handles,
+    /// returns `None` if `ty` has none.
+    fn print_lower(&self, expr: TokenStream, ty: &Type) -> Option<TokenStream> {
+        self.print_map_handles(expr, ty, &|expr, _| {
+            self.print_lookup(
+                quote! { ctx.resource_table().push_owned(cx.metadata(), ::std::sync::Arc::new(#expr)) },
+                "failed to insert resource",
+            )
+        })
+    }
+
+    /// Applies `map` to every resource in `expr`, returns `None` if `ty` has none.
+    fn print_map_handles(
+        &self,
+        expr: TokenStream,
+        ty: &Type,
+        map: &dyn Fn(TokenStream, &Type) -> TokenStream,
+    ) -> Option<TokenStream> {
+        match ty {
+            Type::Own(_) | Type::Borrow(_) => Some(map(expr, ty)),
+            Type::Id(id) if self.is_resource(*id) => Some(map(expr, ty)),
+            Type::List(ty) => {
+                let inner = self.print_map_handles(quote! { v }, ty, map)?;
+
+                Some(quote! {{
+                    let mut out = Vec::new();
+                    for v in #expr {
+                        out.push(#inner);
+                    }
+                    out
+                }})
+            }
+            Type::Option(ty) => {
+                let inner = self.print_map_handles(quote! { v }, ty, map)?;
+
+                Some(quote! {
+                    match #expr {
+                        Some(v) => Some(#inner),
+                        None => None,
+                    }
+                })
+            }
+            Type::Tuple(types) => {
+                let idents: Vec<_> = (0..types.len()).map(|i| format_ident!("v{i}")).collect();
+                let lifted: Vec<_> = idents
+                    .iter()
+                    .zip(types)
+                    .map(|(ident, ty)| self.print_map_handles(quote! { #ident }, ty, map))
+                    .collect();
+
+                if lifted.iter().all(Option::is_none) {
+                    return None;
+                }
+
+                let lifted = lifted
+                    .into_iter()
+                    .zip(&idents)
+                    .map(|(lifted, ident)| lifted.unwrap_or(quote! { #ident }));
+
+                Some(quote! {{
+                    let (#(#idents,)*) = #expr;
+                    (#(#lifted,)*)
+                }})
+            }
+            Type::Result { ok, err } => {
+                let ok = ok
+                    .as_ref()
+                    .and_then(|ty| self.print_map_handles(quote! { v }, ty, map));
+                let err = err
+                    .as_ref()
+                    .and_then(|ty| self.print_map_handles(quote! { v }, ty, map));
+
+                if ok.is_none() && err.is_none() {
+                    return None;
+                }
+
+                let ok = ok.unwrap_or(quote! { v });
+                let err = err.unwrap_or(quote! { v });
+
+                Some(quote! {
+                    match #expr {
+                        Ok(v) => Ok(#ok),
+                        Err(v) => Err(#err),
+                    }
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Unwraps the result of a resource lookup, recording the error when tracing is enabled.
+    fn print_lookup(&self, lookup: TokenStream, message: &str) -> TokenStream {
+        if self.opts.tracing {
+            quote! {
+                #lookup.map_err(|err| {
+                    ::tauri_bindgen_host::tracing::error!(error = %err, #message);
+                    err
+                })?
+            }
+        } else {
+            quote! { #lookup? }
+        }
+    }
+
+    /// Routes a call to a function of the interface's trait.
+    ///
+    /// Constructors and static functions of resources are routed under the resource's module and their own name,
+    /// but call the trait function `func` they were renamed to.
+    fn print_router_fn_definition(
+        &self,
+        mod_name: &str,
+        resource_name: Option<&str>,
+        func_name: &str,
+        func: &Function,
+    ) -> TokenStream {
+        let func_ident = format_ident!("{}", rust_ident(&func.id));
 
         let param_decl = match func.params.len() {
             0 => quote! { () },
@@ -330,19 +605,51 @@ impl Host {
             }
         };
 
-        let param_acc = match func.params.len() {
-            0 => quote! {},
-            1 => quote! { p },
-            _ => {
-                let ids = func.params.iter().enumerate().map(|(i, _)| {
+        let params: Vec<_> = match func.params.len() {
+            0 => vec![],
+            1 => vec![quote! { p }],
+            _ => (0..func.params.len())
+                .map(|i| {
                     let i = Literal::usize_unsuffixed(i);
                     quote! { p.#i }
-                });
-                quote! { #(#ids),* }
-            }
+                })
+                .collect(),
+        };
+        let lifted: Vec<_> = params
+            .into_iter()
+            .zip(&func.params)
+            .map(|(param, (_, ty))| self.print_lift(param.clone(), ty).ok_or(param))
+            .collect();
+        let has_handles = lifted.iter().any(Result::is_ok) || self.returns_handles(func);
+        let param_acc = lifted
+            .into_iter()
+            .map(|param| param.unwrap_or_else(|param| param));
+        let param_acc = quote! { #(#param_acc),* };
+
+        let span = self.print_span(mod_name, func_name, resource_name, false);
+        let mod_name = match resource_name {
+            Some(resource_name) => format!("{mod_name}::resource::{resource_name}"),
+            None => mod_name.to_string(),
+        };
+        let cx = self.print_cx_arg();
+        let call = self.print_call(quote! { ctx.#func_ident(#cx #param_acc) }, func);
+        // resolving and inserting handles needs the caller's webview
+        let clone_cx = if has_handles {
+            Some(quote! { let cx = ::std::clone::Clone::clone(&ctx); })
+        } else {
+            self.print_clone_cx()
         };
 
         if self.opts.async_ {
+            let fut = quote! {
+                async move {
+                    #clone_cx
+                    let ctx = get_cx(ctx.data());
+                    #call
+                }
+            };
+            let (span, fut) = instrument(span, fut);
+
             quote! {
                 let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
                 router.define_async(
@@ -350,22 +657,24 @@ impl Host {
                     #func_name,
                     move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: #param_decl| {
                         let get_cx = get_cx.clone();
-                        Box::pin(async move {
-                            let ctx = get_cx(ctx.data());
-                            Ok(ctx.#func_ident(#param_acc).await)
-                        })
+                        #span
+                        Box::pin(#fut)
                     })?;
             }
         } else {
+            let enter = enter(span);
+
             quote! {
                 let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
                 router.define(
                     #mod_name,
                     #func_name,
                     move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: #param_decl| {
+                        #enter
+                        #clone_cx
                         let ctx = get_cx(ctx.data());
 
-                        Ok(ctx.#func_ident(#param_acc))
+                        #call
                     },
                 )?;
             }
@@ -379,29 +688,45 @@ impl Host {
         method: &Function,
     ) -> TokenStream {
         let func_name = method.id.to_snake_case();
-        let func_ident = format_ident!("{}", func_name);
+        let func_ident = format_ident!("{}", rust_ident(&method.id));
 
         let param_decl = method
             .params
             .iter()
             .map(|(_, ty)| self.print_ty(ty, &BorrowMode::Owned));
 
-        let param_acc = match method.params.len() {
-            0 => quote! {},
-            1 => quote! { p.1 },
-            _ => {
-                let ids = method.params.iter().enumerate().map(|(i, _)| {
-                    let i = Literal::usize_unsuffixed(i + 1);
-                    quote! { p.#i }
-                });
-                quote! { #(#ids),* }
-            }
-        };
+        let param_acc = method.params.iter().enumerate().map(|(i, (_, ty))| {
+            let i = Literal::usize_unsuffixed(i + 1);
+
+            self.print_lift(quote! { p.#i }, ty)
+                .unwrap_or(quote! { p.#i })
+        });
+        let param_acc = quote! { #(#param_acc),* };
 
+        let span = self.print_span(mod_name, &func_name, Some(resource_name), true);
         let mod_name = format!("{mod_name}::resource::{resource_name}");
-        let get_r_ident = format_ident!("get_{}", resource_name.to_snake_case());
+        let ty = format_ident!("{}", rust_type_name(resource_name));
+
+        let get_r = self.print_lookup(
+            quote! { ctx.resource_table().get_owned::<U::#ty>(cx.metadata(), p.0) },
+            "failed to look up resource",
+        );
+        let cx = self.print_cx_arg();
+        let call = self.print_call(quote! { r.#func_ident(#cx #param_acc) }, method);
+        // the lookup needs the caller's webview, even when the implementation doesn't get the metadata
+        let clone_cx = quote! { let cx = ::std::clone::Clone::clone(&ctx); };
 
         if self.opts.async_ {
+            let fut = quote! {
+                async move {
+                    #clone_cx
+                    let ctx = get_cx(ctx.data());
+                    let r = #get_r;
+                    #call
+                }
+            };
+            let (span, fut) = instrument(span, fut);
+
             quote! {
                 let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
                 router.define_async(
@@ -409,14 +734,13 @@ impl Host {
                     #func_name,
                     move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: (::tauri_bindgen_host::ResourceId, #(#param_decl),*)| {
                         let get_cx = get_cx.clone();
-                        Box::pin(async move {
-                            let ctx = get_cx(ctx.data());
-                            let r = ctx.#get_r_ident(p.0)?;
-                            Ok(r.#func_ident(#param_acc).await)
-                        })
+                        #span
+                        Box::pin(#fut)
                     })?;
             }
         } else {
+            let enter = enter(span);
+
             quote! {
                 let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
                 router.define(
@@ -426,31 +750,248 @@ impl Host {
                         ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
                         p: (::tauri_bindgen_host::ResourceId, #(#param_decl),*)
                     | {
+                        #enter
+                        #clone_cx
                         let ctx = get_cx(ctx.data());
-                        let r = ctx.#get_r_ident(p.0)?;
-                        Ok(r.#func_ident(#param_acc))
+                        let r = #get_r;
+                        #call
                     },
                 )?;
             }
         }
     }
 
+    /// Routes the `drop` function every resource has, which releases the guest's handle.
+    fn print_router_drop_definition(&self, mod_name: &str, resource_name: &str) -> TokenStream {
+        let span = self.print_span(mod_name, "drop", Some(resource_name), true);
+        let enter = enter(span);
+        let mod_name = format!("{mod_name}::resource::{resource_name}");
+        let ty = format_ident!("{}", rust_type_name(resource_name));
+
+        let drop = self.print_lookup(
+            quote! { ctx.resource_table().take_owned::<U::#ty>(cx.metadata(), p.0) },
+            "failed to drop resource",
+        );
+
+        quote! {
+            let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+            router.define(
+                #mod_name,
+                "drop",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: (::tauri_bindgen_host::ResourceId,)| {
+                    #enter
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    #drop;
+                    Ok(())
+                },
+            )?;
+        }
+    }
+
+    /// Keeps the caller around, so its metadata can be passed on after `ctx` is shadowed.
+    fn print_clone_cx(&self) -> Option<TokenStream> {
+        self.opts
+            .context
+            .then(|| quote! { let cx = ::std::clone::Clone::clone(&ctx); })
+    }
+
+    fn print_cx_arg(&self) -> Option<TokenStream> {
+        self.opts.context.then(|| quote! { cx.metadata(), })
+    }
+
+    /// Creates the span a routed call runs in, when tracing is enabled.
+    ///
+    /// Calls to resource functions additionally record the resource, and calls on an `instance` its id.
+    fn print_span(
+        &self,
+        mod_name: &str,
+        func_name: &str,
+        resource_name: Option<&str>,
+        instance: bool,
+    ) -> Option<TokenStream> {
+        if !self.opts.tracing {
+            return None;
+        }
+
+        let (name, resource) = match resource_name {
+            Some(resource_name) => {
+                let id = instance.then(|| quote! { resource_id = %p.0, });
+
+                (
+                    format!("{mod_name}::{resource_name}::{func_name}"),
+                    Some(quote! { resource = #resource_name, #id }),
+                )
+            }
+            None => (format!("{mod_name}::{func_name}"), None),
+        };
+
+        Some(quote! {
+            ::tauri_bindgen_host::tracing::info_span!(
+                #name,
+                interface = #mod_name,
+                #resource
+                function = #func_name,
+            )
+        })
+    }
+
+    /// Calls the implementation, recording returned errors when tracing is enabled.
+    ///
+    /// Returned resources are inserted into the table, so the guest receives their handles.
+    fn print_call(&self, call: TokenStream, func: &Function) -> TokenStream {
+        let returns_result = matches!(func.result, Some(FunctionResult::Anon(Type::Result { .. })));
+
+        let call = if self.opts.async_ {
+            quote! { #call.await }
+        } else {
+            call
+        };
+        let lower = result_ty(func).and_then(|ty| self.print_lower(quote! { ret }, &ty));
+
+        if self.opts.tracing && returns_result {
+            let ret = lower.unwrap_or(quote! { ret });
+
+            quote! {
+                let ret = #call;
+                if let Err(err) = &ret {
+                    ::tauri_bindgen_host::tracing::error!(error = ?err, "call returned an error");
+                }
+                Ok(#ret)
+            }
+        } else if let Some(lower) = lower {
+            quote! {
+                let ret = #call;
+                Ok(#lower)
+            }
+        } else {
+            quote! { Ok(#call) }
+        }
+    }
+
+    fn returns_handles(&self, func: &Function) -> bool {
+        result_ty(func).is_some_and(|ty| self.print_lower(quote! { ret }, &ty).is_some())
+    }
+
+    /// Prints `emit_<event>`, which sends the postcard encoded payload to the guest as a tauri event.
+    fn print_emit(&self, event: &Event) -> TokenStream {
+        let docs = self.print_docs(&event.docs);
+        let ident = format_ident!("emit_{}", event.id.to_snake_case());
+        let name = format!(
+            "{}::{}",
+            self.interface.ident.to_snake_case(),
+            event.id.to_snake_case()
+        );
+
+        let idents: Vec<_> = event
+            .payload
+            .iter()
+            .map(|(ident, _)| format_ident!("{}", rust_ident(ident)))
+            .collect();
+        let tys = event
+            .payload
+            .iter()
+            .map(|(_, ty)| self.print_ty(ty, &BorrowMode::Owned));
+
+        quote! {
+            #docs
+            pub fn #ident<R: ::tauri_bindgen_host::tauri::Runtime>(
+                emitter: &impl ::tauri_bindgen_host::tauri::Emitter<R>,
+                #(#idents: #tys),*
+            ) -> ::tauri_bindgen_host::Result<()> {
+                let payload = ::tauri_bindgen_host::postcard::to_allocvec(&(#(#idents,)*))?;
+                emitter.emit(#name, payload)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn print_manifest(&self) -> TokenStream {
+        let mod_name = self.interface.ident.to_snake_case();
+
+        let functions = self
+            .interface
+            .functions
+            .iter()
+            .map(|func| format!("{mod_name}::{}", func.id.to_snake_case()));
+
+        let methods = self
+            .interface
+            .typedefs
+            .iter()
+            .filter_map(|(_, typedef)| {
+                if let TypeDefKind::Resource(methods) = &typedef.kind {
+                    let names = methods.iter().map(|method| method.id.to_snake_case());
+
+                    Some(
+                        names
+                            .chain(["drop".to_string()])
+                            .map(|name| format!("{mod_name}::resource::{}::{name}", typedef.ident)),
+                    )
+                } else {
+                    None
+                }
+            })
+            .flatten();
+
+        let commands = functions.chain(methods);
+
+        quote! {
+            /// The identifiers of all functions `add_to_router` defines, as used by `Permissions`.
+            pub const COMMANDS: &[&str] = &[#(#commands),*];
+        }
+    }
+
     fn print_add_to_router<'a>(
         &self,
         mod_ident: &str,
         functions: impl Iterator<Item = &'a Function>,
+        resource_functions: &[ResourceFunction<'a>],
         methods: impl Iterator<Item = (&'a str, &'a Function)>,
     ) -> TokenStream {
-        let trait_ident = format_ident!("{}", mod_ident.to_upper_camel_case());
+        let trait_ident = format_ident!("{}", rust_type_name(mod_ident));
 
         let mod_name = mod_ident.to_snake_case();
 
-        let functions = functions.map(|func| self.print_router_fn_definition(&mod_name, func));
+        let functions = functions.map(|func| {
+            self.print_router_fn_definition(&mod_name, None, &func.id.to_snake_case(), func)
+        });
+
+        let resource_functions = resource_functions.iter().map(|f| {
+            self.print_router_fn_definition(
+                &mod_name,
+                Some(f.resource),
+                &f.name.to_snake_case(),
+                &f.func,
+            )
+        });
 
         let methods = methods.map(|(resource_name, method)| {
             self.print_router_method_definition(&mod_name, resource_name, method)
         });
 
+        let drops: Vec<_> = self
+            .interface
+            .typedefs
+            .iter()
+            .filter_map(|(_, typedef)| {
+                if let TypeDefKind::Resource(_) = &typedef.kind {
+                    Some(self.print_router_drop_definition(&mod_name, &typedef.ident))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let release = (!drops.is_empty()).then(|| {
+            quote! {
+                let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+                router.on_webview_destroyed(move |data, label| {
+                    get_cx(data).resource_table().release(label);
+                });
+            }
+        });
+
         quote! {
             pub fn add_to_router<T, U>(
                 router: &mut ::tauri_bindgen_host::ipc_router_wip::Router<T>,
@@ -463,7 +1004,10 @@ impl Host {
                 let wrapped_get_cx = ::std::sync::Arc::new(get_cx);
 
                 #( #functions )*
+                #( #resource_functions )*
                 #( #methods )*
+                #( #drops )*
+                #release
 
                 Ok(())
             }
@@ -475,8 +1019,7 @@ impl Generate for Host {
     fn to_tokens(&mut self) -> TokenStream {
         let docs = self.print_docs(&self.interface.docs);
 
-        let iface_name = self.interface.ident.to_snake_case();
-        let ident = format_ident!("{}", iface_name);
+        let ident = format_ident!("{}", rust_ident(&self.interface.ident));
 
         let typedefs = self.print_typedefs(
             self.interface.typedefs.iter().map(|(id, _)| id),
@@ -489,38 +1032,80 @@ impl Generate for Host {
             .iter()
             .filter_map(|(_, typedef)| {
                 if let TypeDefKind::Resource(methods) = &typedef.kind {
-                    Some(std::iter::repeat(typedef.ident.as_str()).zip(methods.iter()))
+                    Some(
+                        std::iter::repeat(typedef.ident.as_str())
+                            .zip(methods.iter().filter(|method| method.kind.is_method())),
+                    )
                 } else {
                     None
                 }
             })
             .flatten();
 
+        let resource_functions = ResourceFunction::collect(&self.interface);
+
         let resources = self.interface.typedefs.iter().filter_map(|(_, typedef)| {
-            if let TypeDefKind::Resource(_) = &typedef.kind {
-                let ident = format_ident!("{}", typedef.ident.to_upper_camel_case());
-                let func_ident = format_ident!("get_{}", typedef.ident.to_snake_case());
+            if let TypeDefKind::Resource(functions) = &typedef.kind {
+                let ident = format_ident!("{}", rust_type_name(&typedef.ident));
 
-                Some(quote! {
-                    type #ident: #ident + Send + Sync;
+                // the resources the methods take are the ones the interface looks up
+                let bindings: Vec<_> = self
+                    .referenced_resources(functions.iter().filter(|func| func.kind.is_method()))
+                    .into_iter()
+                    .map(|r| {
+                        let ident = format_ident!("{}", rust_type_name(r));
 
-                    fn #func_ident(&self, id: ::tauri_bindgen_host::ResourceId) -> ::tauri_bindgen_host::Result<::std::sync::Arc<Self::#ident>>;
+                        quote! { #ident = Self::#ident }
+                    })
+                    .collect();
+                let bindings = (!bindings.is_empty()).then(|| quote! { <#(#bindings),*> });
+
+                Some(quote! {
+                    type #ident: #ident #bindings + Send + Sync + 'static;
                 })
             } else {
                 None
             }
         });
 
+        let has_resources = self
+            .interface
+            .typedefs
+            .iter()
+            .any(|(_, typedef)| matches!(typedef.kind, TypeDefKind::Resource(_)));
+
+        let table = has_resources.then(|| {
+            quote! {
+                /// The table the resources handed to the guest are kept in.
+                ///
+                /// Handles the guest passes are looked up in it, and the resources of a webview are released when it is destroyed.
+                fn resource_table(&self) -> &::tauri_bindgen_host::ResourceTable;
+            }
+        });
+        let resources = resources.chain(table);
+
         let trait_ = self.print_trait(
             &self.interface.ident,
-            self.interface.functions.iter(),
+            self.interface
+                .functions
+                .iter()
+                .chain(resource_functions.iter().map(|f| &f.func)),
             resources,
             true,
         );
 
+        let manifest = self.opts.manifest.then(|| self.print_manifest());
+
+        let events = self
+            .interface
+            .events
+            .iter()
+            .map(|event| self.print_emit(event));
+
         let add_to_router = self.print_add_to_router(
             &self.interface.ident,
             self.interface.functions.iter(),
+            &resource_functions,
             methods,
         );
 
@@ -536,6 +1121,10 @@ impl Generate for Host {
 
                 #trait_
 
+                #manifest
+
+                #(#events)*
+
                 #add_to_router
             }
         }
@@ -555,3 +1144,70 @@ impl Generate for Host {
         }
     }
 }
+
+/// A constructor or static function of a resource, which the host implements in the interface's trait.
+struct ResourceFunction<'a> {
+    resource: &'a str,
+    /// The name the guest calls the function by.
+    name: &'a str,
+    /// The function as it appears in the trait, `new_<resource>` for constructors and `<resource>_<name>` otherwise.
+    func: Function,
+}
+
+impl<'a> ResourceFunction<'a> {
+    fn collect(interface: &'a Interface) -> Vec<Self> {
+        interface
+            .typedefs
+            .iter()
+            .filter_map(|(_, typedef)| {
+                if let TypeDefKind::Resource(functions) = &typedef.kind {
+                    Some(functions.iter().map(|func| (typedef.ident.as_str(), func)))
+                } else {
+                    None
+                }
+            })
+            .flatten()
+            .filter(|(_, func)| !func.kind.is_method())
+            .map(|(resource, func)| ResourceFunction {
+                resource,
+                name: &func.id,
+                func: Function {
+                    id: resource_function_id(resource, func),
+                    ..func.clone()
+                },
+            })
+            .collect()
+    }
+}
+
+/// The type of the value a function returns, `None` if it returns nothing.
+fn result_ty(func: &Function) -> Option<Type> {
+    match func.result.as_ref()? {
+        FunctionResult::Anon(ty) => Some(ty.clone()),
+        FunctionResult::Named(types) if types.len() == 1 => Some(types[0].1.clone()),
+        FunctionResult::Named(types) if types.is_empty() => None,
+        FunctionResult::Named(types) => Some(Type::Tuple(
+            types.iter().map(|(_, ty)| ty.clone()).collect(),
+        )),
+    }
+}
+
+fn enter(span: Option<TokenStream>) -> Option<TokenStream> {
+    span.map(|span| {
+        quote! {
+            let span = #span;
+            let _enter = span.enter();
+        }
+    })
+}
+
+/// Declares the span outside of the future, so fields can still read the parameters the future takes ownership of.
+fn instrument(span: Option<TokenStream>, fut: TokenStream) -> (Option<TokenStream>, TokenStream) {
+    match span {
+        Some(span) => (
+            Some(quote! { let span = #span; }),
+            quote! { ::tauri_bindgen_host::tracing::Instrument::instrument(#fut, span) },
+        ),
+        None => (None, fut),
+    }
+}
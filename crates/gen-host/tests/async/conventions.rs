@@ -23,6 +23,7 @@ pub mod conventions {
         async fn explicit(&self);
         async fn explicit_snake(&self);
         async fn bool(&self);
+        async fn r#type(&self, self_: u32, class: String);
     }
     pub fn add_to_router<T, U>(
         router: &mut ::tauri_bindgen_host::ipc_router_wip::Router<T>,
@@ -192,6 +193,22 @@ pub mod conventions {
                     })
                 },
             )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define_async(
+                "conventions",
+                "type",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (u32, String)|
+                {
+                    let get_cx = get_cx.clone();
+                    Box::pin(async move {
+                        let ctx = get_cx(ctx.data());
+                        Ok(ctx.r#type(p.0, p.1).await)
+                    })
+                },
+            )?;
         Ok(())
     }
 }
@@ -0,0 +1,62 @@
+///Events the host sends to the frontend.
+#[allow(unused_imports, unused_variables, dead_code)]
+#[rustfmt::skip]
+pub mod events {
+    use ::tauri_bindgen_host::serde;
+    use ::tauri_bindgen_host::bitflags;
+    ///How far a task got.
+    #[derive(serde::Serialize)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Progress {
+        pub done: u32,
+        pub total: u32,
+    }
+    #[::tauri_bindgen_host::async_trait]
+    pub trait Events: Sized {
+        ///Starts a task that reports its progress through events.
+        async fn start(&self);
+    }
+    ///Emitted whenever the task made progress.
+    pub fn emit_progress_changed<R: ::tauri_bindgen_host::tauri::Runtime>(
+        emitter: &impl ::tauri_bindgen_host::tauri::Emitter<R>,
+        progress: Progress,
+    ) -> ::tauri_bindgen_host::Result<()> {
+        let payload = ::tauri_bindgen_host::postcard::to_allocvec(&(progress,))?;
+        emitter.emit("events::progress_changed", payload)?;
+        Ok(())
+    }
+    ///Emitted once the task finished, with an error message if it failed.
+    pub fn emit_finished<R: ::tauri_bindgen_host::tauri::Runtime>(
+        emitter: &impl ::tauri_bindgen_host::tauri::Emitter<R>,
+        task: String,
+        error: Option<String>,
+    ) -> ::tauri_bindgen_host::Result<()> {
+        let payload = ::tauri_bindgen_host::postcard::to_allocvec(&(task, error))?;
+        emitter.emit("events::finished", payload)?;
+        Ok(())
+    }
+    pub fn add_to_router<T, U>(
+        router: &mut ::tauri_bindgen_host::ipc_router_wip::Router<T>,
+        get_cx: impl Fn(&T) -> &U + Send + Sync + 'static,
+    ) -> Result<(), ::tauri_bindgen_host::ipc_router_wip::Error>
+    where
+        T: Send + Sync + 'static,
+        U: Events + Send + Sync + 'static,
+    {
+        let wrapped_get_cx = ::std::sync::Arc::new(get_cx);
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define_async(
+                "events",
+                "start",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: ()| {
+                    let get_cx = get_cx.clone();
+                    Box::pin(async move {
+                        let ctx = get_cx(ctx.data());
+                        Ok(ctx.start().await)
+                    })
+                },
+            )?;
+        Ok(())
+    }
+}
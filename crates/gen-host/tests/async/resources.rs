@@ -12,27 +12,27 @@ pub mod resources {
     #[::tauri_bindgen_host::async_trait]
     pub trait B {
         type A: A;
-        async fn f1(&self) -> ::tauri_bindgen_host::ResourceId;
-        async fn f2(&self, x: ::tauri_bindgen_host::ResourceId) -> Result<u32, ()>;
+        async fn f1(&self) -> Self::A;
+        async fn f2(&self, x: ::std::sync::Arc<Self::A>) -> Result<u32, ()>;
         async fn f3(
             &self,
-            x: Option<Vec<::tauri_bindgen_host::ResourceId>>,
-        ) -> Result<::tauri_bindgen_host::ResourceId, ()>;
+            x: Option<Vec<::std::sync::Arc<Self::A>>>,
+        ) -> Result<Self::A, ()>;
+        async fn f4(&self, x: ::std::sync::Arc<Self::A>, y: Vec<Self::A>);
     }
     #[::tauri_bindgen_host::async_trait]
     pub trait Resources: Sized {
-        type A: A + Send + Sync;
-        fn get_a(
-            &self,
-            id: ::tauri_bindgen_host::ResourceId,
-        ) -> ::tauri_bindgen_host::Result<::std::sync::Arc<Self::A>>;
-        type B: B + Send + Sync;
-        fn get_b(
-            &self,
-            id: ::tauri_bindgen_host::ResourceId,
-        ) -> ::tauri_bindgen_host::Result<::std::sync::Arc<Self::B>>;
-        async fn constructor_a(&self) -> ::tauri_bindgen_host::ResourceId;
-        async fn constructor_b(&self) -> ::tauri_bindgen_host::ResourceId;
+        type A: A + Send + Sync + 'static;
+        type B: B<A = Self::A> + Send + Sync + 'static;
+        /// The table the resources handed to the guest are kept in.
+        ///
+        /// Handles the guest passes are looked up in it, and the resources of a webview are released when it is destroyed.
+        fn resource_table(&self) -> &::tauri_bindgen_host::ResourceTable;
+        async fn constructor_a(&self) -> Self::A;
+        async fn constructor_b(&self) -> Self::B;
+        async fn consume(&self, x: Self::A);
+        async fn new_a(&self, a: u32) -> Self::A;
+        async fn a_f0(&self, a: u32) -> Option<Self::A>;
     }
     pub fn add_to_router<T, U>(
         router: &mut ::tauri_bindgen_host::ipc_router_wip::Router<T>,
@@ -51,8 +51,14 @@ pub mod resources {
                 move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: ()| {
                     let get_cx = get_cx.clone();
                     Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
                         let ctx = get_cx(ctx.data());
-                        Ok(ctx.constructor_a().await)
+                        let ret = ctx.constructor_a().await;
+                        Ok(
+                            ctx
+                                .resource_table()
+                                .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))?,
+                        )
                     })
                 },
             )?;
@@ -64,8 +70,82 @@ pub mod resources {
                 move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: ()| {
                     let get_cx = get_cx.clone();
                     Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
                         let ctx = get_cx(ctx.data());
-                        Ok(ctx.constructor_b().await)
+                        let ret = ctx.constructor_b().await;
+                        Ok(
+                            ctx
+                                .resource_table()
+                                .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))?,
+                        )
+                    })
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define_async(
+                "resources",
+                "consume",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: ::tauri_bindgen_host::ResourceId|
+                {
+                    let get_cx = get_cx.clone();
+                    Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
+                        let ctx = get_cx(ctx.data());
+                        Ok(
+                            ctx
+                                .consume(
+                                    ctx.resource_table().take_value::<U::A>(cx.metadata(), p)?,
+                                )
+                                .await,
+                        )
+                    })
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define_async(
+                "resources::resource::a",
+                "constructor",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: u32| {
+                    let get_cx = get_cx.clone();
+                    Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
+                        let ctx = get_cx(ctx.data());
+                        let ret = ctx.new_a(p).await;
+                        Ok(
+                            ctx
+                                .resource_table()
+                                .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))?,
+                        )
+                    })
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define_async(
+                "resources::resource::a",
+                "f0",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: u32| {
+                    let get_cx = get_cx.clone();
+                    Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
+                        let ctx = get_cx(ctx.data());
+                        let ret = ctx.a_f0(p).await;
+                        Ok(
+                            match ret {
+                                Some(v) => {
+                                    Some(
+                                        ctx
+                                            .resource_table()
+                                            .push_owned(cx.metadata(), ::std::sync::Arc::new(v))?,
+                                    )
+                                }
+                                None => None,
+                            },
+                        )
                     })
                 },
             )?;
@@ -80,8 +160,11 @@ pub mod resources {
                 {
                     let get_cx = get_cx.clone();
                     Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
                         let ctx = get_cx(ctx.data());
-                        let r = ctx.get_a(p.0)?;
+                        let r = ctx
+                            .resource_table()
+                            .get_owned::<U::A>(cx.metadata(), p.0)?;
                         Ok(r.f1().await)
                     })
                 },
@@ -97,8 +180,11 @@ pub mod resources {
                 {
                     let get_cx = get_cx.clone();
                     Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
                         let ctx = get_cx(ctx.data());
-                        let r = ctx.get_a(p.0)?;
+                        let r = ctx
+                            .resource_table()
+                            .get_owned::<U::A>(cx.metadata(), p.0)?;
                         Ok(r.f2(p.1).await)
                     })
                 },
@@ -114,8 +200,11 @@ pub mod resources {
                 {
                     let get_cx = get_cx.clone();
                     Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
                         let ctx = get_cx(ctx.data());
-                        let r = ctx.get_a(p.0)?;
+                        let r = ctx
+                            .resource_table()
+                            .get_owned::<U::A>(cx.metadata(), p.0)?;
                         Ok(r.f3(p.1, p.2).await)
                     })
                 },
@@ -131,9 +220,17 @@ pub mod resources {
                 {
                     let get_cx = get_cx.clone();
                     Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
                         let ctx = get_cx(ctx.data());
-                        let r = ctx.get_b(p.0)?;
-                        Ok(r.f1().await)
+                        let r = ctx
+                            .resource_table()
+                            .get_owned::<U::B>(cx.metadata(), p.0)?;
+                        let ret = r.f1().await;
+                        Ok(
+                            ctx
+                                .resource_table()
+                                .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))?,
+                        )
                     })
                 },
             )?;
@@ -151,9 +248,18 @@ pub mod resources {
                 {
                     let get_cx = get_cx.clone();
                     Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
                         let ctx = get_cx(ctx.data());
-                        let r = ctx.get_b(p.0)?;
-                        Ok(r.f2(p.1).await)
+                        let r = ctx
+                            .resource_table()
+                            .get_owned::<U::B>(cx.metadata(), p.0)?;
+                        Ok(
+                            r
+                                .f2(
+                                    ctx.resource_table().get_owned::<U::A>(cx.metadata(), p.1)?,
+                                )
+                                .await,
+                        )
                     })
                 },
             )?;
@@ -171,12 +277,118 @@ pub mod resources {
                 {
                     let get_cx = get_cx.clone();
                     Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
                         let ctx = get_cx(ctx.data());
-                        let r = ctx.get_b(p.0)?;
-                        Ok(r.f3(p.1).await)
+                        let r = ctx
+                            .resource_table()
+                            .get_owned::<U::B>(cx.metadata(), p.0)?;
+                        let ret = r
+                            .f3(
+                                match p.1 {
+                                    Some(v) => {
+                                        Some({
+                                            let mut out = Vec::new();
+                                            for v in v {
+                                                out.push(
+                                                    ctx.resource_table().get_owned::<U::A>(cx.metadata(), v)?,
+                                                );
+                                            }
+                                            out
+                                        })
+                                    }
+                                    None => None,
+                                },
+                            )
+                            .await;
+                        Ok(
+                            match ret {
+                                Ok(v) => {
+                                    Ok(
+                                        ctx
+                                            .resource_table()
+                                            .push_owned(cx.metadata(), ::std::sync::Arc::new(v))?,
+                                    )
+                                }
+                                Err(v) => Err(v),
+                            },
+                        )
                     })
                 },
             )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define_async(
+                "resources::resource::b",
+                "f4",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (
+                        ::tauri_bindgen_host::ResourceId,
+                        ::tauri_bindgen_host::ResourceId,
+                        Vec<::tauri_bindgen_host::ResourceId>,
+                    )|
+                {
+                    let get_cx = get_cx.clone();
+                    Box::pin(async move {
+                        let cx = ::std::clone::Clone::clone(&ctx);
+                        let ctx = get_cx(ctx.data());
+                        let r = ctx
+                            .resource_table()
+                            .get_owned::<U::B>(cx.metadata(), p.0)?;
+                        Ok(
+                            r
+                                .f4(
+                                    ctx.resource_table().get_owned::<U::A>(cx.metadata(), p.1)?,
+                                    {
+                                        let mut out = Vec::new();
+                                        for v in p.2 {
+                                            out.push(
+                                                ctx.resource_table().take_value::<U::A>(cx.metadata(), v)?,
+                                            );
+                                        }
+                                        out
+                                    },
+                                )
+                                .await,
+                        )
+                    })
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "drop",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId,)|
+                {
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    ctx.resource_table().take_owned::<U::A>(cx.metadata(), p.0)?;
+                    Ok(())
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::b",
+                "drop",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId,)|
+                {
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    ctx.resource_table().take_owned::<U::B>(cx.metadata(), p.0)?;
+                    Ok(())
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .on_webview_destroyed(move |data, label| {
+                get_cx(data).resource_table().release(label);
+            });
         Ok(())
     }
 }
@@ -21,8 +21,10 @@ fn gen_interface(
 fn chars() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(opts, "chars", include_str!("../../../wit/chars.wit"));
@@ -35,8 +37,10 @@ fn chars() {
 fn convention() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(
@@ -53,8 +57,10 @@ fn convention() {
 fn empty() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(opts, "empty", include_str!("../../../wit/empty.wit"));
@@ -67,8 +73,10 @@ fn empty() {
 fn flags() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(opts, "flegs", include_str!("../../../wit/flags.wit"));
@@ -81,8 +89,10 @@ fn flags() {
 fn floats() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) =
@@ -96,8 +106,10 @@ fn floats() {
 fn integers() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) =
@@ -111,8 +123,10 @@ fn integers() {
 fn lists() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(opts, "lists", include_str!("../../../wit/lists.wit"));
@@ -125,8 +139,10 @@ fn lists() {
 fn many_arguments() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(
@@ -143,8 +159,10 @@ fn many_arguments() {
 fn multi_return() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(
@@ -161,8 +179,10 @@ fn multi_return() {
 fn records() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) =
@@ -176,8 +196,10 @@ fn records() {
 fn simple_functions() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(
@@ -194,8 +216,10 @@ fn simple_functions() {
 fn simple_lists() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(
@@ -212,8 +236,10 @@ fn simple_lists() {
 fn small_anonymous() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) = gen_interface(
@@ -230,8 +256,10 @@ fn small_anonymous() {
 fn strings() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) =
@@ -245,8 +273,10 @@ fn strings() {
 fn unions() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) =
@@ -260,8 +290,10 @@ fn unions() {
 fn variants() {
     let opts = Builder {
         fmt: true,
-        tracing: true,
+        tracing: false,
         async_: true,
+        manifest: false,
+        context: false,
     };
 
     let (filename, contents) =
@@ -270,3 +302,60 @@ fn variants() {
     assert_eq!(filename, "variants.rs");
     assert_eq!(contents, include_str!("./async/variants.rs"));
 }
+
+#[test]
+fn context() {
+    let opts = Builder {
+        fmt: true,
+        tracing: true,
+        async_: true,
+        manifest: false,
+        context: true,
+    };
+
+    let (filename, contents) = gen_interface(
+        opts,
+        "resources",
+        include_str!("../../../wit/resources.wit"),
+    );
+
+    assert_eq!(filename, "resources.rs");
+    assert_eq!(contents, include_str!("./context/resources.rs"));
+}
+
+#[test]
+fn events() {
+    let opts = Builder {
+        fmt: true,
+        tracing: false,
+        async_: true,
+        manifest: false,
+        context: false,
+    };
+
+    let (filename, contents) =
+        gen_interface(opts, "events", include_str!("../../../wit/events.wit"));
+
+    assert_eq!(filename, "events.rs");
+    assert_eq!(contents, include_str!("./async/events.rs"));
+}
+
+#[test]
+fn tracing() {
+    let opts = Builder {
+        fmt: true,
+        tracing: true,
+        async_: true,
+        manifest: false,
+        context: false,
+    };
+
+    let (filename, contents) = gen_interface(
+        opts,
+        "resources",
+        include_str!("../../../wit/resources.wit"),
+    );
+
+    assert_eq!(filename, "resources.rs");
+    assert_eq!(contents, include_str!("./async-tracing/resources.rs"));
+}
@@ -0,0 +1,559 @@
+#[allow(unused_imports, unused_variables, dead_code)]
+#[rustfmt::skip]
+pub mod resources {
+    use ::tauri_bindgen_host::serde;
+    use ::tauri_bindgen_host::bitflags;
+    pub trait A {
+        fn f1(&self);
+        fn f2(&self, a: u32);
+        fn f3(&self, a: u32, b: u32);
+    }
+    pub trait B {
+        type A: A;
+        fn f1(&self) -> Self::A;
+        fn f2(&self, x: ::std::sync::Arc<Self::A>) -> Result<u32, ()>;
+        fn f3(&self, x: Option<Vec<::std::sync::Arc<Self::A>>>) -> Result<Self::A, ()>;
+        fn f4(&self, x: ::std::sync::Arc<Self::A>, y: Vec<Self::A>);
+    }
+    pub trait Resources: Sized {
+        type A: A + Send + Sync + 'static;
+        type B: B<A = Self::A> + Send + Sync + 'static;
+        /// The table the resources handed to the guest are kept in.
+        ///
+        /// Handles the guest passes are looked up in it, and the resources of a webview are released when it is destroyed.
+        fn resource_table(&self) -> &::tauri_bindgen_host::ResourceTable;
+        fn constructor_a(&self) -> Self::A;
+        fn constructor_b(&self) -> Self::B;
+        fn consume(&self, x: Self::A);
+        fn new_a(&self, a: u32) -> Self::A;
+        fn a_f0(&self, a: u32) -> Option<Self::A>;
+    }
+    pub fn add_to_router<T, U>(
+        router: &mut ::tauri_bindgen_host::ipc_router_wip::Router<T>,
+        get_cx: impl Fn(&T) -> &U + Send + Sync + 'static,
+    ) -> Result<(), ::tauri_bindgen_host::ipc_router_wip::Error>
+    where
+        T: Send + Sync + 'static,
+        U: Resources + Send + Sync + 'static,
+    {
+        let wrapped_get_cx = ::std::sync::Arc::new(get_cx);
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources",
+                "constructor_a",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: ()| {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::constructor_a", interface = "resources", function =
+                        "constructor_a",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let ret = ctx.constructor_a();
+                    Ok(
+                        ctx
+                            .resource_table()
+                            .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))
+                            .map_err(|err| {
+                                ::tauri_bindgen_host::tracing::error!(
+                                    error = % err, "failed to insert resource"
+                                );
+                                err
+                            })?,
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources",
+                "constructor_b",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: ()| {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::constructor_b", interface = "resources", function =
+                        "constructor_b",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let ret = ctx.constructor_b();
+                    Ok(
+                        ctx
+                            .resource_table()
+                            .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))
+                            .map_err(|err| {
+                                ::tauri_bindgen_host::tracing::error!(
+                                    error = % err, "failed to insert resource"
+                                );
+                                err
+                            })?,
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources",
+                "consume",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: ::tauri_bindgen_host::ResourceId|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::consume", interface = "resources", function =
+                        "consume",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    Ok(
+                        ctx
+                            .consume(
+                                ctx
+                                    .resource_table()
+                                    .take_value::<U::A>(cx.metadata(), p)
+                                    .map_err(|err| {
+                                        ::tauri_bindgen_host::tracing::error!(
+                                            error = % err, "failed to take resource"
+                                        );
+                                        err
+                                    })?,
+                            ),
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "constructor",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: u32| {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::a::constructor", interface = "resources", resource =
+                        "a", function = "constructor",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let ret = ctx.new_a(p);
+                    Ok(
+                        ctx
+                            .resource_table()
+                            .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))
+                            .map_err(|err| {
+                                ::tauri_bindgen_host::tracing::error!(
+                                    error = % err, "failed to insert resource"
+                                );
+                                err
+                            })?,
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "f0",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: u32| {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::a::f0", interface = "resources", resource = "a",
+                        function = "f0",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let ret = ctx.a_f0(p);
+                    Ok(
+                        match ret {
+                            Some(v) => {
+                                Some(
+                                    ctx
+                                        .resource_table()
+                                        .push_owned(cx.metadata(), ::std::sync::Arc::new(v))
+                                        .map_err(|err| {
+                                            ::tauri_bindgen_host::tracing::error!(
+                                                error = % err, "failed to insert resource"
+                                            );
+                                            err
+                                        })?,
+                                )
+                            }
+                            None => None,
+                        },
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "f1",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId,)|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::a::f1", interface = "resources", resource = "a",
+                        resource_id = % p.0, function = "f1",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx
+                        .resource_table()
+                        .get_owned::<U::A>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to look up resource"
+                            );
+                            err
+                        })?;
+                    Ok(r.f1())
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "f2",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId, u32)|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::a::f2", interface = "resources", resource = "a",
+                        resource_id = % p.0, function = "f2",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx
+                        .resource_table()
+                        .get_owned::<U::A>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to look up resource"
+                            );
+                            err
+                        })?;
+                    Ok(r.f2(p.1))
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "f3",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId, u32, u32)|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::a::f3", interface = "resources", resource = "a",
+                        resource_id = % p.0, function = "f3",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx
+                        .resource_table()
+                        .get_owned::<U::A>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to look up resource"
+                            );
+                            err
+                        })?;
+                    Ok(r.f3(p.1, p.2))
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::b",
+                "f1",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId,)|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::b::f1", interface = "resources", resource = "b",
+                        resource_id = % p.0, function = "f1",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx
+                        .resource_table()
+                        .get_owned::<U::B>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to look up resource"
+                            );
+                            err
+                        })?;
+                    let ret = r.f1();
+                    Ok(
+                        ctx
+                            .resource_table()
+                            .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))
+                            .map_err(|err| {
+                                ::tauri_bindgen_host::tracing::error!(
+                                    error = % err, "failed to insert resource"
+                                );
+                                err
+                            })?,
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::b",
+                "f2",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (
+                        ::tauri_bindgen_host::ResourceId,
+                        ::tauri_bindgen_host::ResourceId,
+                    )|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::b::f2", interface = "resources", resource = "b",
+                        resource_id = % p.0, function = "f2",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx
+                        .resource_table()
+                        .get_owned::<U::B>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to look up resource"
+                            );
+                            err
+                        })?;
+                    let ret = r
+                        .f2(
+                            ctx
+                                .resource_table()
+                                .get_owned::<U::A>(cx.metadata(), p.1)
+                                .map_err(|err| {
+                                    ::tauri_bindgen_host::tracing::error!(
+                                        error = % err, "failed to look up resource"
+                                    );
+                                    err
+                                })?,
+                        );
+                    if let Err(err) = &ret {
+                        ::tauri_bindgen_host::tracing::error!(
+                            error = ? err, "call returned an error"
+                        );
+                    }
+                    Ok(ret)
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::b",
+                "f3",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (
+                        ::tauri_bindgen_host::ResourceId,
+                        Option<Vec<::tauri_bindgen_host::ResourceId>>,
+                    )|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::b::f3", interface = "resources", resource = "b",
+                        resource_id = % p.0, function = "f3",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx
+                        .resource_table()
+                        .get_owned::<U::B>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to look up resource"
+                            );
+                            err
+                        })?;
+                    let ret = r
+                        .f3(
+                            match p.1 {
+                                Some(v) => {
+                                    Some({
+                                        let mut out = Vec::new();
+                                        for v in v {
+                                            out.push(
+                                                ctx
+                                                    .resource_table()
+                                                    .get_owned::<U::A>(cx.metadata(), v)
+                                                    .map_err(|err| {
+                                                        ::tauri_bindgen_host::tracing::error!(
+                                                            error = % err, "failed to look up resource"
+                                                        );
+                                                        err
+                                                    })?,
+                                            );
+                                        }
+                                        out
+                                    })
+                                }
+                                None => None,
+                            },
+                        );
+                    if let Err(err) = &ret {
+                        ::tauri_bindgen_host::tracing::error!(
+                            error = ? err, "call returned an error"
+                        );
+                    }
+                    Ok(
+                        match ret {
+                            Ok(v) => {
+                                Ok(
+                                    ctx
+                                        .resource_table()
+                                        .push_owned(cx.metadata(), ::std::sync::Arc::new(v))
+                                        .map_err(|err| {
+                                            ::tauri_bindgen_host::tracing::error!(
+                                                error = % err, "failed to insert resource"
+                                            );
+                                            err
+                                        })?,
+                                )
+                            }
+                            Err(v) => Err(v),
+                        },
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::b",
+                "f4",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (
+                        ::tauri_bindgen_host::ResourceId,
+                        ::tauri_bindgen_host::ResourceId,
+                        Vec<::tauri_bindgen_host::ResourceId>,
+                    )|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::b::f4", interface = "resources", resource = "b",
+                        resource_id = % p.0, function = "f4",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx
+                        .resource_table()
+                        .get_owned::<U::B>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to look up resource"
+                            );
+                            err
+                        })?;
+                    Ok(
+                        r
+                            .f4(
+                                ctx
+                                    .resource_table()
+                                    .get_owned::<U::A>(cx.metadata(), p.1)
+                                    .map_err(|err| {
+                                        ::tauri_bindgen_host::tracing::error!(
+                                            error = % err, "failed to look up resource"
+                                        );
+                                        err
+                                    })?,
+                                {
+                                    let mut out = Vec::new();
+                                    for v in p.2 {
+                                        out.push(
+                                            ctx
+                                                .resource_table()
+                                                .take_value::<U::A>(cx.metadata(), v)
+                                                .map_err(|err| {
+                                                    ::tauri_bindgen_host::tracing::error!(
+                                                        error = % err, "failed to take resource"
+                                                    );
+                                                    err
+                                                })?,
+                                        );
+                                    }
+                                    out
+                                },
+                            ),
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "drop",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId,)|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::a::drop", interface = "resources", resource = "a",
+                        resource_id = % p.0, function = "drop",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    ctx.resource_table()
+                        .take_owned::<U::A>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to drop resource"
+                            );
+                            err
+                        })?;
+                    Ok(())
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::b",
+                "drop",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId,)|
+                {
+                    let span = ::tauri_bindgen_host::tracing::info_span!(
+                        "resources::b::drop", interface = "resources", resource = "b",
+                        resource_id = % p.0, function = "drop",
+                    );
+                    let _enter = span.enter();
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    ctx.resource_table()
+                        .take_owned::<U::B>(cx.metadata(), p.0)
+                        .map_err(|err| {
+                            ::tauri_bindgen_host::tracing::error!(
+                                error = % err, "failed to drop resource"
+                            );
+                            err
+                        })?;
+                    Ok(())
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .on_webview_destroyed(move |data, label| {
+                get_cx(data).resource_table().release(label);
+            });
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ pub mod conventions {
         fn explicit(&self);
         fn explicit_snake(&self);
         fn bool(&self);
+        fn r#type(&self, self_: u32, class: String);
     }
     pub fn add_to_router<T, U>(
         router: &mut ::tauri_bindgen_host::ipc_router_wip::Router<T>,
@@ -155,6 +156,19 @@ pub mod conventions {
                     Ok(ctx.bool())
                 },
             )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "conventions",
+                "type",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (u32, String)|
+                {
+                    let ctx = get_cx(ctx.data());
+                    Ok(ctx.r#type(p.0, p.1))
+                },
+            )?;
         Ok(())
     }
 }
@@ -10,26 +10,23 @@ pub mod resources {
     }
     pub trait B {
         type A: A;
-        fn f1(&self) -> ::tauri_bindgen_host::ResourceId;
-        fn f2(&self, x: ::tauri_bindgen_host::ResourceId) -> Result<u32, ()>;
-        fn f3(
-            &self,
-            x: Option<Vec<::tauri_bindgen_host::ResourceId>>,
-        ) -> Result<::tauri_bindgen_host::ResourceId, ()>;
+        fn f1(&self) -> Self::A;
+        fn f2(&self, x: ::std::sync::Arc<Self::A>) -> Result<u32, ()>;
+        fn f3(&self, x: Option<Vec<::std::sync::Arc<Self::A>>>) -> Result<Self::A, ()>;
+        fn f4(&self, x: ::std::sync::Arc<Self::A>, y: Vec<Self::A>);
     }
     pub trait Resources: Sized {
-        type A: A + Send + Sync;
-        fn get_a(
-            &self,
-            id: ::tauri_bindgen_host::ResourceId,
-        ) -> ::tauri_bindgen_host::Result<::std::sync::Arc<Self::A>>;
-        type B: B + Send + Sync;
-        fn get_b(
-            &self,
-            id: ::tauri_bindgen_host::ResourceId,
-        ) -> ::tauri_bindgen_host::Result<::std::sync::Arc<Self::B>>;
-        fn constructor_a(&self) -> ::tauri_bindgen_host::ResourceId;
-        fn constructor_b(&self) -> ::tauri_bindgen_host::ResourceId;
+        type A: A + Send + Sync + 'static;
+        type B: B<A = Self::A> + Send + Sync + 'static;
+        /// The table the resources handed to the guest are kept in.
+        ///
+        /// Handles the guest passes are looked up in it, and the resources of a webview are released when it is destroyed.
+        fn resource_table(&self) -> &::tauri_bindgen_host::ResourceTable;
+        fn constructor_a(&self) -> Self::A;
+        fn constructor_b(&self) -> Self::B;
+        fn consume(&self, x: Self::A);
+        fn new_a(&self, a: u32) -> Self::A;
+        fn a_f0(&self, a: u32) -> Option<Self::A>;
     }
     pub fn add_to_router<T, U>(
         router: &mut ::tauri_bindgen_host::ipc_router_wip::Router<T>,
@@ -46,8 +43,14 @@ pub mod resources {
                 "resources",
                 "constructor_a",
                 move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: ()| {
+                    let cx = ::std::clone::Clone::clone(&ctx);
                     let ctx = get_cx(ctx.data());
-                    Ok(ctx.constructor_a())
+                    let ret = ctx.constructor_a();
+                    Ok(
+                        ctx
+                            .resource_table()
+                            .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))?,
+                    )
                 },
             )?;
         let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
@@ -56,8 +59,72 @@ pub mod resources {
                 "resources",
                 "constructor_b",
                 move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: ()| {
+                    let cx = ::std::clone::Clone::clone(&ctx);
                     let ctx = get_cx(ctx.data());
-                    Ok(ctx.constructor_b())
+                    let ret = ctx.constructor_b();
+                    Ok(
+                        ctx
+                            .resource_table()
+                            .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))?,
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources",
+                "consume",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: ::tauri_bindgen_host::ResourceId|
+                {
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    Ok(
+                        ctx
+                            .consume(
+                                ctx.resource_table().take_value::<U::A>(cx.metadata(), p)?,
+                            ),
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "constructor",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: u32| {
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let ret = ctx.new_a(p);
+                    Ok(
+                        ctx
+                            .resource_table()
+                            .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))?,
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "f0",
+                move |ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>, p: u32| {
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let ret = ctx.a_f0(p);
+                    Ok(
+                        match ret {
+                            Some(v) => {
+                                Some(
+                                    ctx
+                                        .resource_table()
+                                        .push_owned(cx.metadata(), ::std::sync::Arc::new(v))?,
+                                )
+                            }
+                            None => None,
+                        },
+                    )
                 },
             )?;
         let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
@@ -69,8 +136,9 @@ pub mod resources {
                     ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
                     p: (::tauri_bindgen_host::ResourceId,)|
                 {
+                    let cx = ::std::clone::Clone::clone(&ctx);
                     let ctx = get_cx(ctx.data());
-                    let r = ctx.get_a(p.0)?;
+                    let r = ctx.resource_table().get_owned::<U::A>(cx.metadata(), p.0)?;
                     Ok(r.f1())
                 },
             )?;
@@ -83,8 +151,9 @@ pub mod resources {
                     ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
                     p: (::tauri_bindgen_host::ResourceId, u32)|
                 {
+                    let cx = ::std::clone::Clone::clone(&ctx);
                     let ctx = get_cx(ctx.data());
-                    let r = ctx.get_a(p.0)?;
+                    let r = ctx.resource_table().get_owned::<U::A>(cx.metadata(), p.0)?;
                     Ok(r.f2(p.1))
                 },
             )?;
@@ -97,8 +166,9 @@ pub mod resources {
                     ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
                     p: (::tauri_bindgen_host::ResourceId, u32, u32)|
                 {
+                    let cx = ::std::clone::Clone::clone(&ctx);
                     let ctx = get_cx(ctx.data());
-                    let r = ctx.get_a(p.0)?;
+                    let r = ctx.resource_table().get_owned::<U::A>(cx.metadata(), p.0)?;
                     Ok(r.f3(p.1, p.2))
                 },
             )?;
@@ -111,9 +181,15 @@ pub mod resources {
                     ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
                     p: (::tauri_bindgen_host::ResourceId,)|
                 {
+                    let cx = ::std::clone::Clone::clone(&ctx);
                     let ctx = get_cx(ctx.data());
-                    let r = ctx.get_b(p.0)?;
-                    Ok(r.f1())
+                    let r = ctx.resource_table().get_owned::<U::B>(cx.metadata(), p.0)?;
+                    let ret = r.f1();
+                    Ok(
+                        ctx
+                            .resource_table()
+                            .push_owned(cx.metadata(), ::std::sync::Arc::new(ret))?,
+                    )
                 },
             )?;
         let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
@@ -128,9 +204,10 @@ pub mod resources {
                         ::tauri_bindgen_host::ResourceId,
                     )|
                 {
+                    let cx = ::std::clone::Clone::clone(&ctx);
                     let ctx = get_cx(ctx.data());
-                    let r = ctx.get_b(p.0)?;
-                    Ok(r.f2(p.1))
+                    let r = ctx.resource_table().get_owned::<U::B>(cx.metadata(), p.0)?;
+                    Ok(r.f2(ctx.resource_table().get_owned::<U::A>(cx.metadata(), p.1)?))
                 },
             )?;
         let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
@@ -145,11 +222,108 @@ pub mod resources {
                         Option<Vec<::tauri_bindgen_host::ResourceId>>,
                     )|
                 {
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx.resource_table().get_owned::<U::B>(cx.metadata(), p.0)?;
+                    let ret = r
+                        .f3(
+                            match p.1 {
+                                Some(v) => {
+                                    Some({
+                                        let mut out = Vec::new();
+                                        for v in v {
+                                            out.push(
+                                                ctx.resource_table().get_owned::<U::A>(cx.metadata(), v)?,
+                                            );
+                                        }
+                                        out
+                                    })
+                                }
+                                None => None,
+                            },
+                        );
+                    Ok(
+                        match ret {
+                            Ok(v) => {
+                                Ok(
+                                    ctx
+                                        .resource_table()
+                                        .push_owned(cx.metadata(), ::std::sync::Arc::new(v))?,
+                                )
+                            }
+                            Err(v) => Err(v),
+                        },
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::b",
+                "f4",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (
+                        ::tauri_bindgen_host::ResourceId,
+                        ::tauri_bindgen_host::ResourceId,
+                        Vec<::tauri_bindgen_host::ResourceId>,
+                    )|
+                {
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    let r = ctx.resource_table().get_owned::<U::B>(cx.metadata(), p.0)?;
+                    Ok(
+                        r
+                            .f4(
+                                ctx.resource_table().get_owned::<U::A>(cx.metadata(), p.1)?,
+                                {
+                                    let mut out = Vec::new();
+                                    for v in p.2 {
+                                        out.push(
+                                            ctx.resource_table().take_value::<U::A>(cx.metadata(), v)?,
+                                        );
+                                    }
+                                    out
+                                },
+                            ),
+                    )
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::a",
+                "drop",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId,)|
+                {
+                    let cx = ::std::clone::Clone::clone(&ctx);
+                    let ctx = get_cx(ctx.data());
+                    ctx.resource_table().take_owned::<U::A>(cx.metadata(), p.0)?;
+                    Ok(())
+                },
+            )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .define(
+                "resources::resource::b",
+                "drop",
+                move |
+                    ctx: ::tauri_bindgen_host::ipc_router_wip::Caller<T>,
+                    p: (::tauri_bindgen_host::ResourceId,)|
+                {
+                    let cx = ::std::clone::Clone::clone(&ctx);
                     let ctx = get_cx(ctx.data());
-                    let r = ctx.get_b(p.0)?;
-                    Ok(r.f3(p.1))
+                    ctx.resource_table().take_owned::<U::B>(cx.metadata(), p.0)?;
+                    Ok(())
                 },
             )?;
+        let get_cx = ::std::sync::Arc::clone(&wrapped_get_cx);
+        router
+            .on_webview_destroyed(move |data, label| {
+                get_cx(data).resource_table().release(label);
+            });
         Ok(())
     }
 }
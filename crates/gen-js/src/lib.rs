@@ -1,10 +1,13 @@
 use fmt::Write;
-use heck::{ToLowerCamelCase, ToUpperCamelCase};
 use std::fmt;
-use tauri_bindgen_core::{flags_repr, union_case_names, TypeInfos};
+use tauri_bindgen_core::{
+    flags_repr,
+    names::{js_ident, js_type_name},
+    union_case_names, TypeInfos,
+};
 use wit_parser::{
-    EnumCase, FlagsField, Function, FunctionResult, Interface, RecordField, Type, TypeDefArena,
-    TypeDefId, TypeDefKind, UnionCase, VariantCase,
+    EnumCase, Event, FlagsField, Function, FunctionResult, Interface, RecordField, Type,
+    TypeDefArena, TypeDefId, TypeDefKind, UnionCase, VariantCase,
 };
 
 pub trait JavaScriptGenerator {
@@ -92,16 +95,20 @@ pub trait JavaScriptGenerator {
 
                 format!("deserializeResult(de, {ok}, {err})")
             }
+            Type::Own(id) | Type::Borrow(id) => format!(
+                "{}.deserialize(de)",
+                js_type_name(&self.interface().typedefs[*id].ident)
+            ),
             Type::Id(id) => {
                 if let TypeDefKind::Resource(_) = self.interface().typedefs[*id].kind {
                     format!(
                         "{}.deserialize(de)",
-                        self.interface().typedefs[*id].ident.to_upper_camel_case()
+                        js_type_name(&self.interface().typedefs[*id].ident)
                     )
                 } else {
                     format!(
                         "deserialize{}(de)",
-                        self.interface().typedefs[*id].ident.to_upper_camel_case()
+                        js_type_name(&self.interface().typedefs[*id].ident)
                     )
                 }
             }
@@ -110,7 +117,7 @@ pub trait JavaScriptGenerator {
 
     fn print_deserialize_typedef(&self, id: TypeDefId) -> String {
         let typedef = &self.interface().typedefs[id];
-        let ident = &typedef.ident.to_upper_camel_case();
+        let ident = &js_type_name(&typedef.ident);
 
         match &typedef.kind {
             TypeDefKind::Alias(ty) => self.print_deserialize_alias(ident, ty),
@@ -137,7 +144,7 @@ pub trait JavaScriptGenerator {
         let fields = fields
             .iter()
             .map(|field| {
-                let ident = field.id.to_lower_camel_case();
+                let ident = js_ident(&field.id);
 
                 format!("{ident}: {}", self.print_deserialize_ty(&field.ty))
             })
@@ -179,7 +186,7 @@ pub trait JavaScriptGenerator {
                     .as_ref()
                     .map_or("null".to_string(), |ty| self.print_deserialize_ty(ty));
 
-                let ident = case.id.to_upper_camel_case();
+                let ident = js_type_name(&case.id);
 
                 let _ = write!(
                     str,
@@ -209,7 +216,7 @@ pub trait JavaScriptGenerator {
             .iter()
             .enumerate()
             .fold(String::new(), |mut str, (tag, case)| {
-                let ident = case.id.to_upper_camel_case();
+                let ident = js_type_name(&case.id);
 
                 let _ = write!(
                     str,
@@ -314,22 +321,92 @@ pub trait JavaScriptGenerator {
 
                 format!("serializeResult(out, (out, v) => {ok}, (out, v) => {err}, {ident})")
             }
+            // owned handles are only given up once the host accepted the call, see `print_transfer_params`
+            Type::Own(_) | Type::Borrow(_) => format!("{ident}.serialize(out)"),
             Type::Id(id) => {
                 if let TypeDefKind::Resource(_) = self.interface().typedefs[*id].kind {
                     format!("{ident}.serialize(out)")
                 } else {
                     format!(
                         "serialize{}(out, {ident})",
-                        self.interface().typedefs[*id].ident.to_upper_camel_case()
+                        js_type_name(&self.interface().typedefs[*id].ident)
                     )
                 }
             }
         }
     }
 
+    /// Gives up the handles the parameters hand over with `own` once the call succeeded,
+    /// so a failed call leaves them usable and the guest can still dispose them.
+    fn print_transfer_params(&self, params: &[(String, Type)]) -> String {
+        let transfers: Vec<_> = params
+            .iter()
+            .filter_map(|(ident, ty)| self.print_transfer(&js_ident(ident), ty))
+            .collect();
+
+        if transfers.is_empty() {
+            return String::new();
+        }
+
+        format!(
+            "
+        .then(r => {{
+            {}
+            return r
+        }})",
+            transfers.join("\n            ")
+        )
+    }
+
+    /// Gives up the owned handles in `ident`, returns `None` if `ty` has none.
+    ///
+    /// Named types can't contain owned handles, `check_interface` rejects them.
+    fn print_transfer(&self, ident: &str, ty: &Type) -> Option<String> {
+        match ty {
+            Type::Own(_) => Some(format!("{ident}.transfer();")),
+            Type::List(ty) => {
+                let inner = self.print_transfer("v", ty)?;
+
+                Some(format!("{ident}.forEach(v => {{ {inner} }});"))
+            }
+            Type::Option(ty) => {
+                let inner = self.print_transfer(ident, ty)?;
+
+                Some(format!("if ({ident}) {{ {inner} }}"))
+            }
+            Type::Tuple(tys) => {
+                let inner: Vec<_> = tys
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, ty)| self.print_transfer(&format!("{ident}[{idx}]"), ty))
+                    .collect();
+
+                (!inner.is_empty()).then(|| inner.join(" "))
+            }
+            Type::Result { ok, err } => {
+                let ok = ok
+                    .as_ref()
+                    .and_then(|ty| self.print_transfer(&format!("{ident}.Ok"), ty));
+                let err = err
+                    .as_ref()
+                    .and_then(|ty| self.print_transfer(&format!("{ident}.Err"), ty));
+
+                match (ok, err) {
+                    (None, None) => None,
+                    (ok, err) => Some(format!(
+                        "if ({ident}.Ok) {{ {} }} else if ({ident}.Err) {{ {} }}",
+                        ok.unwrap_or_default(),
+                        err.unwrap_or_default()
+                    )),
+                }
+            }
+            _ => None,
+        }
+    }
+
     fn print_serialize_typedef(&self, id: TypeDefId) -> String {
         let typedef = &self.interface().typedefs[id];
-        let ident = &typedef.ident.to_upper_camel_case();
+        let ident = &js_type_name(&typedef.ident);
 
         match &typedef.kind {
             TypeDefKind::Alias(ty) => self.print_serialize_alias(ident, ty),
@@ -387,7 +464,7 @@ pub trait JavaScriptGenerator {
             .iter()
             .enumerate()
             .fold(String::new(), |mut str, (tag, case)| {
-                let prop_access = format!("val.{}", case.id.to_upper_camel_case());
+                let prop_access = format!("val.{}", js_type_name(&case.id));
 
                 let inner = case.ty.as_ref().map_or(String::new(), |ty| {
                     self.print_serialize_ty(&prop_access, ty)
@@ -420,7 +497,7 @@ pub trait JavaScriptGenerator {
             .iter()
             .enumerate()
             .fold(String::new(), |mut str, (tag, case)| {
-                let ident = case.id.to_upper_camel_case();
+                let ident = js_type_name(&case.id);
 
                 let _ = write!(
                     str,
@@ -501,6 +578,7 @@ bitflags::bitflags! {
         const DE                = 1 << 20;
         const SER               = 1 << 21;
         const STR_UTIL          = 1 << 22;
+        const IPC_ERROR         = 1 << 23;
 
         const VARINT            = Self::_VARINT.bits() | Self::VARINT_MAX.bits();
         const U8               = Self::BITS8.bits() | Self::VARINT.bits() | Self::UNSIGNED.bits();
@@ -699,6 +777,10 @@ impl std::fmt::Display for SerdeUtils {
             f.write_str("const __text_encoder = new TextEncoder();\n")?;
         }
 
+        if self.contains(SerdeUtils::IPC_ERROR) {
+            f.write_str(include_str!("./js/ipc_error.js"))?;
+        }
+
         Ok(())
     }
 }
@@ -708,6 +790,10 @@ impl SerdeUtils {
     pub fn collect_from_functions(typedefs: &TypeDefArena, functions: &[Function]) -> Self {
         let mut info = Self::empty();
 
+        if !functions.is_empty() {
+            info |= SerdeUtils::IPC_ERROR;
+        }
+
         for func in functions {
             for (_, ty) in &func.params {
                 info |= SerdeUtils::SER;
@@ -732,6 +818,34 @@ impl SerdeUtils {
         info
     }
 
+    /// The utilities needed to decode the payloads of `events`.
+    pub fn collect_from_events(typedefs: &TypeDefArena, events: &[Event]) -> Self {
+        let mut info = Self::empty();
+
+        for event in events {
+            for (_, ty) in &event.payload {
+                info |= SerdeUtils::DE;
+                info |= Self::collect_type_info(typedefs, ty);
+            }
+        }
+
+        info
+    }
+
+    /// The utilities needed by the classes of resources, which encode their handle and call the host.
+    pub fn collect_from_resources(typedefs: &TypeDefArena) -> Self {
+        let mut info = Self::empty();
+
+        for (_, typedef) in typedefs {
+            if let TypeDefKind::Resource(functions) = &typedef.kind {
+                info |= SerdeUtils::SER | SerdeUtils::U64 | SerdeUtils::IPC_ERROR;
+                info |= Self::collect_from_functions(typedefs, functions);
+            }
+        }
+
+        info
+    }
+
     fn collect_typedef_info(typedefs: &TypeDefArena, id: TypeDefId) -> SerdeUtils {
         let mut info = SerdeUtils::empty();
         match &typedefs[id].kind {
@@ -757,9 +871,12 @@ impl SerdeUtils {
                     info |= Self::collect_type_info(typedefs, &case.ty);
                 }
             }
-            TypeDefKind::Enum(_) | TypeDefKind::Resource(_) => {
+            TypeDefKind::Enum(_) => {
                 info |= SerdeUtils::U32;
             }
+            TypeDefKind::Resource(_) => {
+                info |= SerdeUtils::U64;
+            }
             TypeDefKind::Flags(fields) => {
                 info |= match flags_repr(fields) {
                     wit_parser::Int::U8 => SerdeUtils::U8,
@@ -810,7 +927,9 @@ impl SerdeUtils {
 
                 SerdeUtils::RESULT | ok | err
             }
-            Type::Id(id) => Self::collect_typedef_info(typedefs, *id),
+            Type::Own(id) | Type::Borrow(id) | Type::Id(id) => {
+                Self::collect_typedef_info(typedefs, *id)
+            }
         }
     }
 }
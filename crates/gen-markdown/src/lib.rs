@@ -2,7 +2,7 @@ use heck::{ToKebabCase, ToSnakeCase};
 use std::fmt::Write;
 use std::path::PathBuf;
 use tauri_bindgen_core::{Generate, GeneratorBuilder};
-use wit_parser::{Function, FunctionResult, Interface, Type, TypeDefId};
+use wit_parser::{Event, Function, FunctionKind, FunctionResult, Interface, Type, TypeDefId};
 
 #[derive(Default, Debug, Clone)]
 #[cfg_attr(feature = "clap", derive(clap::Args))]
@@ -64,6 +64,8 @@ impl Markdown {
 
                 format!("result<{ok}, {err}>")
             }
+            Type::Own(id) => format!("own<{}>", self.print_ty(&Type::Id(*id))),
+            Type::Borrow(id) => format!("borrow<{}>", self.print_ty(&Type::Id(*id))),
             Type::Id(id) => {
                 let ident = &self.interface.typedefs[*id].ident;
                 let lnk = ident.to_snake_case();
@@ -159,14 +161,25 @@ impl Markdown {
             }
             wit_parser::TypeDefKind::Resource(functions) => {
                 let functions = functions.iter().fold(String::new(), |mut str, func| {
+                    let (title, head) = match func.kind {
+                        FunctionKind::Constructor => {
+                            ("Constructor".to_string(), "constructor".to_string())
+                        }
+                        FunctionKind::Static => (
+                            format!("Static function {}", func.id),
+                            format!("static func {}", func.id),
+                        ),
+                        _ => (format!("Method {}", func.id), format!("func {}", func.id)),
+                    };
+
                     let _ = write!(
                         str,
-                        "### Method {ident}\n\n`func {ident} ({params}){result}`\n\n{docs}",
-                        ident = func.id,
+                        "### {title}\n\n`{head} ({params}){result}`\n\n{docs}",
                         params = self.print_named_types(&func.params),
                         result = func
                             .result
                             .as_ref()
+                            .filter(|_| func.kind != FunctionKind::Constructor)
                             .map(|result| self.print_result(result))
                             .unwrap_or_default(),
                         docs = func.docs
@@ -194,6 +207,15 @@ impl Markdown {
         )
     }
 
+    fn print_event(&self, event: &Event) -> String {
+        format!(
+            "### Event {ident}\n\n` event {ident} ({payload})`\n\n{docs}",
+            ident = event.id,
+            payload = self.print_named_types(&event.payload),
+            docs = event.docs
+        )
+    }
+
     fn print_named_types(&self, types: &[(String, Type)]) -> String {
         types
             .iter()
@@ -241,10 +263,22 @@ impl Generate for Markdown {
             .collect::<Vec<_>>()
             .join("\n");
 
-        let contents = format!(
+        let mut contents = format!(
             "# {ident}\n\n{docs}\n\n## Type definitions\n\n{typedefs}\n\n## Functions\n\n{functions}",
         );
 
+        if !self.interface.events.is_empty() {
+            let events = self
+                .interface
+                .events
+                .iter()
+                .map(|event| self.print_event(event))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            let _ = write!(contents, "\n\n## Events\n\n{events}");
+        }
+
         let mut filename = PathBuf::from(self.interface.ident.to_kebab_case());
         filename.set_extension("md");
 
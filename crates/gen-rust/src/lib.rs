@@ -1,15 +1,18 @@
 #![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
 
-use heck::{ToShoutySnekCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{format_ident, quote};
 
 use syn::Lifetime;
-use tauri_bindgen_core::{flags_repr, union_case_names, TypeInfo, TypeInfos};
+use tauri_bindgen_core::{
+    flags_repr,
+    names::{rust_const_name, rust_ident, rust_type_name},
+    union_case_names, TypeInfo, TypeInfos,
+};
 
 use wit_parser::{
-    EnumCase, FlagsField, Function, FunctionResult, Int, Interface, RecordField, Type, TypeDefId,
-    TypeDefKind, UnionCase, VariantCase,
+    EnumCase, FlagsField, Function, FunctionResult, Int, Interface, RecordField, Type,
+    TypeDefArena, TypeDefId, TypeDefKind, UnionCase, VariantCase,
 };
 
 pub trait RustGenerator {
@@ -26,6 +29,11 @@ pub trait RustGenerator {
         info: TypeInfo,
     ) -> TokenStream;
 
+    /// Whether the generated code can only use `core` and `alloc`, not `std`.
+    fn no_std(&self) -> bool {
+        false
+    }
+
     fn print_typedefs(
         &self,
         ids: impl Iterator<Item = TypeDefId>,
@@ -36,7 +44,7 @@ pub trait RustGenerator {
         for id in ids {
             let typedef = &self.interface().typedefs[id];
             let info = self.infos()[id];
-            let variants = self.variants_of(&typedef.ident.to_upper_camel_case(), info, mode);
+            let variants = self.variants_of(&rust_type_name(&typedef.ident), info, mode);
 
             for TypeVariant { ident, borrow_mode } in variants {
                 let docs = &typedef.docs;
@@ -87,10 +95,13 @@ pub trait RustGenerator {
             Type::Float64 => quote! { f64 },
             Type::Char => quote! { char },
             Type::String => match mode {
+                BorrowMode::Owned if self.no_std() => quote! { alloc::string::String },
                 BorrowMode::Owned => quote! { String },
                 BorrowMode::AllBorrowed(lt) | BorrowMode::LeafBorrowed(lt) => quote! { &#lt str },
             },
             Type::List(ty) => {
+                // owned handles have to be moved out of the list
+                let moves_handles = has_own(ty, &self.interface().typedefs);
                 let is_primitive = matches!(
                     **ty,
                     Type::U8
@@ -106,15 +117,21 @@ pub trait RustGenerator {
                 );
 
                 let ty = self.print_ty(ty, mode);
+                let vec = if self.no_std() {
+                    quote! { alloc::vec::Vec }
+                } else {
+                    quote! { Vec }
+                };
 
                 match mode {
-                    BorrowMode::Owned => quote! { Vec<#ty> },
+                    BorrowMode::Owned => quote! { #vec<#ty> },
+                    BorrowMode::AllBorrowed(_) if moves_handles => quote! { #vec<#ty> },
                     BorrowMode::AllBorrowed(lt) => quote! { &#lt [#ty] },
                     BorrowMode::LeafBorrowed(lt) => {
                         if is_primitive {
                             quote! { &#lt [#ty] }
                         } else {
-                            quote! { Vec<#ty> }
+                            quote! { #vec<#ty> }
                         }
                     }
                 }
@@ -147,21 +164,44 @@ pub trait RustGenerator {
 
                 quote! { Result<#ok, #err> }
             }
+            // an owned handle is moved into the call, so the caller can't use it afterwards
+            Type::Own(id) => {
+                let ident =
+                    format_ident!("{}", rust_type_name(&self.interface().typedefs[*id].ident));
+
+                quote! { #ident }
+            }
+            Type::Borrow(id) => {
+                let ident =
+                    format_ident!("{}", rust_type_name(&self.interface().typedefs[*id].ident));
+
+                match mode {
+                    BorrowMode::AllBorrowed(lt) | BorrowMode::LeafBorrowed(lt) => {
+                        quote! { &#lt #ident }
+                    }
+                    BorrowMode::Owned => quote! { #ident },
+                }
+            }
             Type::Id(id) => {
                 let typedef = &self.interface().typedefs[*id];
                 let info = self.infos()[*id];
 
+                // passing a handle doesn't give it up, only dropping it does
+                if let TypeDefKind::Resource(_) = typedef.kind {
+                    return self.print_ty(&Type::Borrow(*id), mode);
+                }
+
                 let ident = if self.uses_two_names(info) {
                     match mode {
                         BorrowMode::Owned => {
-                            format_ident!("{}Result", typedef.ident.to_upper_camel_case())
+                            format_ident!("{}Result", rust_type_name(&typedef.ident))
                         }
                         BorrowMode::AllBorrowed(_) | BorrowMode::LeafBorrowed(_) => {
-                            format_ident!("{}Param", typedef.ident.to_upper_camel_case())
+                            format_ident!("{}Param", rust_type_name(&typedef.ident))
                         }
                     }
                 } else {
-                    format_ident!("{}", typedef.ident.to_upper_camel_case())
+                    format_ident!("{}", rust_type_name(&typedef.ident))
                 };
 
                 let generics = print_generics(info, mode);
@@ -199,6 +239,7 @@ pub trait RustGenerator {
     ) -> TokenStream {
         let docs = self.print_docs(docs);
         let additional_attrs = self.additional_attrs(&ident.to_string(), info);
+        let derives = print_derives(info);
         let generics = print_generics(info, mode);
         let fields = fields
             .iter()
@@ -207,7 +248,7 @@ pub trait RustGenerator {
         quote! {
             #docs
             #additional_attrs
-            #[derive(Debug, Clone, PartialEq)]
+            #derives
             pub struct #ident #generics {
                 #(#fields),*
             }
@@ -219,7 +260,7 @@ pub trait RustGenerator {
         let borrow_attr = self
             .needs_borrow(&field.ty, mode)
             .then_some(quote! { #[serde(borrow)] });
-        let ident = format_ident!("{}", field.id.to_snake_case());
+        let ident = format_ident!("{}", rust_ident(&field.id));
         let ty = self.print_ty(&field.ty, mode);
 
         quote! {
@@ -240,19 +281,23 @@ pub trait RustGenerator {
         let additional_attrs = self.additional_attrs(&ident.to_string(), info);
         let repr = self.print_int(&flags_repr(fields));
 
-        let fields = fields
-            .iter()
-            .enumerate()
-            .map(|(i, FlagsField { docs, id: ident })| {
+        let fields = fields.iter().enumerate().map(
+            |(
+                i,
+                FlagsField {
+                    docs, id: ident, ..
+                },
+            )| {
                 let docs = self.print_docs(docs);
-                let ident = format_ident!("{}", ident.TO_SHOUTY_SNEK_CASE());
+                let ident = format_ident!("{}", rust_const_name(ident));
                 let i = Literal::usize_unsuffixed(i);
 
                 quote! {
                     #docs
                     const #ident = 1 << #i;
                 }
-            });
+            },
+        );
 
         quote! {
             bitflags::bitflags! {
@@ -275,13 +320,14 @@ pub trait RustGenerator {
     ) -> TokenStream {
         let docs = self.print_docs(docs);
         let additional_attrs = self.additional_attrs(&ident.to_string(), info);
+        let derives = print_derives(info);
         let generics = print_generics(info, mode);
         let cases = cases.iter().map(|case| self.print_variant_case(case, mode));
 
         quote! {
             #docs
             #additional_attrs
-            #[derive(Debug, Clone, PartialEq)]
+            #derives
             pub enum #ident #generics {
                 #(#cases),*
             }
@@ -290,7 +336,7 @@ pub trait RustGenerator {
 
     fn print_variant_case(&self, case: &VariantCase, mode: &BorrowMode) -> TokenStream {
         let docs = self.print_docs(&case.docs);
-        let ident = format_ident!("{}", case.id.to_upper_camel_case());
+        let ident = format_ident!("{}", rust_type_name(&case.id));
 
         let payload = case.ty.as_ref().map(|ty| {
             let ty = self.print_ty(ty, mode);
@@ -327,7 +373,7 @@ pub trait RustGenerator {
 
     fn print_enum_case(&self, case: &EnumCase) -> TokenStream {
         let docs = self.print_docs(&case.docs);
-        let ident = format_ident!("{}", case.id.to_upper_camel_case());
+        let ident = format_ident!("{}", rust_type_name(&case.id));
 
         quote! {
             #docs
@@ -345,6 +391,7 @@ pub trait RustGenerator {
     ) -> TokenStream {
         let docs = self.print_docs(docs);
         let additional_attrs = self.additional_attrs(&ident.to_string(), info);
+        let derives = print_derives(info);
         let generics = print_generics(info, mode);
 
         let cases = union_case_names(&self.interface().typedefs, cases)
@@ -364,7 +411,7 @@ pub trait RustGenerator {
         quote! {
             #docs
             #additional_attrs
-            #[derive(Debug, Clone, PartialEq)]
+            #derives
             pub enum #ident #generics {
                 #(#cases),*
             }
@@ -378,7 +425,7 @@ pub trait RustGenerator {
         results_mode: &BorrowMode,
     ) -> TokenStream {
         let docs = self.print_docs(&sig.func.docs);
-        let ident = format_ident!("{}", sig.func.id.to_snake_case());
+        let ident = format_ident!("{}", rust_ident(&sig.func.id));
 
         let pub_ = (!sig.private).then_some(quote! { pub });
         let unsafe_ = sig.unsafe_.then_some(quote! { unsafe });
@@ -392,7 +439,16 @@ pub trait RustGenerator {
             .func
             .result
             .as_ref()
-            .map(|result| self.print_function_result(result, results_mode));
+            .and_then(|result| self.print_function_result(result, results_mode));
+
+        let result = match &sig.error {
+            Some(err) => {
+                let ok = result.unwrap_or(quote! { () });
+
+                Some(quote! { -> Result<#ok, #err> })
+            }
+            None => result.map(|ty| quote! { -> #ty }),
+        };
 
         quote! {
             #docs
@@ -402,7 +458,7 @@ pub trait RustGenerator {
 
     fn print_function_params(&self, params: &[(String, Type)], mode: &BorrowMode) -> TokenStream {
         let params = params.iter().map(|(ident, ty)| {
-            let ident = format_ident!("{}", ident.to_snake_case());
+            let ident = format_ident!("{}", rust_ident(ident));
             let ty = self.print_ty(ty, mode);
 
             quote! { #ident: #ty }
@@ -411,24 +467,23 @@ pub trait RustGenerator {
         quote! { #(#params),* }
     }
 
-    fn print_function_result(&self, result: &FunctionResult, mode: &BorrowMode) -> TokenStream {
+    fn print_function_result(
+        &self,
+        result: &FunctionResult,
+        mode: &BorrowMode,
+    ) -> Option<TokenStream> {
         match result {
-            FunctionResult::Anon(ty) => {
-                let ty = self.print_ty(ty, mode);
-
-                quote! { -> #ty }
-            }
-            FunctionResult::Named(types) if types.is_empty() => quote! {},
+            FunctionResult::Anon(ty) => Some(self.print_ty(ty, mode)),
+            FunctionResult::Named(types) if types.is_empty() => None,
             FunctionResult::Named(types) if types.len() == 1 => {
                 let (_, ty) = &types[0];
-                let ty = self.print_ty(ty, mode);
 
-                quote! { -> #ty }
+                Some(self.print_ty(ty, mode))
             }
             FunctionResult::Named(types) => {
                 let types = types.iter().map(|(_, ty)| self.print_ty(ty, mode));
 
-                quote! { -> (#(#types),*) }
+                Some(quote! { (#(#types),*) })
             }
         }
     }
@@ -452,7 +507,7 @@ pub trait RustGenerator {
     }
 
     fn uses_two_names(&self, info: TypeInfo) -> bool {
-        info.contains(TypeInfo::HAS_LIST)
+        info.intersects(TypeInfo::HAS_LIST | TypeInfo::HAS_RESOURCE)
             && info.contains(TypeInfo::PARAM | TypeInfo::RESULT)
             && match self.default_param_mode() {
                 BorrowMode::AllBorrowed(_) | BorrowMode::LeafBorrowed(_) => true,
@@ -511,14 +566,48 @@ pub struct FnSig<'a> {
     pub unsafe_: bool,
     pub private: bool,
     pub self_arg: Option<TokenStream>,
+    /// The error type the result is wrapped in, for functions that can fail independently of their declared result.
+    pub error: Option<TokenStream>,
     pub func: &'a Function,
 }
 
+/// Whether `ty` hands over a resource handle with `own`, also inside the named types it refers to.
+#[must_use]
+pub fn has_own(ty: &Type, typedefs: &TypeDefArena) -> bool {
+    match ty {
+        Type::Own(_) => true,
+        Type::List(ty) | Type::Option(ty) => has_own(ty, typedefs),
+        Type::Tuple(types) => types.iter().any(|ty| has_own(ty, typedefs)),
+        Type::Result { ok, err } => ok.iter().chain(err).any(|ty| has_own(ty, typedefs)),
+        // named types can't be recursive, the resolver rejects them
+        Type::Id(id) => match &typedefs[*id].kind {
+            TypeDefKind::Alias(ty) => has_own(ty, typedefs),
+            TypeDefKind::Record(fields) => fields.iter().any(|field| has_own(&field.ty, typedefs)),
+            TypeDefKind::Variant(cases) => cases
+                .iter()
+                .filter_map(|case| case.ty.as_ref())
+                .any(|ty| has_own(ty, typedefs)),
+            TypeDefKind::Union(cases) => cases.iter().any(|case| has_own(&case.ty, typedefs)),
+            TypeDefKind::Flags(_) | TypeDefKind::Enum(_) | TypeDefKind::Resource(_) => false,
+        },
+        _ => false,
+    }
+}
+
+/// Types that hand over a resource handle can't be cloned, the handle would be released twice.
+fn print_derives(info: TypeInfo) -> TokenStream {
+    if info.contains(TypeInfo::HAS_OWN) {
+        quote! { #[derive(Debug, PartialEq)] }
+    } else {
+        quote! { #[derive(Debug, Clone, PartialEq)] }
+    }
+}
+
 #[must_use]
 pub fn lifetime_for(info: TypeInfo, mode: &BorrowMode) -> Option<&Lifetime> {
     match mode {
         BorrowMode::AllBorrowed(s) | BorrowMode::LeafBorrowed(s)
-            if info.contains(TypeInfo::HAS_LIST) =>
+            if info.intersects(TypeInfo::HAS_LIST | TypeInfo::HAS_RESOURCE) =>
         {
             Some(s)
         }
@@ -1,65 +1,432 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[doc(hidden)]
+pub extern crate alloc;
+
 pub use tauri_bindgen_guest_rust_macro::*;
 #[doc(hidden)]
 pub use {bitflags, serde, tracing};
 
-use js_sys::Uint8Array;
-use serde::{de::DeserializeOwned, Serialize};
-use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::JsFuture;
-use web_sys::{Request, RequestInit, RequestMode, Response};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{fmt, future::Future, pin::Pin};
+use once_cell::race::OnceBox;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-#[derive(Debug, thiserror::Error)]
+/// The ways a call to the host can fail, independently of the result declared in the interface.
+#[derive(Debug)]
 pub enum Error {
-    #[error("todo")]
-    Postcard(#[from] postcard::Error),
-    #[error("todo")]
-    JsError(JsValue),
-    #[error("todo")]
+    /// The parameters couldn't be encoded or the response couldn't be decoded.
+    Postcard(postcard::Error),
+    /// JavaScript threw an exception, e.g. because the request couldn't be sent.
+    #[cfg(feature = "fetch")]
+    JsError(wasm_bindgen::JsValue),
+    /// There is no global `window` to send requests through.
+    #[cfg(feature = "fetch")]
     NoWindow,
+    /// No transport was set, and there is no default one without the `fetch` feature.
+    NoTransport,
+    /// [`set_transport`] was called more than once.
+    TransportAlreadySet,
+    /// A custom transport failed to deliver the call.
+    Transport(String),
+    /// The host couldn't handle the call.
+    Host(HostError),
+    /// The transport can't deliver events emitted by the host.
+    EventsUnsupported,
 }
 
-/// # Errors
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Postcard(err) => write!(f, "failed to encode or decode a message: {err}"),
+            #[cfg(feature = "fetch")]
+            Error::JsError(err) => write!(f, "JavaScript exception: {err:?}"),
+            #[cfg(feature = "fetch")]
+            Error::NoWindow => f.write_str("no global `window` exists"),
+            Error::NoTransport => f.write_str("no transport was set"),
+            Error::TransportAlreadySet => f.write_str("a transport was already set"),
+            Error::Transport(msg) => write!(f, "transport failed: {msg}"),
+            Error::Host(err) => write!(f, "host error: {err}"),
+            Error::EventsUnsupported => f.write_str("the transport doesn't support events"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Postcard(err) => Some(err),
+            Error::Host(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<postcard::Error> for Error {
+    fn from(err: postcard::Error) -> Self {
+        Self::Postcard(err)
+    }
+}
+
+impl From<HostError> for Error {
+    fn from(err: HostError) -> Self {
+        Self::Host(err)
+    }
+}
+
+/// What went wrong on the host, as reported by the code in the error envelope.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Deserialize)]
+#[serde(from = "u32")]
+pub enum ErrorKind {
+    /// The host doesn't define the requested function.
+    NotFound,
+    /// The host couldn't decode the request.
+    InvalidRequest,
+    /// The host function returned an error.
+    Handler,
+    /// The host function panicked.
+    Panic,
+    /// The host doesn't allow this webview to call the function.
+    Forbidden,
+    /// A kind this version doesn't know about.
+    Unknown(u32),
+}
+
+impl From<u32> for ErrorKind {
+    fn from(code: u32) -> Self {
+        match code {
+            1 => ErrorKind::NotFound,
+            2 => ErrorKind::InvalidRequest,
+            3 => ErrorKind::Handler,
+            4 => ErrorKind::Panic,
+            5 => ErrorKind::Forbidden,
+            code => ErrorKind::Unknown(code),
+        }
+    }
+}
+
+/// The error envelope the host responds with when a call fails.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct HostError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub details: Option<String>,
+}
+
+impl HostError {
+    /// Decodes the postcard encoded `(kind: u32, message: string, details: option<string>)` envelope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `bytes` is not a valid envelope.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(postcard::from_bytes(bytes)?)
+    }
+}
+
+impl fmt::Display for HostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+
+        if let Some(details) = &self.details {
+            write!(f, ": {details}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HostError {}
+
+/// The future returned by [`Transport::send`].
+pub type SendFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<u8>, Error>> + 'a>>;
+
+/// Receives the postcard encoded payloads of an event, see [`Transport::listen`].
+pub type EventHandler = Box<dyn Fn(&[u8])>;
+
+/// The future returned by [`Transport::listen`].
+pub type ListenFuture<'a> = Pin<Box<dyn Future<Output = Result<Subscription, Error>> + 'a>>;
+
+/// A future run in the background by [`Transport::spawn`].
+pub type SpawnFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// A listener for an event emitted by the host, removed when the subscription is dropped.
+#[must_use = "dropping a subscription stops listening to the event"]
+pub struct Subscription {
+    unlisten: Option<Box<dyn FnOnce()>>,
+}
+
+impl Subscription {
+    /// Creates a subscription that calls `unlisten` when dropped.
+    pub fn new(unlisten: impl FnOnce() + 'static) -> Self {
+        Self {
+            unlisten: Some(Box::new(unlisten)),
+        }
+    }
+
+    /// Keeps listening for as long as the page lives.
+    pub fn forget(mut self) {
+        self.unlisten = None;
+    }
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription").finish_non_exhaustive()
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(unlisten) = self.unlisten.take() {
+            unlisten();
+        }
+    }
+}
+
+/// Delivers encoded calls to the host and returns the encoded response.
+///
+/// Generated bindings send calls through the transport registered with [`set_transport`],
+/// or [`Fetch`] if none was registered.
+pub trait Transport: Send + Sync {
+    /// Sends the postcard encoded parameters of `module::method` and resolves to the postcard encoded result.
+    ///
+    /// Calls the host failed to handle should resolve to [`Error::Host`].
+    fn send<'a>(&'a self, module: &'a str, method: &'a str, body: Vec<u8>) -> SendFuture<'a>;
+
+    /// Calls `handler` with the postcard encoded payload every time the host emits `event`,
+    /// until the returned [`Subscription`] is dropped.
+    ///
+    /// Transports that can't receive events resolve to [`Error::EventsUnsupported`], which is the default.
+    fn listen<'a>(&'a self, event: &'a str, handler: EventHandler) -> ListenFuture<'a> {
+        let _ = (event, handler);
+        Box::pin(async { Err(Error::EventsUnsupported) })
+    }
+
+    /// Runs `future` to completion without waiting for it, used to release resource handles when they are dropped.
+    ///
+    /// Transports without an executor drop the future, which leaves the resource alive on the host, this is the default.
+    fn spawn(&self, future: SpawnFuture) {
+        drop(future);
+        tracing::warn!("the transport can't spawn futures, dropped resources aren't released");
+    }
+}
+
+/// Hands calls to a function in the same process instead of sending them anywhere.
 ///
-/// Everything here is fallible (TODO improve this)
+/// This lets native tests run generated bindings against a host `Router` or a mock:
 ///
-/// # Panics
+/// ```ignore
+/// tauri_bindgen_guest_rust::set_transport(InMemory(move |module, method, body| {
+///     let router = router.clone();
+///     async move {
+///         router
+///             .call(Some(&module), &method, &body)
+///             .await
+///             .map_err(|err| HostError::from_bytes(&err.to_bytes()).map_or_else(|e| e, Error::Host))
+///     }
+/// }))?;
+/// ```
+pub struct InMemory<F>(pub F);
+
+impl<F, Fut> Transport for InMemory<F>
+where
+    F: Fn(String, String, Vec<u8>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Vec<u8>, Error>> + 'static,
+{
+    fn send<'a>(&'a self, module: &'a str, method: &'a str, body: Vec<u8>) -> SendFuture<'a> {
+        Box::pin((self.0)(module.into(), method.into(), body))
+    }
+}
+
+static TRANSPORT: OnceBox<Box<dyn Transport>> = OnceBox::new();
+
+/// Sends all following calls through `transport`, instead of the default `fetch`.
+///
+/// # Errors
 ///
-/// Panics when the response returned by JavaScript is not a `ResponseObject`
+/// Returns an error when a transport was already set.
+pub fn set_transport(transport: impl Transport + 'static) -> Result<(), Error> {
+    TRANSPORT
+        .set(Box::new(Box::new(transport)))
+        .map_err(|_| Error::TransportAlreadySet)
+}
+
+/// # Errors
+///
+/// Returns an error when the parameters can't be encoded, the call can't be delivered or the response can't be decoded.
 pub async fn invoke<P, R>(module: &str, method: &str, val: &P) -> Result<R, Error>
 where
     P: Serialize,
     R: DeserializeOwned,
 {
-    let opts = RequestInit::new();
-    opts.set_method("POST");
-    opts.set_mode(RequestMode::Cors);
+    let body = postcard::to_allocvec(val)?;
+
+    let response = match TRANSPORT.get() {
+        Some(transport) => transport.send(module, method, body).await?,
+        #[cfg(feature = "fetch")]
+        None => Fetch.send(module, method, body).await?,
+        #[cfg(not(feature = "fetch"))]
+        None => return Err(Error::NoTransport),
+    };
+
+    Ok(postcard::from_bytes(&response)?)
+}
+
+/// Tells the host to drop the resource `id` of `module` without waiting for the response.
+///
+/// Called by the `Drop` impl of generated resource handles, use their `dispose` method to wait for the host instead.
+pub fn release(module: &'static str, id: u64) {
+    let future = Box::pin(async move {
+        if let Err(err) = invoke::<_, ()>(module, "drop", &(id,)).await {
+            tracing::error!(module, id, "failed to release resource: {err}");
+        }
+    });
+
+    match TRANSPORT.get() {
+        Some(transport) => transport.spawn(future),
+        #[cfg(feature = "fetch")]
+        None => Fetch.spawn(future),
+        #[cfg(not(feature = "fetch"))]
+        None => tracing::warn!(module, id, "no transport set, the resource isn't released"),
+    }
+}
+
+/// Calls `handler` with the decoded payload every time the host emits `event`.
+///
+/// Payloads that can't be decoded are logged and skipped.
+///
+/// # Errors
+///
+/// Returns an error when the transport can't subscribe to the event.
+pub async fn listen<P>(event: &str, handler: impl Fn(P) + 'static) -> Result<Subscription, Error>
+where
+    P: DeserializeOwned,
+{
+    let name = String::from(event);
+    let handler: EventHandler = Box::new(move |payload| match postcard::from_bytes(payload) {
+        Ok(payload) => handler(payload),
+        Err(err) => tracing::error!(event = %name, "failed to decode event payload: {err}"),
+    });
+
+    match TRANSPORT.get() {
+        Some(transport) => transport.listen(event, handler).await,
+        #[cfg(feature = "fetch")]
+        None => Fetch.listen(event, handler).await,
+        #[cfg(not(feature = "fetch"))]
+        None => Err(Error::NoTransport),
+    }
+}
+
+#[cfg(feature = "fetch")]
+pub use fetch::Fetch;
+
+#[cfg(feature = "fetch")]
+mod fetch {
+    use super::{
+        Error, EventHandler, HostError, ListenFuture, SendFuture, SpawnFuture, Subscription,
+        Transport,
+    };
+    use js_sys::{Function, Promise, Reflect, Uint8Array};
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{Request, RequestInit, RequestMode, Response};
+
+    /// Sends calls to `ipc://localhost/{module}/{method}` using the webview's `fetch`, where the host's router handles them.
+    ///
+    /// Events are received through `window.__TAURI__.event.listen`, which requires `app.withGlobalTauri`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the response returned by JavaScript is not a `ResponseObject`
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Fetch;
+
+    impl Transport for Fetch {
+        fn send<'a>(&'a self, module: &'a str, method: &'a str, body: Vec<u8>) -> SendFuture<'a> {
+            Box::pin(send(module, method, body))
+        }
+
+        fn listen<'a>(&'a self, event: &'a str, handler: EventHandler) -> ListenFuture<'a> {
+            Box::pin(listen(event, handler))
+        }
+
+        fn spawn(&self, future: SpawnFuture) {
+            wasm_bindgen_futures::spawn_local(future);
+        }
+    }
+
+    async fn send(module: &str, method: &str, body: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let opts = RequestInit::new();
+        opts.set_method("POST");
+        opts.set_mode(RequestMode::Cors);
+
+        let body = unsafe { Uint8Array::view(&body) };
+        opts.set_body(&body);
+
+        let url = format!("ipc://localhost/{module}/{method}");
+
+        let request = Request::new_with_str_and_init(&url, &opts).map_err(Error::JsError)?;
+
+        request
+            .headers()
+            .set("Accept", "application/octet-stream")
+            .map_err(Error::JsError)?;
+
+        let window = web_sys::window().ok_or(Error::NoWindow)?;
+        let resp_value = JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .map_err(Error::JsError)?;
+
+        // `resp_value` is a `Response` object.
+        assert!(resp_value.is_instance_of::<Response>());
+        let resp: Response = resp_value.dyn_into().map_err(Error::JsError)?;
 
-    let bytes = postcard::to_allocvec(val)?;
-    let body = unsafe { Uint8Array::view(&bytes) };
-    opts.set_body(&body);
+        let body = JsFuture::from(resp.array_buffer().map_err(Error::JsError)?)
+            .await
+            .map_err(Error::JsError)?;
+        let body = Uint8Array::new(&body).to_vec();
 
-    let url = format!("ipc://localhost/{module}/{method}");
+        if resp.ok() {
+            Ok(body)
+        } else {
+            Err(HostError::from_bytes(&body)?.into())
+        }
+    }
 
-    let request = Request::new_with_str_and_init(&url, &opts).map_err(Error::JsError)?;
+    async fn listen(event: &str, handler: EventHandler) -> Result<Subscription, Error> {
+        let window = web_sys::window().ok_or(Error::NoWindow)?;
 
-    request
-        .headers()
-        .set("Accept", "application/octet-stream")
-        .map_err(Error::JsError)?;
+        let listen = ["__TAURI__", "event", "listen"]
+            .into_iter()
+            .try_fold(JsValue::from(window), |obj, key| {
+                Reflect::get(&obj, &key.into())
+            })
+            .and_then(JsCast::dyn_into::<Function>)
+            .map_err(Error::JsError)?;
 
-    let window = web_sys::window().ok_or(Error::NoWindow)?;
-    let resp_value = JsFuture::from(window.fetch_with_request(&request))
-        .await
-        .map_err(Error::JsError)?;
+        // the payload is the encoded `Vec<u8>`, serialized by tauri as an array of numbers
+        let callback = Closure::<dyn Fn(JsValue)>::new(move |event: JsValue| {
+            if let Ok(payload) = Reflect::get(&event, &"payload".into()) {
+                handler(&Uint8Array::new(&payload).to_vec());
+            }
+        });
 
-    // `resp_value` is a `Response` object.
-    assert!(resp_value.is_instance_of::<Response>());
-    let resp: Response = resp_value.dyn_into().map_err(Error::JsError)?;
+        let promise: Promise = listen
+            .call2(&JsValue::UNDEFINED, &event.into(), callback.as_ref())
+            .and_then(JsCast::dyn_into)
+            .map_err(Error::JsError)?;
 
-    let body = JsFuture::from(resp.array_buffer().map_err(Error::JsError)?)
-        .await
-        .map_err(Error::JsError)?;
-    let body = Uint8Array::new(&body);
+        let unlisten: Function = JsFuture::from(promise)
+            .await
+            .and_then(JsCast::dyn_into)
+            .map_err(Error::JsError)?;
 
-    Ok(postcard::from_bytes(&body.to_vec())?)
+        Ok(Subscription::new(move || {
+            let _ = unlisten.call0(&JsValue::UNDEFINED);
+            drop(callback);
+        }))
+    }
 }
@@ -0,0 +1,100 @@
+use futures_executor::block_on;
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::Once};
+use tauri_bindgen_guest_rust::{
+    Error, EventHandler, ListenFuture, SendFuture, Subscription, Transport,
+};
+
+tauri_bindgen_guest_rust::generate!({
+    path: "../../wit/events.wit"
+});
+
+thread_local! {
+    static LISTENERS: RefCell<HashMap<String, EventHandler>> = RefCell::default();
+}
+
+/// Delivers events the way the webview would, to the listeners of the current thread.
+struct Events;
+
+impl Transport for Events {
+    fn send<'a>(&'a self, _module: &'a str, method: &'a str, _body: Vec<u8>) -> SendFuture<'a> {
+        let method = method.to_string();
+
+        Box::pin(async move { Err(Error::Transport(format!("method {method} not found"))) })
+    }
+
+    fn listen<'a>(&'a self, event: &'a str, handler: EventHandler) -> ListenFuture<'a> {
+        let event = event.to_string();
+
+        Box::pin(async move {
+            LISTENERS.with(|l| l.borrow_mut().insert(event.clone(), handler));
+
+            Ok(Subscription::new(move || {
+                LISTENERS.with(|l| l.borrow_mut().remove(&event));
+            }))
+        })
+    }
+}
+
+fn emit(event: &str, payload: &impl serde::Serialize) {
+    let payload = postcard::to_allocvec(payload).unwrap();
+
+    LISTENERS.with(|l| {
+        if let Some(listener) = l.borrow().get(event) {
+            listener(&payload);
+        }
+    });
+}
+
+fn setup() {
+    static SETUP: Once = Once::new();
+
+    SETUP.call_once(|| {
+        tauri_bindgen_guest_rust::set_transport(Events).unwrap();
+    });
+}
+
+#[test]
+fn subscribe() {
+    setup();
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    let subscription = block_on(events::on_finished({
+        let received = received.clone();
+        move |task, error| received.borrow_mut().push((task, error))
+    }))
+    .unwrap();
+
+    emit("events::finished", &("build", Some("failed")));
+    emit("events::finished", &("test", None::<&str>));
+    emit("events::progress_changed", &((1u32, 2u32),));
+
+    drop(subscription);
+    emit("events::finished", &("lint", None::<&str>));
+
+    assert_eq!(
+        *received.borrow(),
+        [
+            ("build".to_string(), Some("failed".to_string())),
+            ("test".to_string(), None)
+        ]
+    );
+}
+
+#[test]
+fn invalid_payload() {
+    setup();
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    let _subscription = block_on(events::on_progress_changed({
+        let received = received.clone();
+        move |progress| received.borrow_mut().push(progress)
+    }))
+    .unwrap();
+
+    emit("events::progress_changed", &((3u32, 4u32),));
+    emit("events::progress_changed", &());
+
+    assert_eq!(*received.borrow(), [events::Progress { done: 3, total: 4 }]);
+}
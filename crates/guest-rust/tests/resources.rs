@@ -0,0 +1,103 @@
+use futures_executor::block_on;
+use std::{cell::RefCell, sync::Once};
+use tauri_bindgen_guest_rust::{SendFuture, SpawnFuture, Transport};
+
+tauri_bindgen_guest_rust::generate!({
+    path: "../../wit/resources.wit"
+});
+
+thread_local! {
+    static CALLS: RefCell<Vec<(String, Vec<u8>)>> = RefCell::default();
+    static SPAWNED: RefCell<Vec<SpawnFuture>> = RefCell::default();
+}
+
+/// Records the calls of the current thread and hands out the handle `7` for every new resource.
+struct Host;
+
+impl Transport for Host {
+    fn send<'a>(&'a self, module: &'a str, method: &'a str, body: Vec<u8>) -> SendFuture<'a> {
+        CALLS.with(|c| c.borrow_mut().push((format!("{module}/{method}"), body)));
+
+        let response = match method {
+            "constructor" => postcard::to_allocvec(&7u64).unwrap(),
+            _ => Vec::new(),
+        };
+
+        Box::pin(async move { Ok(response) })
+    }
+
+    fn spawn(&self, future: SpawnFuture) {
+        SPAWNED.with(|s| s.borrow_mut().push(future));
+    }
+}
+
+fn setup() {
+    static SETUP: Once = Once::new();
+
+    SETUP.call_once(|| {
+        tauri_bindgen_guest_rust::set_transport(Host).unwrap();
+    });
+}
+
+fn run_spawned() {
+    for future in SPAWNED.with(|s| s.take()) {
+        block_on(future);
+    }
+}
+
+fn calls() -> Vec<(String, Vec<u8>)> {
+    CALLS.with(|c| c.take())
+}
+
+#[test]
+fn drop_releases() {
+    setup();
+
+    let a = block_on(resources::A::new(1)).unwrap();
+    block_on(a.f2(2)).unwrap();
+    drop(a);
+    run_spawned();
+
+    assert_eq!(
+        calls(),
+        [
+            ("resources::resource::a/constructor".to_string(), vec![1]),
+            ("resources::resource::a/f2".to_string(), vec![7, 2]),
+            ("resources::resource::a/drop".to_string(), vec![7]),
+        ]
+    );
+}
+
+#[test]
+fn dispose() {
+    setup();
+
+    let a = block_on(resources::A::new(1)).unwrap();
+    block_on(a.dispose()).unwrap();
+
+    assert!(SPAWNED.with(|s| s.borrow().is_empty()));
+    assert_eq!(
+        calls(),
+        [
+            ("resources::resource::a/constructor".to_string(), vec![1]),
+            ("resources::resource::a/drop".to_string(), vec![7]),
+        ]
+    );
+}
+
+#[test]
+fn own_hands_over() {
+    setup();
+
+    let a = block_on(resources::A::new(1)).unwrap();
+    block_on(resources::consume(a)).unwrap();
+    run_spawned();
+
+    assert_eq!(
+        calls(),
+        [
+            ("resources::resource::a/constructor".to_string(), vec![1]),
+            ("resources/consume".to_string(), vec![7]),
+        ]
+    );
+}
@@ -0,0 +1,77 @@
+use futures_executor::block_on;
+use std::sync::Once;
+use tauri_bindgen_guest_rust::{Error, ErrorKind, HostError, InMemory};
+
+tauri_bindgen_guest_rust::generate!({
+    path: "../../wit/strings.wit"
+});
+
+/// Answers calls the way a host implementing `strings` would.
+async fn host(module: String, method: String, body: Vec<u8>) -> Result<Vec<u8>, Error> {
+    assert_eq!(module, "strings");
+
+    let res = match method.as_str() {
+        "a" => {
+            let envelope = postcard::to_allocvec(&(3u32, "invalid string", Some("too short")))?;
+
+            return Err(HostError::from_bytes(&envelope)?.into());
+        }
+        "b" => postcard::to_allocvec("hello")?,
+        "c" => {
+            let (a, b): (String, String) = postcard::from_bytes(&body)?;
+
+            postcard::to_allocvec(&(a + &b))?
+        }
+        _ => return Err(Error::Transport(format!("method {method} not found"))),
+    };
+
+    Ok(res)
+}
+
+fn setup() {
+    static SETUP: Once = Once::new();
+
+    SETUP.call_once(|| {
+        tauri_bindgen_guest_rust::set_transport(InMemory(host)).unwrap();
+    });
+}
+
+#[test]
+fn in_memory() {
+    setup();
+
+    assert_eq!(block_on(strings::b()).unwrap(), "hello");
+    assert_eq!(block_on(strings::c("foo", "bar")).unwrap(), "foobar");
+}
+
+#[test]
+fn transport_error() {
+    setup();
+
+    let err = block_on(tauri_bindgen_guest_rust::invoke::<_, ()>(
+        "strings",
+        "d",
+        &(),
+    ))
+    .unwrap_err();
+    assert!(matches!(err, Error::Transport(_)), "{err:?}");
+
+    let err = tauri_bindgen_guest_rust::set_transport(InMemory(host)).unwrap_err();
+    assert!(matches!(err, Error::TransportAlreadySet));
+}
+
+#[test]
+fn host_error() {
+    setup();
+
+    let Error::Host(err) = block_on(strings::a("foo")).unwrap_err() else {
+        panic!("expected a host error");
+    };
+    assert_eq!(err.kind, ErrorKind::Handler);
+    assert_eq!(err.to_string(), "invalid string: too short");
+
+    let envelope = postcard::to_allocvec(&(42u32, "new", None::<&str>)).unwrap();
+    let err = HostError::from_bytes(&envelope).unwrap();
+    assert_eq!(err.kind, ErrorKind::Unknown(42));
+    assert_eq!(err.details, None);
+}
@@ -10,11 +10,15 @@ pub fn generate(input: TokenStream) -> TokenStream {
 
 mod kw {
     syn::custom_keyword!(tracing);
+    syn::custom_keyword!(manifest);
+    syn::custom_keyword!(context);
 }
 
 enum Opt {
     Async(bool),
     Tracing(bool),
+    Manifest(bool),
+    Context(bool),
 }
 
 impl Parse for Opt {
@@ -29,6 +33,14 @@ impl Parse for Opt {
             input.parse::<kw::tracing>()?;
             input.parse::<Token![:]>()?;
             Ok(Opt::Tracing(input.parse::<syn::LitBool>()?.value))
+        } else if l.peek(kw::manifest) {
+            input.parse::<kw::manifest>()?;
+            input.parse::<Token![:]>()?;
+            Ok(Opt::Manifest(input.parse::<syn::LitBool>()?.value))
+        } else if l.peek(kw::context) {
+            input.parse::<kw::context>()?;
+            input.parse::<Token![:]>()?;
+            Ok(Opt::Context(input.parse::<syn::LitBool>()?.value))
         } else {
             Err(l.error())
         }
@@ -40,6 +52,8 @@ impl rust_macro_shared::Configure<Builder> for Opt {
         match self {
             Opt::Async(val) => builder.async_ = val,
             Opt::Tracing(val) => builder.tracing = val,
+            Opt::Manifest(val) => builder.manifest = val,
+            Opt::Context(val) => builder.context = val,
         }
     }
 }
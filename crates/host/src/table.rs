@@ -0,0 +1,410 @@
+use ipc_router_wip::RequestMetadata;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// A handle to a resource in a [`ResourceTable`].
+///
+/// The low 32 bits are the index of the resource's slot, the high 32 bits the slot's generation.
+/// The generation changes every time a slot is freed, so a stale handle never refers to the resource
+/// that reuses its slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ResourceId(u64);
+
+impl ResourceId {
+    #[must_use]
+    pub fn new(index: u32, generation: u32) -> Self {
+        Self(u64::from(generation) << 32 | u64::from(index))
+    }
+
+    #[must_use]
+    pub fn index(self) -> u32 {
+        self.0 as u32
+    }
+
+    #[must_use]
+    pub fn generation(self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+}
+
+impl fmt::Display for ResourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.index(), self.generation())
+    }
+}
+
+/// Why a resource couldn't be looked up, inserted or removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ResourceError {
+    #[error("resource {0} is not in the table")]
+    NotFound(ResourceId),
+    #[error("resource {0} is a different type")]
+    WrongType(ResourceId),
+    #[error("resource {0} is owned by another webview")]
+    NotOwner(ResourceId),
+    #[error("resource {0} is still in use")]
+    Shared(ResourceId),
+    #[error("the resource table is full")]
+    Full,
+}
+
+/// The resources handed out to guests.
+///
+/// Resources can be owned by the webview whose call created them, see [`ResourceTable::push_owned`].
+/// Owned resources can only be used by that webview and are released with [`ResourceTable::release`]
+/// when it is destroyed.
+///
+/// Freed slots are kept in a free list and reused by the next insert, with a new generation.
+///
+/// The methods panic if the table's lock is poisoned, because a thread panicked while it held the lock.
+#[derive(Default)]
+pub struct ResourceTable(RwLock<ResourceTableInner>);
+
+#[derive(Default)]
+struct ResourceTableInner {
+    slots: Vec<Slot>,
+    /// The indices of the empty slots that can be reused.
+    free: Vec<u32>,
+}
+
+#[derive(Default)]
+struct Slot {
+    generation: u32,
+    entry: Option<Entry>,
+}
+
+struct Entry {
+    /// The label of the webview that owns the resource, `None` if any webview may use it.
+    owner: Option<String>,
+    resource: Arc<dyn Any + Send + Sync>,
+}
+
+impl Entry {
+    fn check_owner(&self, cx: &RequestMetadata, key: ResourceId) -> Result<(), ResourceError> {
+        match &self.owner {
+            Some(owner) if cx.webview_label.as_ref() != Some(owner) => {
+                Err(ResourceError::NotOwner(key))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ResourceTableInner {
+    fn entry(&self, key: ResourceId) -> Result<&Entry, ResourceError> {
+        self.slots
+            .get(key.index() as usize)
+            .filter(|slot| slot.generation == key.generation())
+            .and_then(|slot| slot.entry.as_ref())
+            .ok_or(ResourceError::NotFound(key))
+    }
+
+    fn entry_mut(&mut self, key: ResourceId) -> Result<&mut Entry, ResourceError> {
+        self.slots
+            .get_mut(key.index() as usize)
+            .filter(|slot| slot.generation == key.generation())
+            .and_then(|slot| slot.entry.as_mut())
+            .ok_or(ResourceError::NotFound(key))
+    }
+
+    fn insert(&mut self, entry: Entry) -> Result<ResourceId, ResourceError> {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                let index = u32::try_from(self.slots.len()).map_err(|_| ResourceError::Full)?;
+                self.slots.push(Slot::default());
+                index
+            }
+        };
+
+        let slot = &mut self.slots[index as usize];
+        slot.entry = Some(entry);
+
+        Ok(ResourceId::new(index, slot.generation))
+    }
+
+    fn remove(&mut self, index: u32) -> Option<Entry> {
+        let slot = &mut self.slots[index as usize];
+        let entry = slot.entry.take()?;
+
+        // slots that ran out of generations are retired, so their handles can't come back to life
+        if let Some(generation) = slot.generation.checked_add(1) {
+            slot.generation = generation;
+            self.free.push(index);
+        }
+
+        Some(entry)
+    }
+}
+
+impl ResourceTable {
+    /// Create an empty table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a resource into a free slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table is full.
+    pub fn push<T: Any + Send + Sync>(&self, a: Arc<T>) -> Result<ResourceId, ResourceError> {
+        self.0.write().unwrap().insert(Entry {
+            owner: None,
+            resource: a,
+        })
+    }
+
+    /// Insert a resource owned by the webview that made the call, calls that didn't come
+    /// from a webview insert it without an owner.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table is full.
+    pub fn push_owned<T: Any + Send + Sync>(
+        &self,
+        cx: &RequestMetadata,
+        a: Arc<T>,
+    ) -> Result<ResourceId, ResourceError> {
+        self.0.write().unwrap().insert(Entry {
+            owner: cx.webview_label.clone(),
+            resource: a,
+        })
+    }
+
+    /// Check if the table has a resource with the given handle.
+    pub fn contains_key(&self, key: ResourceId) -> bool {
+        self.0.read().unwrap().entry(key).is_ok()
+    }
+
+    /// Check if the resource with a given handle can be downcast to a given type.
+    pub fn is<T: Any + Sized>(&self, key: ResourceId) -> bool {
+        self.0
+            .read()
+            .unwrap()
+            .entry(key)
+            .is_ok_and(|entry| entry.resource.is::<T>())
+    }
+
+    /// Get an Arc reference to a resource of a given type with a given handle. Multiple
+    /// immutable references can be borrowed at any given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resource is not in the table or not of the given type.
+    pub fn get<T: Any + Send + Sync + Sized>(
+        &self,
+        key: ResourceId,
+    ) -> Result<Arc<T>, ResourceError> {
+        let inner = self.0.read().unwrap();
+
+        downcast(inner.entry(key)?.resource.clone(), key)
+    }
+
+    /// Like [`ResourceTable::get`], but fails if the resource is owned by another webview than the one that made the call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resource is not in the table, not of the given type or owned by another webview.
+    pub fn get_owned<T: Any + Send + Sync + Sized>(
+        &self,
+        cx: &RequestMetadata,
+        key: ResourceId,
+    ) -> Result<Arc<T>, ResourceError> {
+        let inner = self.0.read().unwrap();
+        let entry = inner.entry(key)?;
+
+        entry.check_owner(cx, key)?;
+        downcast(entry.resource.clone(), key)
+    }
+
+    /// Get a mutable reference to a resource of a given type with a given handle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resource is not in the table, not of the given type or if other `Arc`s to it are alive.
+    pub fn get_mut<T: Any>(&mut self, key: ResourceId) -> Result<&mut T, ResourceError> {
+        let entry = self.0.get_mut().unwrap().entry_mut(key)?;
+
+        Arc::get_mut(&mut entry.resource)
+            .ok_or(ResourceError::Shared(key))?
+            .downcast_mut::<T>()
+            .ok_or(ResourceError::WrongType(key))
+    }
+
+    /// Remove the resource with a given handle from the table and return it.
+    ///
+    /// Resources of another type than `T` stay in the table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resource is not in the table or not of the given type.
+    pub fn take<T: Any + Send + Sync>(&self, key: ResourceId) -> Result<Arc<T>, ResourceError> {
+        let mut inner = self.0.write().unwrap();
+
+        if !inner.entry(key)?.resource.is::<T>() {
+            return Err(ResourceError::WrongType(key));
+        }
+
+        let entry = inner.remove(key.index()).unwrap();
+        downcast(entry.resource, key)
+    }
+
+    /// Like [`ResourceTable::take`], but fails if the resource is owned by another webview than the one that made the call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resource is not in the table, not of the given type or owned by another webview.
+    pub fn take_owned<T: Any + Send + Sync>(
+        &self,
+        cx: &RequestMetadata,
+        key: ResourceId,
+    ) -> Result<Arc<T>, ResourceError> {
+        let mut inner = self.0.write().unwrap();
+        let entry = inner.entry(key)?;
+
+        entry.check_owner(cx, key)?;
+        if !entry.resource.is::<T>() {
+            return Err(ResourceError::WrongType(key));
+        }
+
+        let entry = inner.remove(key.index()).unwrap();
+        downcast(entry.resource, key)
+    }
+
+    /// Like [`ResourceTable::take_owned`], but returns the resource itself instead of an `Arc`, e.g. when the
+    /// guest hands over its handle.
+    ///
+    /// The resource stays in the table if it is still used by another call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resource is not in the table, not of the given type, owned by another webview
+    /// or still in use.
+    pub fn take_value<T: Any + Send + Sync>(
+        &self,
+        cx: &RequestMetadata,
+        key: ResourceId,
+    ) -> Result<T, ResourceError> {
+        let mut inner = self.0.write().unwrap();
+        let entry = inner.entry(key)?;
+
+        entry.check_owner(cx, key)?;
+        if !entry.resource.is::<T>() {
+            return Err(ResourceError::WrongType(key));
+        }
+        // new references can only be handed out by the table, which is locked
+        if Arc::strong_count(&entry.resource) > 1 {
+            return Err(ResourceError::Shared(key));
+        }
+
+        let entry = inner.remove(key.index()).unwrap();
+        Arc::try_unwrap(downcast(entry.resource, key)?).map_err(|_| ResourceError::Shared(key))
+    }
+
+    /// Remove all resources owned by the webview `label`, e.g. when it was destroyed.
+    ///
+    /// Returns the number of removed resources.
+    pub fn release(&self, label: &str) -> usize {
+        let mut inner = self.0.write().unwrap();
+
+        let owned: Vec<u32> = (0..inner.slots.len() as u32)
+            .filter(|&index| {
+                inner.slots[index as usize]
+                    .entry
+                    .as_ref()
+                    .is_some_and(|entry| entry.owner.as_deref() == Some(label))
+            })
+            .collect();
+
+        for &index in &owned {
+            inner.remove(index);
+        }
+
+        owned.len()
+    }
+}
+
+fn downcast<T: Any + Send + Sync>(
+    resource: Arc<dyn Any + Send + Sync>,
+    key: ResourceId,
+) -> Result<Arc<T>, ResourceError> {
+    resource
+        .downcast::<T>()
+        .map_err(|_| ResourceError::WrongType(key))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reuses_slots_with_new_generation() {
+        let table = ResourceTable::new();
+
+        let a = table.push(Arc::new(1u32)).unwrap();
+        let b = table.push(Arc::new(2u32)).unwrap();
+        assert_eq!((a.index(), b.index()), (0, 1));
+
+        assert_eq!(*table.take::<u32>(a).unwrap(), 1);
+        assert_eq!(table.take::<u32>(a), Err(ResourceError::NotFound(a)));
+
+        let c = table.push(Arc::new(3u32)).unwrap();
+        assert_eq!(c.index(), a.index());
+        assert_ne!(c, a);
+        assert_eq!(table.get::<u32>(a), Err(ResourceError::NotFound(a)));
+        assert_eq!(*table.get::<u32>(c).unwrap(), 3);
+    }
+
+    #[test]
+    fn take_wrong_type() {
+        let table = ResourceTable::new();
+        let a = table.push(Arc::new(1u32)).unwrap();
+
+        assert_eq!(table.take::<String>(a), Err(ResourceError::WrongType(a)));
+        assert!(table.contains_key(a));
+    }
+
+    #[test]
+    fn take_value_in_use() {
+        let table = ResourceTable::new();
+        let cx = RequestMetadata::default();
+        let a = table.push(Arc::new(1u32)).unwrap();
+
+        let borrowed = table.get::<u32>(a).unwrap();
+        assert_eq!(
+            table.take_value::<u32>(&cx, a),
+            Err(ResourceError::Shared(a))
+        );
+
+        drop(borrowed);
+        assert_eq!(table.take_value::<u32>(&cx, a), Ok(1));
+        assert!(!table.contains_key(a));
+    }
+
+    #[test]
+    fn retires_exhausted_slots() {
+        let mut inner = ResourceTableInner::default();
+        inner.slots.push(Slot {
+            generation: u32::MAX,
+            entry: None,
+        });
+        inner.free.push(0);
+
+        let entry = || Entry {
+            owner: None,
+            resource: Arc::new(()),
+        };
+
+        let a = inner.insert(entry()).unwrap();
+        assert_eq!((a.index(), a.generation()), (0, u32::MAX));
+
+        inner.remove(a.index());
+        let b = inner.insert(entry()).unwrap();
+        assert_eq!(b.index(), 1);
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+use tauri_bindgen_host::ipc_router_wip::{RequestMetadata, Router};
+use tauri_bindgen_host::{ResourceId, ResourceTable};
+
+tauri_bindgen_host::generate!({
+    path: "../../wit/resources.wit"
+});
+
+struct A;
+
+impl resources::A for A {
+    fn f1(&self) {}
+    fn f2(&self, _a: u32) {}
+    fn f3(&self, _a: u32, _b: u32) {}
+}
+
+struct B;
+
+impl resources::B for B {
+    type A = A;
+
+    fn f1(&self) -> A {
+        A
+    }
+    fn f2(&self, _x: Arc<A>) -> Result<u32, ()> {
+        Err(())
+    }
+    fn f3(&self, _x: Option<Vec<Arc<A>>>) -> Result<A, ()> {
+        Err(())
+    }
+    fn f4(&self, _x: Arc<A>, _y: Vec<A>) {}
+}
+
+#[derive(Default)]
+struct Ctx {
+    table: ResourceTable,
+}
+
+impl resources::Resources for Ctx {
+    type A = A;
+    type B = B;
+
+    fn resource_table(&self) -> &ResourceTable {
+        &self.table
+    }
+    fn constructor_a(&self) -> A {
+        A
+    }
+    fn constructor_b(&self) -> B {
+        B
+    }
+    fn consume(&self, _x: A) {}
+    fn new_a(&self, _a: u32) -> A {
+        A
+    }
+    fn a_f0(&self, _a: u32) -> Option<A> {
+        None
+    }
+}
+
+fn webview(label: &str) -> RequestMetadata {
+    let mut metadata = RequestMetadata::default();
+    metadata.webview_label = Some(label.to_string());
+    metadata
+}
+
+fn call(router: &Router<Ctx>, label: &str, method: &str, id: ResourceId) -> bool {
+    call_in(router, label, "resources::resource::a", method, id)
+}
+
+fn call_in(router: &Router<Ctx>, label: &str, module: &str, method: &str, id: ResourceId) -> bool {
+    let params = tauri_bindgen_host::postcard::to_allocvec(&(id,)).unwrap();
+
+    tauri::async_runtime::block_on(router.call_with(webview(label), Some(module), method, &params))
+        .is_ok()
+}
+
+fn setup() -> (Router<Ctx>, ResourceId) {
+    let ctx = Ctx::default();
+    let id = ctx.table.push_owned(&webview("main"), Arc::new(A)).unwrap();
+
+    let mut router = Router::new(ctx);
+    resources::add_to_router(&mut router, |ctx| ctx).unwrap();
+
+    (router, id)
+}
+
+#[test]
+fn owner_only() {
+    let (router, id) = setup();
+
+    assert!(!call(&router, "other", "f1", id));
+    assert!(!call(&router, "other", "drop", id));
+    assert!(call(&router, "main", "f1", id));
+    assert!(call(&router, "main", "drop", id));
+    assert!(!call(&router, "main", "f1", id));
+}
+
+#[test]
+fn released_when_destroyed() {
+    let (router, id) = setup();
+
+    router.webview_destroyed("other");
+    assert!(call(&router, "main", "f1", id));
+
+    router.webview_destroyed("main");
+    assert!(!call(&router, "main", "f1", id));
+}
+
+#[test]
+fn own_takes_resource() {
+    let (router, id) = setup();
+
+    assert!(!call_in(&router, "other", "resources", "consume", id));
+    assert!(call_in(&router, "main", "resources", "consume", id));
+    assert!(!call(&router, "main", "f1", id));
+    assert!(!call(&router, "main", "drop", id));
+}
+
+#[test]
+fn returned_resources_are_inserted() {
+    let (router, _) = setup();
+
+    let ret = tauri::async_runtime::block_on(router.call_with(
+        webview("main"),
+        Some("resources"),
+        "constructor_a",
+        &[],
+    ))
+    .unwrap();
+    let id: ResourceId = tauri_bindgen_host::postcard::from_bytes(&ret).unwrap();
+
+    assert!(!call(&router, "other", "f1", id));
+    assert!(call(&router, "main", "f1", id));
+}
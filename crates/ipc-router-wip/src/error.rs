@@ -0,0 +1,135 @@
+use serde::{Serialize, Serializer};
+use std::{any::Any, fmt};
+use tauri::http::StatusCode;
+
+/// What went wrong while handling a call.
+///
+/// Encoded as a stable `u32` code, so guests can tell the kinds apart without parsing messages.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// No handler is defined for the requested module and method.
+    NotFound = 1,
+    /// The request path or the parameters couldn't be decoded.
+    InvalidRequest = 2,
+    /// The handler returned an error.
+    Handler = 3,
+    /// The handler panicked.
+    Panic = 4,
+    /// The calling webview isn't allowed to call the function.
+    Forbidden = 5,
+}
+
+impl ErrorKind {
+    /// The code this kind is encoded as.
+    #[must_use]
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+
+    /// The HTTP status the `ipc` protocol responds with.
+    #[must_use]
+    pub fn status(self) -> StatusCode {
+        match self {
+            ErrorKind::NotFound => StatusCode::NOT_FOUND,
+            ErrorKind::InvalidRequest => StatusCode::BAD_REQUEST,
+            ErrorKind::Forbidden => StatusCode::FORBIDDEN,
+            ErrorKind::Handler | ErrorKind::Panic => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl Serialize for ErrorKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.code())
+    }
+}
+
+/// A call that failed before it could produce a result.
+///
+/// The `ipc` protocol sends it to the guest as the body of a non-2xx response,
+/// postcard encoded as `(kind: u32, message: string, details: option<string>)`.
+///
+/// Handlers can return a `CallError` (wrapped in an [`anyhow::Error`]) to pick the kind themselves,
+/// all other errors are reported as [`ErrorKind::Handler`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CallError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub details: Option<String>,
+}
+
+impl CallError {
+    #[must_use]
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// The HTTP status the `ipc` protocol responds with.
+    #[must_use]
+    pub fn status(&self) -> StatusCode {
+        self.kind.status()
+    }
+
+    /// The postcard encoded envelope sent to the guest.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        postcard::to_allocvec(self).unwrap()
+    }
+
+    pub(crate) fn invalid_params(err: postcard::Error) -> Self {
+        Self::new(ErrorKind::InvalidRequest, "failed to decode parameters")
+            .with_details(err.to_string())
+    }
+
+    pub(crate) fn handler(err: anyhow::Error) -> Self {
+        match err.downcast::<CallError>() {
+            Ok(err) => err,
+            Err(err) => {
+                let causes: Vec<_> = err.chain().skip(1).map(ToString::to_string).collect();
+
+                Self {
+                    kind: ErrorKind::Handler,
+                    message: err.to_string(),
+                    details: (!causes.is_empty()).then(|| causes.join(": ")),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn panic(module: Option<&str>, name: &str, payload: &(dyn Any + Send)) -> Self {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("Box<dyn Any>");
+
+        log::error!("handler for {:?}::{} panicked: {}", module, name, msg);
+
+        Self::new(ErrorKind::Panic, "handler panicked").with_details(msg)
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+
+        if let Some(details) = &self.details {
+            write!(f, ": {details}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for CallError {}
@@ -0,0 +1,43 @@
+use crate::{CallError, CallResult, Caller, Definition};
+use std::{future::Future, pin::Pin};
+
+/// The future returned by a layer, resolving to the postcard encoded result of the call.
+pub type LayerFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<u8>, CallError>> + Send + 'a>>;
+
+pub(crate) type Layer<T> =
+    Box<dyn for<'a> Fn(Call<'a, T>, Next<'a, T>) -> LayerFuture<'a> + Send + Sync + 'static>;
+
+/// A call on its way through the layers of a [`Router`](crate::Router).
+pub struct Call<'a, T> {
+    pub module: Option<&'a str>,
+    pub method: &'a str,
+    /// The postcard encoded parameters.
+    pub params: &'a [u8],
+    pub caller: Caller<T>,
+}
+
+/// The rest of the layers and the handler a layer passes the call on to.
+pub struct Next<'a, T> {
+    pub(crate) layers: &'a [Layer<T>],
+    pub(crate) handler: &'a Definition<T>,
+}
+
+impl<'a, T> Next<'a, T> {
+    /// Runs the remaining layers and the handler.
+    pub async fn run(self, call: Call<'a, T>) -> Result<Vec<u8>, CallError> {
+        match self.layers.split_first() {
+            Some((layer, layers)) => {
+                let next = Next {
+                    layers,
+                    handler: self.handler,
+                };
+
+                layer(call, next).await
+            }
+            None => match (self.handler)(call.caller, call.params)? {
+                CallResult::Value(val) => Ok(val),
+                CallResult::Future(fut) => fut.await,
+            },
+        }
+    }
+}
@@ -1,28 +1,56 @@
 #![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
 
+mod error;
+mod layer;
+mod permissions;
+
 pub use anyhow::Error;
+pub use error::{CallError, ErrorKind};
+pub use layer::{Call, LayerFuture, Next};
+pub use permissions::{Permissions, Scope};
 
 use futures_util::FutureExt;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    any::Any,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    fmt,
     future::Future,
     marker::PhantomData,
+    panic::AssertUnwindSafe,
     pin::Pin,
-    sync::Arc,
+    sync::{Arc, Mutex},
+};
+use tauri::{
+    http::{
+        header::{CONTENT_TYPE, ORIGIN},
+        HeaderMap, Request, Response, StatusCode,
+    },
+    AppHandle, Runtime, WindowEvent,
 };
-use tauri::http::{header::CONTENT_TYPE, Request, Response, StatusCode};
 
 type Definition<T> =
-    Box<dyn Fn(Caller<T>, &[u8]) -> anyhow::Result<CallResult> + Send + Sync + 'static>;
+    Box<dyn Fn(Caller<T>, &[u8]) -> Result<CallResult, CallError> + Send + Sync + 'static>;
+
+type WebviewHook<T> = Box<dyn Fn(&T, &str) + Send + Sync + 'static>;
 
 enum CallResult {
     Value(Vec<u8>),
-    Future(Pin<Box<dyn Future<Output = anyhow::Result<Vec<u8>>> + Send + 'static>>),
+    Future(Pin<Box<dyn Future<Output = Result<Vec<u8>, CallError>> + Send + 'static>>),
 }
 
 pub struct Caller<T> {
     data: Arc<T>,
+    metadata: Arc<RequestMetadata>,
+}
+
+impl<T> Clone for Caller<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            metadata: self.metadata.clone(),
+        }
+    }
 }
 
 impl<T> Caller<T> {
@@ -30,6 +58,57 @@ impl<T> Caller<T> {
     pub fn data(&self) -> &T {
         &self.data
     }
+
+    /// Where the call came from.
+    #[must_use]
+    pub fn metadata(&self) -> &RequestMetadata {
+        &self.metadata
+    }
+
+    /// The handle of the app that received the call, see [`RequestMetadata::app_handle`].
+    #[must_use]
+    pub fn app_handle<R: Runtime>(&self) -> Option<&AppHandle<R>> {
+        self.metadata.app_handle()
+    }
+}
+
+/// Where a call came from.
+#[derive(Clone, Default)]
+pub struct RequestMetadata {
+    /// The label of the webview that sent the request.
+    pub webview_label: Option<String>,
+    /// The `Origin` header of the request.
+    pub origin: Option<String>,
+    pub headers: HeaderMap,
+    app_handle: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+// headers can carry cookies and tokens, so only their names are printed
+impl fmt::Debug for RequestMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestMetadata")
+            .field("webview_label", &self.webview_label)
+            .field("origin", &self.origin)
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field("app_handle", &self.app_handle.is_some())
+            .finish()
+    }
+}
+
+impl RequestMetadata {
+    #[must_use]
+    pub fn with_app_handle<R: Runtime>(mut self, app_handle: AppHandle<R>) -> Self {
+        self.app_handle = Some(Arc::new(app_handle));
+        self
+    }
+
+    /// The handle of the app that received the call, e.g. to emit events or look up windows.
+    ///
+    /// Returns `None` for calls that didn't come through the `ipc` protocol, or when `R` is not the app's runtime.
+    #[must_use]
+    pub fn app_handle<R: Runtime>(&self) -> Option<&AppHandle<R>> {
+        self.app_handle.as_ref()?.downcast_ref()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -44,6 +123,9 @@ pub struct Router<T> {
     string2idx: HashMap<Arc<str>, usize>,
     strings: Vec<Arc<str>>,
     map: HashMap<ImportKey, Definition<T>>,
+    layers: Vec<layer::Layer<T>>,
+    permissions: Option<Permissions>,
+    on_webview_destroyed: Vec<WebviewHook<T>>,
 }
 
 impl<T> Router<T> {
@@ -54,6 +136,60 @@ impl<T> Router<T> {
             string2idx: HashMap::new(),
             strings: Vec::new(),
             map: HashMap::new(),
+            layers: Vec::new(),
+            permissions: None,
+            on_webview_destroyed: Vec::new(),
+        }
+    }
+
+    /// Only lets calls through that `permissions` allow, before they reach any layer or handler.
+    ///
+    /// Without permissions every webview may call every function.
+    pub fn permissions(&mut self, permissions: Permissions) {
+        self.permissions = Some(permissions);
+    }
+
+    /// Wraps every call in `layer`, e.g. to check permissions, log or measure calls.
+    ///
+    /// A layer receives the [`Call`] and decides whether to pass it on by running `next`,
+    /// which resolves to the result of the remaining layers and the handler. Both sync and async handlers
+    /// go through layers the same way. Layers run in the order they were added, so the first one added sees
+    /// the call first and the result last.
+    ///
+    /// ```ignore
+    /// router.layer(|call, next| {
+    ///     Box::pin(async move {
+    ///         let (module, method) = (call.module, call.method);
+    ///         let res = next.run(call).await;
+    ///         log::info!("{module:?}::{method} succeeded: {}", res.is_ok());
+    ///         res
+    ///     })
+    /// });
+    /// ```
+    pub fn layer<F>(&mut self, layer: F)
+    where
+        F: for<'a> Fn(Call<'a, T>, Next<'a, T>) -> LayerFuture<'a> + Send + Sync + 'static,
+    {
+        self.layers.push(Box::new(layer));
+    }
+
+    /// Calls `hook` with the router's data and the label of every destroyed webview, e.g. to release the resources it owns.
+    pub fn on_webview_destroyed<F>(&mut self, hook: F)
+    where
+        F: Fn(&T, &str) + Send + Sync + 'static,
+    {
+        self.on_webview_destroyed.push(Box::new(hook));
+    }
+
+    /// Runs the hooks added with [`Router::on_webview_destroyed`].
+    ///
+    /// [`BuilderExt::ipc_router`] calls this for the webviews of a window when tauri reports the window destroyed,
+    /// tests can call it to simulate closing a window.
+    pub fn webview_destroyed(&self, label: &str) {
+        log::debug!("webview {} destroyed", label);
+
+        for hook in &self.on_webview_destroyed {
+            hook(&self.data, label);
         }
     }
 
@@ -68,9 +204,9 @@ impl<T> Router<T> {
         self.insert(
             key,
             Box::new(move |caller, params| {
-                let params = postcard::from_bytes(params)?;
+                let params = postcard::from_bytes(params).map_err(CallError::invalid_params)?;
 
-                let res = func(caller, params)?;
+                let res = func(caller, params).map_err(CallError::handler)?;
 
                 Ok(CallResult::Value(postcard::to_allocvec(&res).unwrap()))
             }),
@@ -96,10 +232,12 @@ impl<T> Router<T> {
         self.insert(
             key,
             Box::new(move |caller, params| {
-                let params = postcard::from_bytes(params)?;
+                let params = postcard::from_bytes(params).map_err(CallError::invalid_params)?;
 
                 let fut = func(caller, params)
-                    .map(|res| postcard::to_allocvec(&res?).map_err(Into::into))
+                    .map(
+                        |res| Ok(postcard::to_allocvec(&res.map_err(CallError::handler)?).unwrap()),
+                    )
                     .boxed();
 
                 Ok(CallResult::Future(fut))
@@ -109,26 +247,82 @@ impl<T> Router<T> {
         Ok(())
     }
 
-    async fn call(
+    /// Calls the handler defined for `module::name` with the postcard encoded `params`.
+    ///
+    /// When [`Router::permissions`] were set, calls made this way are denied, as they don't come from any webview.
+    ///
+    /// The call passes through all layers added with [`Router::layer`] first. Panics in the layers, the handler or its future
+    /// are caught and returned as an [`ErrorKind::Panic`] error.
+    ///
+    /// This is what the `ipc` protocol does for every request, but can also be used to call handlers in-process,
+    /// e.g. from native tests.
+    pub async fn call(
         &self,
         module: Option<&str>,
         name: &str,
         params: &[u8],
-    ) -> anyhow::Result<Vec<u8>> {
+    ) -> Result<Vec<u8>, CallError> {
+        self.call_with(RequestMetadata::default(), module, name, params)
+            .await
+    }
+
+    /// Like [`Router::call`], but checks the [`Permissions`] against where the call came from.
+    pub async fn call_with(
+        &self,
+        metadata: RequestMetadata,
+        module: Option<&str>,
+        name: &str,
+        params: &[u8],
+    ) -> Result<Vec<u8>, CallError> {
+        if let Some(permissions) = &self.permissions {
+            let command =
+                module.map_or_else(|| name.to_string(), |module| format!("{module}::{name}"));
+
+            if !permissions.allows(
+                metadata.webview_label.as_deref(),
+                metadata.origin.as_deref(),
+                &command,
+            ) {
+                log::warn!(
+                    "denied call to {} from webview {:?} with origin {:?}",
+                    command,
+                    metadata.webview_label,
+                    metadata.origin
+                );
+
+                return Err(CallError::new(
+                    ErrorKind::Forbidden,
+                    format!("calling `{command}` is not allowed"),
+                ));
+            }
+        }
+
         let key = self.import_key_read_only(module, name)?;
 
         let handler = self
             .map
             .get(&key)
-            .ok_or(anyhow::anyhow!("method not found"))?;
+            .ok_or_else(|| CallError::new(ErrorKind::NotFound, "method not found"))?;
 
         let caller = Caller {
             data: self.data.clone(),
+            metadata: Arc::new(metadata),
+        };
+
+        let call = Call {
+            module,
+            method: name,
+            params,
+            caller,
+        };
+        let next = Next {
+            layers: &self.layers,
+            handler,
         };
 
-        match handler(caller, params)? {
-            CallResult::Value(val) => Ok(val),
-            CallResult::Future(fut) => Ok(fut.await?),
+        match AssertUnwindSafe(next.run(call)).catch_unwind().await {
+            Ok(res) => res,
+            Err(payload) => Err(CallError::panic(module, name, &*payload)),
         }
     }
 
@@ -151,17 +345,21 @@ impl<T> Router<T> {
 
     fn import_key(&mut self, module: Option<impl AsRef<str>>, name: impl AsRef<str>) -> ImportKey {
         ImportKey {
-            module: module.map_or(usize::max_value(), |name| self.intern_str(name.as_ref())),
+            module: module.map_or(usize::MAX, |name| self.intern_str(name.as_ref())),
             name: self.intern_str(name.as_ref()),
         }
     }
 
-    fn import_key_read_only(&self, module: Option<&str>, name: &str) -> anyhow::Result<ImportKey> {
+    fn import_key_read_only(
+        &self,
+        module: Option<&str>,
+        name: &str,
+    ) -> Result<ImportKey, CallError> {
         let module = if let Some(module) = module {
             *self
                 .string2idx
                 .get(module)
-                .ok_or(anyhow::anyhow!("unknown module"))?
+                .ok_or_else(|| CallError::new(ErrorKind::NotFound, "unknown module"))?
         } else {
             usize::MAX
         };
@@ -169,7 +367,7 @@ impl<T> Router<T> {
         let name = *self
             .string2idx
             .get(name)
-            .ok_or(anyhow::anyhow!("unknown function"))?;
+            .ok_or_else(|| CallError::new(ErrorKind::NotFound, "unknown function"))?;
 
         Ok(ImportKey { module, name })
     }
@@ -187,6 +385,8 @@ impl<T> Router<T> {
 }
 
 pub trait BuilderExt {
+    /// Serves `router` on the `ipc` protocol and runs its [`Router::on_webview_destroyed`] hooks for every webview
+    /// of a window when the window is destroyed.
     #[must_use]
     fn ipc_router<U: Send + Sync + 'static>(self, router: Router<U>) -> Self;
 }
@@ -194,18 +394,49 @@ pub trait BuilderExt {
 impl<R: tauri::Runtime> BuilderExt for tauri::Builder<R> {
     fn ipc_router<U: Send + Sync + 'static>(self, router: Router<U>) -> Self {
         let router = Arc::new(router);
-
-        self.register_asynchronous_uri_scheme_protocol("ipc", move |_app, req, responder| {
+        let lifecycle = router.clone();
+        // the labels of the webviews by the label of their window,
+        // tauri has already forgotten a window's webviews when it reports the window destroyed
+        let webviews: Arc<Mutex<HashMap<String, HashSet<String>>>> = Arc::default();
+        let ready = webviews.clone();
+
+        self.plugin(
+            tauri::plugin::Builder::<R>::new("ipc-router")
+                .on_webview_ready(move |webview| {
+                    ready
+                        .lock()
+                        .unwrap()
+                        .entry(webview.window().label().to_string())
+                        .or_default()
+                        .insert(webview.label().to_string());
+                })
+                .build(),
+        )
+        .on_window_event(move |window, event| {
+            if let WindowEvent::Destroyed = event {
+                let labels = webviews.lock().unwrap().remove(window.label());
+
+                for label in labels.unwrap_or_default() {
+                    lifecycle.webview_destroyed(&label);
+                }
+            }
+        })
+        .register_asynchronous_uri_scheme_protocol("ipc", move |ctx, req, responder| {
             let router = router.clone();
+            let metadata = RequestMetadata {
+                webview_label: Some(ctx.webview_label().to_string()),
+                origin: req
+                    .headers()
+                    .get(ORIGIN)
+                    .and_then(|origin| origin.to_str().ok())
+                    .map(ToString::to_string),
+                headers: req.headers().clone(),
+                app_handle: None,
+            }
+            .with_app_handle(ctx.app_handle().clone());
 
             tauri::async_runtime::spawn(async move {
-                let mut response = match uri_scheme_inner(&router, req).await {
-                    Ok(res) => res,
-                    Err(err) => Response::builder()
-                        .status(StatusCode::BAD_REQUEST)
-                        .body(err.to_string().into_bytes())
-                        .unwrap(),
-                };
+                let mut response = uri_scheme_inner(&router, metadata, req).await;
 
                 response.headers_mut().insert(
                     tauri::http::header::ACCESS_CONTROL_ALLOW_ORIGIN,
@@ -221,9 +452,10 @@ impl<R: tauri::Runtime> BuilderExt for tauri::Builder<R> {
 #[inline]
 async fn uri_scheme_inner<T>(
     router: &Router<T>,
+    metadata: RequestMetadata,
     request: Request<Vec<u8>>,
-) -> anyhow::Result<Response<Vec<u8>>> {
-    let path = request.uri().path().strip_prefix('/').unwrap();
+) -> Response<Vec<u8>> {
+    let path = request.uri().path().strip_prefix('/').unwrap_or_default();
 
     let (module, method) = path
         .split_once('/')
@@ -231,15 +463,27 @@ async fn uri_scheme_inner<T>(
 
     log::debug!("ipc request for {:?}::{}", module, method);
 
-    let response = router.call(module, method, request.body()).await?;
+    let (status, body) = match router
+        .call_with(metadata, module, method, request.body())
+        .await
+    {
+        Ok(response) => {
+            log::debug!("call result {:?}", response);
+
+            (StatusCode::OK, response)
+        }
+        Err(err) => {
+            log::debug!("call to {:?}::{} failed: {}", module, method, err);
 
-    log::debug!("call result {:?}", response);
+            (err.status(), err.to_bytes())
+        }
+    };
 
-    let mut resp = Response::builder().status(StatusCode::OK);
+    let mut resp = Response::builder().status(status);
     resp.headers_mut().unwrap().insert(
         CONTENT_TYPE,
         tauri::http::header::HeaderValue::from_static("application/octet-stream"),
     );
 
-    Ok(resp.body(response)?)
+    resp.body(body).unwrap()
 }
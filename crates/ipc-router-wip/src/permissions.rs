@@ -0,0 +1,216 @@
+use serde::Deserialize;
+
+/// Which webviews may call which functions.
+///
+/// Commands are identified by their module and method, e.g. `greet::greet` for the function `greet` of the interface
+/// `greet` or `files::resource::file::read` for the method `read` of the resource `file`. A rule also matches all
+/// commands it is a prefix of, so `greet` covers the whole interface.
+///
+/// A call is allowed when at least one scope that applies to the calling webview allows it and none denies it.
+/// Calls from webviews no scope applies to are denied.
+///
+/// Permissions can be built in code or deserialized, e.g. from JSON:
+///
+/// ```json
+/// {
+///     "scopes": [
+///         { "windows": ["main"], "allow": ["greet"], "deny": ["greet::reset"] },
+///         { "origins": ["https://example.com"], "allow": ["greet::greet"] }
+///     ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Permissions {
+    #[serde(default)]
+    pub scopes: Vec<Scope>,
+}
+
+/// The commands the webviews with one of the given labels or origins may or may not call.
+///
+/// `*` matches every label or origin.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Scope {
+    #[serde(default)]
+    pub windows: Vec<String>,
+    #[serde(default)]
+    pub origins: Vec<String>,
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl Permissions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.scopes.push(scope);
+        self
+    }
+
+    /// Whether the webview with the given label or origin may call `command`.
+    #[must_use]
+    pub fn allows(&self, window: Option<&str>, origin: Option<&str>, command: &str) -> bool {
+        let mut allowed = false;
+
+        for scope in self.scopes.iter().filter(|s| s.applies_to(window, origin)) {
+            if scope.deny.iter().any(|rule| matches(rule, command)) {
+                return false;
+            }
+
+            allowed |= scope.allow.iter().any(|rule| matches(rule, command));
+        }
+
+        allowed
+    }
+
+    /// Checks that every rule matches at least one of `commands`, e.g. the `COMMANDS` manifest emitted by the host generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the rules that don't match any command.
+    pub fn validate(&self, commands: &[&str]) -> anyhow::Result<()> {
+        let unknown: Vec<_> = self
+            .scopes
+            .iter()
+            .flat_map(|scope| scope.allow.iter().chain(&scope.deny))
+            .filter(|rule| !commands.iter().any(|command| matches(rule, command)))
+            .map(String::as_str)
+            .collect();
+
+        if !unknown.is_empty() {
+            anyhow::bail!("unknown commands in permissions: {}", unknown.join(", "));
+        }
+
+        Ok(())
+    }
+}
+
+impl Scope {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn window(mut self, label: impl Into<String>) -> Self {
+        self.windows.push(label.into());
+        self
+    }
+
+    #[must_use]
+    pub fn origin(mut self, origin: impl Into<String>) -> Self {
+        self.origins.push(origin.into());
+        self
+    }
+
+    #[must_use]
+    pub fn allow(mut self, command: impl Into<String>) -> Self {
+        self.allow.push(command.into());
+        self
+    }
+
+    #[must_use]
+    pub fn deny(mut self, command: impl Into<String>) -> Self {
+        self.deny.push(command.into());
+        self
+    }
+
+    fn applies_to(&self, window: Option<&str>, origin: Option<&str>) -> bool {
+        fn contains(list: &[String], val: Option<&str>) -> bool {
+            val.is_some_and(|val| list.iter().any(|item| item == "*" || item == val))
+        }
+
+        contains(&self.windows, window) || contains(&self.origins, origin)
+    }
+}
+
+fn matches(rule: &str, command: &str) -> bool {
+    command
+        .strip_prefix(rule)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let permissions = Permissions::new()
+            .scope(Scope::new().window("main").allow("greet"))
+            .scope(Scope::new().window("main").deny("greet::reset"));
+
+        assert!(permissions.allows(Some("main"), None, "greet::greet"));
+        assert!(!permissions.allows(Some("main"), None, "greet::reset"));
+    }
+
+    #[test]
+    fn rules_match_on_path_boundaries() {
+        assert!(matches("foo", "foo"));
+        assert!(matches("foo", "foo::bar"));
+        assert!(matches("foo::resource", "foo::resource::file::read"));
+        assert!(!matches("foo", "foobar"));
+        assert!(!matches("foo", "foobar::baz"));
+        assert!(!matches("foo::bar", "foo"));
+
+        let permissions = Permissions::new().scope(Scope::new().window("main").allow("foo"));
+        assert!(!permissions.allows(Some("main"), None, "foobar::baz"));
+    }
+
+    #[test]
+    fn wildcard_matches_every_webview() {
+        let permissions = Permissions::new()
+            .scope(Scope::new().window("*").allow("greet"))
+            .scope(Scope::new().origin("*").deny("greet::reset"));
+
+        assert!(permissions.allows(Some("settings"), None, "greet::greet"));
+        assert!(!permissions.allows(Some("settings"), Some("tauri://localhost"), "greet::reset"));
+        // the wildcard doesn't apply to calls that aren't from a webview
+        assert!(!permissions.allows(None, None, "greet::greet"));
+    }
+
+    #[test]
+    fn denies_without_matching_scope() {
+        let permissions = Permissions::new()
+            .scope(Scope::new().window("main").allow("greet"))
+            .scope(Scope::new().origin("https://example.com").allow("greet"));
+
+        assert!(!permissions.allows(Some("settings"), Some("https://evil.com"), "greet::greet"));
+        assert!(!permissions.allows(Some("main"), None, "files::read"));
+        assert!(!Permissions::new().allows(Some("main"), None, "greet::greet"));
+    }
+
+    #[test]
+    fn validate() {
+        let commands = [
+            "greet::greet",
+            "greet::reset",
+            "files::resource::file::read",
+        ];
+
+        let permissions = Permissions::new().scope(
+            Scope::new()
+                .window("main")
+                .allow("greet")
+                .deny("files::resource::file"),
+        );
+        assert!(permissions.validate(&commands).is_ok());
+
+        let permissions = Permissions::new().scope(
+            Scope::new()
+                .window("main")
+                .allow("gree")
+                .deny("files::write"),
+        );
+        let err = permissions.validate(&commands).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown commands in permissions: gree, files::write"
+        );
+    }
+}
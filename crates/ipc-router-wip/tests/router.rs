@@ -0,0 +1,173 @@
+use ipc_router_wip::{CallError, ErrorKind, Permissions, RequestMetadata, Router, Scope};
+use std::sync::{Arc, Mutex};
+use tauri::{async_runtime::block_on, http::StatusCode};
+
+fn router() -> Router<()> {
+    let mut router = Router::new(());
+
+    router
+        .define("test", "add_one", |_, x: u32| Ok(x + 1))
+        .unwrap();
+    router
+        .define("test", "panic", |_, _: u32| -> anyhow::Result<u32> {
+            panic!("sync handler failed")
+        })
+        .unwrap();
+    router
+        .define_async("test", "panic_async", |_, _: u32| {
+            Box::pin(async move {
+                if true {
+                    panic!("async handler failed");
+                }
+
+                Ok(0u32)
+            })
+        })
+        .unwrap();
+
+    router
+}
+
+fn call(router: &Router<()>, method: &str, x: u32) -> Result<u32, CallError> {
+    let params = postcard::to_allocvec(&x).unwrap();
+    let res = block_on(router.call(Some("test"), method, &params))?;
+
+    Ok(postcard::from_bytes(&res).unwrap())
+}
+
+fn assert_panicked(err: &CallError, details: &str) {
+    assert_eq!(err.kind, ErrorKind::Panic);
+    assert_eq!(err.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(err.details.as_deref(), Some(details));
+}
+
+#[test]
+fn sync_panic_becomes_error() {
+    let router = router();
+
+    let err = call(&router, "panic", 1).unwrap_err();
+    assert_panicked(&err, "sync handler failed");
+
+    // the router keeps serving
+    assert_eq!(call(&router, "add_one", 1).unwrap(), 2);
+}
+
+#[test]
+fn async_panic_becomes_error() {
+    let router = router();
+
+    let err = call(&router, "panic_async", 1).unwrap_err();
+    assert_panicked(&err, "async handler failed");
+
+    assert_eq!(call(&router, "add_one", 1).unwrap(), 2);
+}
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+/// Adds a layer that records when it sees the call and the result, and optionally returns early.
+fn record_layer(router: &mut Router<()>, log: &Log, name: &'static str, short_circuit: bool) {
+    let log = log.clone();
+
+    router.layer(move |call, next| {
+        let log = log.clone();
+
+        Box::pin(async move {
+            log.lock().unwrap().push(format!("{name} call"));
+
+            if short_circuit {
+                return Err(CallError::new(ErrorKind::Forbidden, "stopped"));
+            }
+
+            let res = next.run(call).await;
+            log.lock().unwrap().push(format!("{name} result"));
+            res
+        })
+    });
+}
+
+fn recording_router(log: &Log) -> Router<()> {
+    let mut router = Router::new(());
+
+    let handler_log = log.clone();
+    router
+        .define("test", "add_one", move |_, x: u32| {
+            handler_log.lock().unwrap().push("handler".to_string());
+            Ok(x + 1)
+        })
+        .unwrap();
+
+    router
+}
+
+#[test]
+fn layers_run_in_order() {
+    let log = Log::default();
+    let mut router = recording_router(&log);
+    record_layer(&mut router, &log, "first", false);
+    record_layer(&mut router, &log, "second", false);
+
+    assert_eq!(call(&router, "add_one", 1).unwrap(), 2);
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            "first call",
+            "second call",
+            "handler",
+            "second result",
+            "first result"
+        ]
+    );
+}
+
+#[test]
+fn layer_can_return_early() {
+    let log = Log::default();
+    let mut router = recording_router(&log);
+    record_layer(&mut router, &log, "first", false);
+    record_layer(&mut router, &log, "guard", true);
+    record_layer(&mut router, &log, "last", false);
+
+    let err = call(&router, "add_one", 1).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Forbidden);
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["first call", "guard call", "first result"]
+    );
+}
+
+#[test]
+fn forbidden_calls_skip_the_handler() {
+    let log = Log::default();
+    let mut router = recording_router(&log);
+    router.permissions(Permissions::new().scope(Scope::new().window("main").allow("test")));
+
+    let metadata = |label: &str| {
+        let mut metadata = RequestMetadata::default();
+        metadata.webview_label = Some(label.to_string());
+        metadata
+    };
+    let params = postcard::to_allocvec(&1u32).unwrap();
+
+    let err = block_on(router.call_with(metadata("other"), Some("test"), "add_one", &params))
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Forbidden);
+    assert_eq!(err.status(), StatusCode::FORBIDDEN);
+    assert!(log.lock().unwrap().is_empty());
+
+    block_on(router.call_with(metadata("main"), Some("test"), "add_one", &params)).unwrap();
+    assert_eq!(*log.lock().unwrap(), ["handler"]);
+}
+
+#[test]
+fn metadata_debug_hides_header_values() {
+    let mut metadata = RequestMetadata::default();
+    metadata.webview_label = Some("main".to_string());
+    metadata
+        .headers
+        .insert("authorization", "Bearer secret".parse().unwrap());
+
+    let debug = format!("{metadata:?}");
+    assert!(debug.contains("main"));
+    assert!(debug.contains("authorization"));
+    assert!(!debug.contains("secret"));
+}
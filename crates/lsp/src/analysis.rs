@@ -0,0 +1,421 @@
+use crate::line_index::LineIndex;
+use lsp_types::{
+    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, Hover, HoverContents,
+    Location, MarkupContent, MarkupKind, NumberOrString, Position, Range, Url,
+};
+use miette::SourceCode;
+use std::path::PathBuf;
+use wit_parser::{
+    lex::Token,
+    parse::{self, FuncResult, InterfaceItem, InterfaceItemInner, UseName},
+    SourceMap, TypeDef, TypeDefKind,
+};
+
+type Span = std::ops::Range<usize>;
+
+/// A `*.wit` document that is open in the editor.
+pub struct Document {
+    uri: Url,
+    text: String,
+    index: LineIndex,
+    parsed: parse::Document,
+}
+
+/// What an identifier refers to.
+enum Target<'a> {
+    /// An item of the interface the identifier is used in.
+    Item(&'a InterfaceItem),
+    /// A type imported through a `use` item.
+    Imported(TypeDef, SourceMap),
+    /// A type imported through a `use` item that could not be resolved.
+    Use(&'a UseName),
+}
+
+impl Document {
+    pub fn new(uri: Url, text: String) -> Self {
+        let index = LineIndex::new(&text);
+        let (parsed, _) = wit_parser::parse_partial(&text);
+
+        Self {
+            uri,
+            text,
+            index,
+            parsed,
+        }
+    }
+
+    /// The path of the document on disk, documents that aren't saved yet don't have one.
+    fn path(&self) -> Option<PathBuf> {
+        self.uri.to_file_path().ok()
+    }
+
+    /// The name the parser uses for this document in error reports.
+    fn source_name(&self) -> String {
+        self.path().map_or_else(
+            || "virtual file".to_string(),
+            |path| path.to_string_lossy().to_string(),
+        )
+    }
+
+    fn resolve(&self) -> miette::Result<wit_parser::Document> {
+        match self.path() {
+            Some(path) => {
+                wit_parser::parse_and_resolve_document_source(&self.text, path, |_| false)
+            }
+            None => wit_parser::parse_and_resolve_document_str(&self.text, |_| false),
+        }
+    }
+
+    fn ident(&self, span: &Span) -> &str {
+        self.text[span.clone()].trim_start_matches('%')
+    }
+
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let Err(report) = self.resolve() else {
+            return Vec::new();
+        };
+
+        let mut diagnostics = Vec::new();
+        self.collect_diagnostics(report.as_ref(), None, &mut diagnostics);
+        diagnostics
+    }
+
+    fn collect_diagnostics(
+        &self,
+        diagnostic: &dyn miette::Diagnostic,
+        source: Option<&dyn SourceCode>,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        let source = diagnostic.source_code().or(source);
+
+        if let Some(related) = diagnostic.related() {
+            for diagnostic in related {
+                self.collect_diagnostics(diagnostic, source, out);
+            }
+            return;
+        }
+
+        let label = diagnostic.labels().and_then(|mut labels| labels.next());
+
+        let mut message = diagnostic.to_string();
+        let mut range = Range::default();
+
+        if let Some(label) = &label {
+            let name = source
+                .and_then(|source| source.read_span(label.inner(), 0, 0).ok())
+                .and_then(|contents| contents.name().map(ToString::to_string));
+
+            match name {
+                // errors in other files of the package are reported at the start of the document
+                Some(name) if name != self.source_name() => {
+                    message = format!("{name}: {message}");
+                }
+                _ => {
+                    let start = label.offset();
+                    range = self.index.range(&self.text, start..start + label.len());
+                }
+            }
+
+            if let Some(label) = label.label() {
+                message.push_str(&format!("\n{label}"));
+            }
+        }
+
+        if let Some(help) = diagnostic.help() {
+            message.push_str(&format!("\nhelp: {help}"));
+        }
+
+        let severity = match diagnostic.severity() {
+            Some(miette::Severity::Advice) => DiagnosticSeverity::HINT,
+            Some(miette::Severity::Warning) => DiagnosticSeverity::WARNING,
+            Some(miette::Severity::Error) | None => DiagnosticSeverity::ERROR,
+        };
+
+        out.push(Diagnostic {
+            range,
+            severity: Some(severity),
+            code: diagnostic
+                .code()
+                .map(|code| NumberOrString::String(code.to_string())),
+            source: Some("wit".to_string()),
+            message,
+            ..Diagnostic::default()
+        });
+    }
+
+    /// The identifier at `position` along with the interface it's used in.
+    fn symbol_at(&self, position: Position) -> Option<(&parse::Interface, Span)> {
+        let offset = self.index.offset(&self.text, position);
+        let contains = |span: &Span| span.start <= offset && offset <= span.end;
+
+        self.parsed.interfaces.iter().find_map(|iface| {
+            let mut spans = Vec::new();
+
+            for item in &iface.items {
+                match &item.inner {
+                    InterfaceItemInner::Use(names) => {
+                        spans.extend(names.iter().map(|name| name.local().clone()));
+                    }
+                    _ => spans.push(item.ident.clone()),
+                }
+                item_references(item, &mut spans);
+            }
+
+            spans.into_iter().find(contains).map(|span| (iface, span))
+        })
+    }
+
+    fn lookup<'a>(&'a self, iface: &'a parse::Interface, ident: &str) -> Option<Target<'a>> {
+        let mut uses = Vec::new();
+
+        for item in &iface.items {
+            match &item.inner {
+                InterfaceItemInner::Use(names) => uses.extend(names),
+                _ if self.ident(&item.ident) == ident => return Some(Target::Item(item)),
+                _ => {}
+            }
+        }
+
+        let name = uses
+            .into_iter()
+            .find(|name| self.ident(name.local()) == ident)?;
+
+        let imported = self.resolve().ok().and_then(|document| {
+            let iface_ident = self.ident(&iface.ident);
+            let iface = document
+                .interfaces
+                .into_iter()
+                .find(|resolved| resolved.ident == iface_ident)?;
+
+            let typedef = iface
+                .typedefs
+                .iter()
+                .find(|(_, typedef)| typedef.ident == ident)?
+                .1
+                .clone();
+
+            Some(Target::Imported(typedef, iface.source_map))
+        });
+
+        Some(imported.unwrap_or(Target::Use(name)))
+    }
+
+    pub fn definition(&self, position: Position) -> Option<Location> {
+        let (iface, span) = self.symbol_at(position)?;
+
+        match self.lookup(iface, self.ident(&span))? {
+            Target::Item(item) => Some(self.location(item.ident.clone())),
+            Target::Use(name) => Some(self.location(name.ident.clone())),
+            Target::Imported(typedef, source_map) => {
+                let span = typedef.span?;
+                let name = source_map.name(&span)?;
+                let contents = source_map.contents(&span)?;
+
+                let uri = if name == self.source_name() {
+                    self.uri.clone()
+                } else {
+                    Url::from_file_path(name).ok()?
+                };
+
+                Some(Location {
+                    uri,
+                    range: LineIndex::new(contents).range(contents, span.range),
+                })
+            }
+        }
+    }
+
+    fn location(&self, span: Span) -> Location {
+        Location {
+            uri: self.uri.clone(),
+            range: self.index.range(&self.text, span),
+        }
+    }
+
+    pub fn hover(&self, position: Position) -> Option<Hover> {
+        let (iface, span) = self.symbol_at(position)?;
+        let ident = self.ident(&span);
+
+        let (kind, docs) = match self.lookup(iface, ident)? {
+            Target::Item(item) => (item_kind(&item.inner), self.docs(&item.docs)),
+            Target::Imported(typedef, _) => (typedef_kind(&typedef.kind), typedef.docs),
+            Target::Use(_) => return None,
+        };
+
+        let mut value = format!("```wit\n{kind} {ident}\n```");
+        if !docs.is_empty() {
+            value.push_str("\n\n");
+            value.push_str(&docs);
+        }
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: Some(self.index.range(&self.text, span)),
+        })
+    }
+
+    fn docs(&self, docs: &[Span]) -> String {
+        docs.iter()
+            .map(|span| {
+                let str = &self.text[span.clone()];
+                let str = str.strip_prefix("///").unwrap_or(str);
+                let str = str.strip_prefix("/**").unwrap_or(str);
+                let str = str.strip_suffix("*/").unwrap_or(str);
+
+                str.trim()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Completes the builtin types and the types defined or imported by any interface of the document.
+    pub fn completions(&self) -> Vec<CompletionItem> {
+        let keywords = Token::TYPE_KEYWORD
+            .iter()
+            .filter(|token| **token != Token::Ident)
+            .map(|token| CompletionItem {
+                label: token.as_str().trim_matches('\'').to_string(),
+                kind: Some(CompletionItemKind::KEYWORD),
+                ..CompletionItem::default()
+            });
+
+        let types = self
+            .parsed
+            .interfaces
+            .iter()
+            .flat_map(|iface| &iface.items)
+            .flat_map(|item| -> Vec<(&Span, CompletionItemKind)> {
+                match &item.inner {
+                    InterfaceItemInner::Func(_) | InterfaceItemInner::Event(_) => vec![],
+                    InterfaceItemInner::Use(names) => names
+                        .iter()
+                        .map(|name| (name.local(), CompletionItemKind::REFERENCE))
+                        .collect(),
+                    inner => vec![(&item.ident, completion_kind(inner))],
+                }
+            })
+            .map(|(span, kind)| CompletionItem {
+                label: self.ident(span).to_string(),
+                kind: Some(kind),
+                ..CompletionItem::default()
+            });
+
+        keywords.chain(types).collect()
+    }
+}
+
+/// Collects the spans of all types an item refers to by name.
+fn item_references(item: &InterfaceItem, out: &mut Vec<Span>) {
+    match &item.inner {
+        InterfaceItemInner::Alias(ty) => type_references(ty, out),
+        InterfaceItemInner::Record(fields) => {
+            for field in fields {
+                type_references(&field.ty, out);
+            }
+        }
+        InterfaceItemInner::Variant(cases) => {
+            for ty in cases.iter().filter_map(|case| case.ty.as_ref()) {
+                type_references(ty, out);
+            }
+        }
+        InterfaceItemInner::Union(cases) => {
+            for case in cases {
+                type_references(&case.ty, out);
+            }
+        }
+        InterfaceItemInner::Func(func) => func_references(func, out),
+        InterfaceItemInner::Event(payload) => {
+            for (_, ty) in payload {
+                type_references(ty, out);
+            }
+        }
+        InterfaceItemInner::Resource(methods) => {
+            for method in methods {
+                func_references(&method.inner, out);
+            }
+        }
+        InterfaceItemInner::Flags(_) | InterfaceItemInner::Enum(_) | InterfaceItemInner::Use(_) => {
+        }
+    }
+}
+
+fn func_references(func: &parse::Func, out: &mut Vec<Span>) {
+    for (_, ty) in &func.params {
+        type_references(ty, out);
+    }
+
+    match &func.result {
+        Some(FuncResult::Anon(ty)) => type_references(ty, out),
+        Some(FuncResult::Named(results)) => {
+            for (_, ty) in results {
+                type_references(ty, out);
+            }
+        }
+        None => {}
+    }
+}
+
+fn type_references(ty: &parse::Type, out: &mut Vec<Span>) {
+    match ty {
+        parse::Type::List(ty) | parse::Type::Option(ty) => type_references(ty, out),
+        parse::Type::Tuple(types) => {
+            for ty in types {
+                type_references(ty, out);
+            }
+        }
+        parse::Type::Result { ok, err } => {
+            for ty in ok.iter().chain(err) {
+                type_references(ty, out);
+            }
+        }
+        parse::Type::Own(span) | parse::Type::Borrow(span) | parse::Type::Id(span) => {
+            out.push(span.clone());
+        }
+        _ => {}
+    }
+}
+
+fn item_kind(inner: &InterfaceItemInner) -> &'static str {
+    match inner {
+        InterfaceItemInner::Alias(_) => "type",
+        InterfaceItemInner::Record(_) => "record",
+        InterfaceItemInner::Flags(_) => "flags",
+        InterfaceItemInner::Variant(_) => "variant",
+        InterfaceItemInner::Enum(_) => "enum",
+        InterfaceItemInner::Union(_) => "union",
+        InterfaceItemInner::Func(_) => "func",
+        InterfaceItemInner::Event(_) => "event",
+        InterfaceItemInner::Resource(_) => "resource",
+        InterfaceItemInner::Use(_) => "use",
+    }
+}
+
+fn typedef_kind(kind: &TypeDefKind) -> &'static str {
+    match kind {
+        TypeDefKind::Alias(_) => "type",
+        TypeDefKind::Record(_) => "record",
+        TypeDefKind::Flags(_) => "flags",
+        TypeDefKind::Variant(_) => "variant",
+        TypeDefKind::Enum(_) => "enum",
+        TypeDefKind::Union(_) => "union",
+        TypeDefKind::Resource(_) => "resource",
+    }
+}
+
+fn completion_kind(inner: &InterfaceItemInner) -> CompletionItemKind {
+    match inner {
+        InterfaceItemInner::Record(_) => CompletionItemKind::STRUCT,
+        InterfaceItemInner::Flags(_)
+        | InterfaceItemInner::Variant(_)
+        | InterfaceItemInner::Enum(_)
+        | InterfaceItemInner::Union(_) => CompletionItemKind::ENUM,
+        InterfaceItemInner::Resource(_) => CompletionItemKind::CLASS,
+        InterfaceItemInner::Event(_) => CompletionItemKind::EVENT,
+        InterfaceItemInner::Alias(_) | InterfaceItemInner::Func(_) | InterfaceItemInner::Use(_) => {
+            CompletionItemKind::REFERENCE
+        }
+    }
+}
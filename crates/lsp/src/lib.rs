@@ -0,0 +1,174 @@
+//! A language server for `*.wit` files.
+//!
+//! The server reports syntax and resolution errors as diagnostics,
+//! resolves type references to their definition, shows doc comments on hover and completes type names.
+
+#![allow(clippy::missing_errors_doc)]
+
+mod analysis;
+mod line_index;
+
+use analysis::Document;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _,
+        PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Request as _},
+    CompletionOptions, CompletionResponse, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, GotoDefinitionResponse, HoverProviderCapability, OneOf,
+    PublishDiagnosticsParams, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+    Url,
+};
+use miette::IntoDiagnostic;
+use std::collections::HashMap;
+
+/// Serves the language server protocol over `connection` until the client asks the server to exit.
+pub fn run(connection: Connection) -> miette::Result<()> {
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions::default()),
+        ..ServerCapabilities::default()
+    };
+
+    connection
+        .initialize(serde_json::to_value(capabilities).into_diagnostic()?)
+        .into_diagnostic()?;
+
+    let mut server = Server {
+        connection: &connection,
+        documents: HashMap::new(),
+    };
+
+    for msg in &connection.receiver {
+        match msg {
+            Message::Request(req) => {
+                if connection.handle_shutdown(&req).into_diagnostic()? {
+                    break;
+                }
+
+                server.handle_request(req)?;
+            }
+            Message::Notification(not) => server.handle_notification(not)?,
+            Message::Response(_) => {}
+        }
+    }
+
+    log::debug!("shutting down");
+
+    Ok(())
+}
+
+struct Server<'a> {
+    connection: &'a Connection,
+    documents: HashMap<Url, Document>,
+}
+
+impl Server<'_> {
+    fn handle_request(&self, req: Request) -> miette::Result<()> {
+        log::debug!("received request {}", req.method);
+
+        let response = match req.method.as_str() {
+            GotoDefinition::METHOD => self.respond::<GotoDefinition>(req, |server, params| {
+                let params = params.text_document_position_params;
+                let document = server.documents.get(&params.text_document.uri)?;
+
+                document
+                    .definition(params.position)
+                    .map(GotoDefinitionResponse::Scalar)
+            }),
+            HoverRequest::METHOD => self.respond::<HoverRequest>(req, |server, params| {
+                let params = params.text_document_position_params;
+                let document = server.documents.get(&params.text_document.uri)?;
+
+                document.hover(params.position)
+            }),
+            Completion::METHOD => self.respond::<Completion>(req, |server, params| {
+                let uri = params.text_document_position.text_document.uri;
+                let document = server.documents.get(&uri)?;
+
+                Some(CompletionResponse::Array(document.completions()))
+            }),
+            _ => Response::new_err(
+                req.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unhandled method {}", req.method),
+            ),
+        };
+
+        self.connection
+            .sender
+            .send(response.into())
+            .into_diagnostic()
+    }
+
+    fn respond<R>(&self, req: Request, f: impl FnOnce(&Self, R::Params) -> R::Result) -> Response
+    where
+        R: lsp_types::request::Request,
+    {
+        match serde_json::from_value(req.params) {
+            Ok(params) => Response::new_ok(req.id, f(self, params)),
+            Err(err) => Response::new_err(req.id, ErrorCode::InvalidParams as i32, err.to_string()),
+        }
+    }
+
+    fn handle_notification(&mut self, not: Notification) -> miette::Result<()> {
+        log::debug!("received notification {}", not.method);
+
+        match not.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    serde_json::from_value(not.params).into_diagnostic()?;
+                let document = params.text_document;
+
+                self.update(document.uri, document.text)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    serde_json::from_value(not.params).into_diagnostic()?;
+
+                // we only support full document sync, so the last change holds the whole text
+                let Some(change) = params.content_changes.into_iter().last() else {
+                    return Ok(());
+                };
+
+                self.update(params.text_document.uri, change.text)
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    serde_json::from_value(not.params).into_diagnostic()?;
+                let uri = params.text_document.uri;
+
+                self.documents.remove(&uri);
+                self.publish_diagnostics(uri, Vec::new())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn update(&mut self, uri: Url, text: String) -> miette::Result<()> {
+        let document = Document::new(uri.clone(), text);
+        let diagnostics = document.diagnostics();
+
+        self.documents.insert(uri.clone(), document);
+        self.publish_diagnostics(uri, diagnostics)
+    }
+
+    fn publish_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+    ) -> miette::Result<()> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        };
+        let not = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+
+        self.connection.sender.send(not.into()).into_diagnostic()
+    }
+}
@@ -0,0 +1,84 @@
+use lsp_types::{Position, Range};
+
+/// Converts between byte offsets and LSP positions.
+///
+/// LSP positions count characters in UTF-16 code units, so this needs the text the offsets point into.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        Self { line_starts }
+    }
+
+    pub fn position(&self, text: &str, offset: usize) -> Position {
+        let offset = floor_char_boundary(text, offset);
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let start = self.line_starts[line];
+
+        Position {
+            line: u32::try_from(line).unwrap(),
+            character: u32::try_from(text[start..offset].encode_utf16().count()).unwrap(),
+        }
+    }
+
+    pub fn range(&self, text: &str, range: std::ops::Range<usize>) -> Range {
+        Range {
+            start: self.position(text, range.start),
+            end: self.position(text, range.end),
+        }
+    }
+
+    pub fn offset(&self, text: &str, position: Position) -> usize {
+        let Some(start) = self.line_starts.get(position.line as usize) else {
+            return text.len();
+        };
+
+        let mut units = 0;
+        for (idx, char) in text[*start..].char_indices() {
+            if char == '\n' || units >= position.character as usize {
+                return start + idx;
+            }
+            units += char.len_utf16();
+        }
+
+        text.len()
+    }
+}
+
+fn floor_char_boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let text = "interface a {\n  /// ✨\n  type b = u8\n}";
+        let index = LineIndex::new(text);
+
+        let offset = text.find("b =").unwrap();
+        let position = index.position(text, offset);
+
+        assert_eq!(position, Position::new(2, 7));
+        assert_eq!(index.offset(text, position), offset);
+
+        // the sparkle is one UTF-16 code unit, but three bytes
+        let newline = text.find("\n  type").unwrap();
+        assert_eq!(index.position(text, newline), Position::new(1, 7));
+        assert_eq!(index.offset(text, Position::new(1, 100)), newline);
+        assert_eq!(index.offset(text, Position::new(7, 0)), text.len());
+    }
+}
@@ -0,0 +1,13 @@
+use lsp_server::Connection;
+use miette::IntoDiagnostic;
+
+fn main() -> miette::Result<()> {
+    // stdout is reserved for the protocol, so logs go to stderr
+    env_logger::init();
+
+    let (connection, io_threads) = Connection::stdio();
+
+    tauri_bindgen_lsp::run(connection)?;
+
+    io_threads.join().into_diagnostic()
+}
@@ -0,0 +1,277 @@
+use lsp_server::{Connection, Message, Notification, Request, RequestId};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidOpenTextDocument, Exit, Initialized, Notification as _,
+        PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Initialize, Shutdown},
+    CompletionParams, CompletionResponse, DidChangeTextDocumentParams, DidOpenTextDocumentParams,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams,
+    InitializeParams, Position, PublishDiagnosticsParams, Range, TextDocumentContentChangeEvent,
+    TextDocumentIdentifier, TextDocumentItem, TextDocumentPositionParams, Url,
+    VersionedTextDocumentIdentifier,
+};
+use std::thread::JoinHandle;
+
+/// Drives a server running on another thread through an in-memory connection.
+struct Client {
+    connection: Connection,
+    server: Option<JoinHandle<miette::Result<()>>>,
+    next_id: i32,
+}
+
+impl Client {
+    fn new() -> Self {
+        let (client, server) = Connection::memory();
+        let server = std::thread::spawn(move || tauri_bindgen_lsp::run(server));
+
+        let mut client = Self {
+            connection: client,
+            server: Some(server),
+            next_id: 0,
+        };
+
+        client.request::<Initialize>(InitializeParams::default());
+        client.notify::<Initialized>(lsp_types::InitializedParams {});
+
+        client
+    }
+
+    fn request<R: lsp_types::request::Request>(&mut self, params: R::Params) -> R::Result {
+        self.next_id += 1;
+        let id = RequestId::from(self.next_id);
+
+        self.connection
+            .sender
+            .send(Request::new(id.clone(), R::METHOD.to_string(), params).into())
+            .unwrap();
+
+        loop {
+            match self.connection.receiver.recv().unwrap() {
+                Message::Response(res) if res.id == id => {
+                    assert!(res.error.is_none(), "{:?}", res.error);
+
+                    return serde_json::from_value(res.result.unwrap_or_default()).unwrap();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn notify<N: lsp_types::notification::Notification>(&self, params: N::Params) {
+        self.connection
+            .sender
+            .send(Notification::new(N::METHOD.to_string(), params).into())
+            .unwrap();
+    }
+
+    fn diagnostics(&self) -> PublishDiagnosticsParams {
+        loop {
+            match self.connection.receiver.recv().unwrap() {
+                Message::Notification(not) if not.method == PublishDiagnostics::METHOD => {
+                    return serde_json::from_value(not.params).unwrap();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn open(&self, uri: &Url, text: &str) -> PublishDiagnosticsParams {
+        self.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(
+                uri.clone(),
+                "wit".to_string(),
+                0,
+                text.to_string(),
+            ),
+        });
+
+        self.diagnostics()
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        self.request::<Shutdown>(());
+        self.notify::<Exit>(());
+
+        self.server.take().unwrap().join().unwrap().unwrap();
+    }
+}
+
+fn uri() -> Url {
+    Url::parse("untitled:greet.wit").unwrap()
+}
+
+fn position_params(uri: &Url, position: Position) -> TextDocumentPositionParams {
+    TextDocumentPositionParams::new(TextDocumentIdentifier::new(uri.clone()), position)
+}
+
+const SOURCE: &str = "interface greet {
+    /// A greeting
+    record greeting {
+        text: string,
+    }
+
+    func greet(name: string) -> greeting
+}
+";
+
+#[test]
+fn diagnostics() {
+    let client = Client::new();
+    let uri = uri();
+
+    let params = client.open(&uri, SOURCE);
+    assert_eq!(params.uri, uri);
+    assert!(params.diagnostics.is_empty());
+
+    client.notify::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+        text_document: VersionedTextDocumentIdentifier::new(uri.clone(), 1),
+        content_changes: vec![TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: SOURCE.replace("-> greeting", "-> greting"),
+        }],
+    });
+
+    let params = client.diagnostics();
+    assert_eq!(params.diagnostics.len(), 1);
+
+    let diagnostic = &params.diagnostics[0];
+    assert_eq!(
+        diagnostic.range,
+        Range::new(Position::new(6, 32), Position::new(6, 39))
+    );
+    assert!(diagnostic.message.contains("Did you mean \"greeting\"?"));
+
+    // syntax errors are reported all at once
+    let params = client.open(
+        &Url::parse("untitled:broken.wit").unwrap(),
+        "interface a {\n  fnc b()\n  record c { d: }\n}",
+    );
+    assert_eq!(params.diagnostics.len(), 2);
+    assert_eq!(params.diagnostics[0].range.start, Position::new(1, 2));
+
+    // so are names that collide in generated code
+    let params = client.open(
+        &Url::parse("untitled:collision.wit").unwrap(),
+        "interface a {\n  func foo-bar()\n  func foo_bar()\n}",
+    );
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].range,
+        Range::new(Position::new(2, 7), Position::new(2, 14))
+    );
+}
+
+#[test]
+fn goto_definition() {
+    let mut client = Client::new();
+    let uri = uri();
+    client.open(&uri, SOURCE);
+
+    let res = client.request::<GotoDefinition>(GotoDefinitionParams {
+        text_document_position_params: position_params(&uri, Position::new(6, 34)),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    });
+
+    let Some(GotoDefinitionResponse::Scalar(location)) = res else {
+        panic!("expected a single location, found {res:?}");
+    };
+    assert_eq!(location.uri, uri);
+    assert_eq!(
+        location.range,
+        Range::new(Position::new(2, 11), Position::new(2, 19))
+    );
+
+    // builtin types have no definition
+    let res = client.request::<GotoDefinition>(GotoDefinitionParams {
+        text_document_position_params: position_params(&uri, Position::new(3, 16)),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    });
+    assert!(res.is_none());
+}
+
+#[test]
+fn goto_definition_in_other_file() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../wit-parser/tests/package")
+        .canonicalize()
+        .unwrap();
+    let uri = Url::from_file_path(dir.join("app.wit")).unwrap();
+    let text = std::fs::read_to_string(dir.join("app.wit")).unwrap();
+
+    let mut client = Client::new();
+    assert!(client.open(&uri, &text).diagnostics.is_empty());
+
+    let res = client.request::<GotoDefinition>(GotoDefinitionParams {
+        text_document_position_params: position_params(&uri, Position::new(3, 30)),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    });
+
+    let Some(GotoDefinitionResponse::Scalar(location)) = res else {
+        panic!("expected a single location, found {res:?}");
+    };
+    assert_eq!(
+        location.uri,
+        Url::from_file_path(dir.join("shapes.wit")).unwrap()
+    );
+    assert_eq!(
+        location.range,
+        Range::new(Position::new(3, 9), Position::new(3, 14))
+    );
+}
+
+#[test]
+fn hover() {
+    let mut client = Client::new();
+    let uri = uri();
+    client.open(&uri, SOURCE);
+
+    let res = client.request::<HoverRequest>(HoverParams {
+        text_document_position_params: position_params(&uri, Position::new(6, 34)),
+        work_done_progress_params: Default::default(),
+    });
+
+    let Some(Hover {
+        contents: HoverContents::Markup(contents),
+        range,
+    }) = res
+    else {
+        panic!("expected markup, found {res:?}");
+    };
+    assert_eq!(contents.value, "```wit\nrecord greeting\n```\n\nA greeting");
+    assert_eq!(
+        range,
+        Some(Range::new(Position::new(6, 32), Position::new(6, 40)))
+    );
+}
+
+#[test]
+fn completion() {
+    let mut client = Client::new();
+    let uri = uri();
+    client.open(&uri, SOURCE);
+
+    let res = client.request::<Completion>(CompletionParams {
+        text_document_position: position_params(&uri, Position::new(6, 32)),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+        context: None,
+    });
+
+    let Some(CompletionResponse::Array(items)) = res else {
+        panic!("expected completion items, found {res:?}");
+    };
+    let labels: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+
+    assert!(labels.contains(&"u32"));
+    assert!(labels.contains(&"float64"));
+    assert!(labels.contains(&"result"));
+    assert!(labels.contains(&"greeting"));
+    assert!(!labels.contains(&"greet"));
+}
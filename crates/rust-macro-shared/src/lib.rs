@@ -4,7 +4,7 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::marker;
 use std::path::PathBuf;
 use syn::parse::{Error, Parse, ParseStream, Result};
@@ -16,17 +16,36 @@ use tauri_bindgen_core::GeneratorBuilder;
 pub fn generate<F, B>(input: TokenStream) -> TokenStream
 where
     F: Parse + Configure<B>,
-    B: GeneratorBuilder + Default,
+    B: GeneratorBuilder + Default + Clone,
 {
     let input = syn::parse_macro_input!(input as Opts<F, B>);
-    let iface =
-        wit_parser::parse_and_resolve_file(&input.file, |t| input.skip.contains(t)).unwrap();
-
-    let mut gen = input.builder.build(iface);
-    let mut tokens = gen.to_tokens();
-
-    let filepath = input.file.to_string_lossy();
-    tokens.extend(quote! {const _: &str = include_str!(#filepath);});
+    let document =
+        wit_parser::parse_and_resolve_document_file(&input.file, |t| input.skip.contains(t))
+            .unwrap();
+    let interfaces = document.select_world(input.world.as_deref()).unwrap();
+
+    // every file of the package the bindings were resolved from, so editing any of them triggers a rebuild
+    let files: BTreeSet<String> = interfaces
+        .iter()
+        .flat_map(|iface| iface.source_map.names())
+        .map(ToString::to_string)
+        .chain([input.file.to_string_lossy().to_string()])
+        .collect();
+
+    let mut tokens: proc_macro2::TokenStream = interfaces
+        .into_iter()
+        .map(|iface| {
+            tauri_bindgen_core::check_interface(&iface)
+                .map_err(miette::Report::from)
+                .unwrap();
+
+            input.builder.clone().build(iface).to_tokens()
+        })
+        .collect();
+
+    for filepath in files {
+        tokens.extend(quote! {const _: &str = include_str!(#filepath);});
+    }
 
     tokens.into()
 }
@@ -39,12 +58,14 @@ struct Opts<F, O> {
     builder: O,
     skip: HashSet<String>,
     file: PathBuf,
+    world: Option<String>,
     _marker: marker::PhantomData<F>,
 }
 
 mod kw {
     syn::custom_keyword!(path);
     syn::custom_keyword!(skip);
+    syn::custom_keyword!(world);
 }
 
 impl<F, O> Parse for Opts<F, O>
@@ -60,6 +81,7 @@ where
             builder: O::default(),
             file: PathBuf::new(),
             skip: HashSet::new(),
+            world: None,
             _marker: marker::PhantomData,
         };
 
@@ -82,6 +104,7 @@ where
                     ConfigField::Skip(skip) => {
                         ret.skip = skip.iter().map(syn::LitStr::value).collect();
                     }
+                    ConfigField::World(world) => ret.world = Some(world.value()),
                     ConfigField::Other(other) => other.configure(&mut ret.builder),
                 }
             }
@@ -112,6 +135,7 @@ fn parse_path(path: &syn::LitStr) -> PathBuf {
 enum ConfigField<F> {
     Path(syn::LitStr),
     Skip(Vec<syn::LitStr>),
+    World(syn::LitStr),
     Other(F),
 }
 
@@ -129,6 +153,10 @@ impl<F: Parse> Parse for ConfigField<F> {
             syn::bracketed!(contents in input);
             let list = Punctuated::<_, Token![,]>::parse_terminated(&contents)?;
             Ok(ConfigField::Skip(list.iter().cloned().collect()))
+        } else if l.peek(kw::world) {
+            input.parse::<kw::world>()?;
+            input.parse::<Token![:]>()?;
+            Ok(ConfigField::World(input.parse()?))
         } else {
             Ok(ConfigField::Other(input.parse()?))
         }
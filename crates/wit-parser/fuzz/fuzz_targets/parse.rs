@@ -6,5 +6,5 @@ fuzz_target!(|data: &str| {
     // fuzzed code goes here
     drop(env_logger::try_init());
     
-    drop(wit_parser::parse(data, |_| false))
+    drop(wit_parser::parse(data))
 });
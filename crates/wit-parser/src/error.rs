@@ -92,6 +92,62 @@ pub enum Error {
         #[label("remove this type")]
         location: Span,
     },
+    /// Two types brought into scope by `use` items (or a `use` item and a local type) can't share a name.
+    #[error("type {ident} is already defined in this interface.")]
+    #[diagnostic(code(wit_parser::import_conflict))]
+    ImportConflict {
+        #[label("imported type conflicts with another type of the same name")]
+        location: Span,
+        ident: String,
+        #[help]
+        help: Option<String>,
+    },
+    /// Interfaces can't import types from themselves, directly or through other interfaces.
+    #[error("interface {ident} depends on itself.")]
+    #[diagnostic(code(wit_parser::cyclic_dependency))]
+    CyclicDependency {
+        #[label("interface cannot depend on itself")]
+        location: Span,
+        ident: String,
+    },
+    /// Resources get generated functions to create and release them, so their functions can't use those names.
+    #[error("{ident} is reserved for a generated resource function.")]
+    #[diagnostic(code(wit_parser::reserved_name))]
+    ReservedName {
+        #[label("this name is reserved")]
+        location: Span,
+        ident: String,
+    },
+    /// `own` and `borrow` handles can only refer to resources.
+    #[error("handles can only refer to resources.")]
+    #[diagnostic(code(wit_parser::not_a_resource))]
+    NotAResource {
+        #[label("this is not a resource")]
+        location: Span,
+    },
+    /// A borrowed handle is only valid for the duration of a call, so it can't be returned or emitted.
+    #[error("borrowed handles can't be returned.")]
+    #[diagnostic(code(wit_parser::borrowed_result))]
+    BorrowedResult {
+        #[label("this returns a borrowed handle")]
+        location: Span,
+        #[help]
+        help: Option<String>,
+    },
+    /// Identifiers are converted to the naming conventions of each language, which can make two distinct identifiers equal.
+    #[error("{ident} and {previous} both turn into `{name}` in generated {language} code")]
+    #[diagnostic(
+        code(wit_parser::name_collision),
+        help("rename {ident} so it doesn't clash with {previous}")
+    )]
+    NameCollision {
+        ident: String,
+        previous: String,
+        name: String,
+        language: &'static str,
+        #[label("this name collides with {previous}")]
+        location: Span,
+    },
     #[error("Failed with multiple errors:")]
     Multi {
         #[related]
@@ -173,6 +229,61 @@ impl Error {
         }
     }
 
+    pub fn import_conflict(loc: impl Into<Span>, ident: impl Into<String>) -> Self {
+        let ident = ident.into();
+
+        Self::ImportConflict {
+            location: loc.into(),
+            help: Some(format!(
+                "Rename the local type, or import {ident} under a different name using `as`"
+            )),
+            ident,
+        }
+    }
+
+    pub fn reserved_name(loc: impl Into<Span>, ident: impl Into<String>) -> Self {
+        Self::ReservedName {
+            location: loc.into(),
+            ident: ident.into(),
+        }
+    }
+
+    pub fn not_a_resource(loc: impl Into<Span>) -> Self {
+        Self::NotAResource {
+            location: loc.into(),
+        }
+    }
+
+    pub fn borrowed_result(loc: impl Into<Span>) -> Self {
+        Self::BorrowedResult {
+            location: loc.into(),
+            help: Some("Use `own<..>` to hand the resource over".to_string()),
+        }
+    }
+
+    pub fn cyclic_dependency(loc: impl Into<Span>, ident: impl Into<String>) -> Self {
+        Self::CyclicDependency {
+            location: loc.into(),
+            ident: ident.into(),
+        }
+    }
+
+    pub fn name_collision(
+        loc: impl Into<Span>,
+        ident: impl Into<String>,
+        previous: impl Into<String>,
+        name: impl Into<String>,
+        language: &'static str,
+    ) -> Self {
+        Self::NameCollision {
+            ident: ident.into(),
+            previous: previous.into(),
+            name: name.into(),
+            language,
+            location: loc.into(),
+        }
+    }
+
     #[must_use]
     pub fn bidirectional_override_codepoint(pos: usize) -> Self {
         Self::BidirectionalOverrideCodepoint { location: pos }
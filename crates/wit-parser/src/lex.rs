@@ -51,7 +51,7 @@ pub enum Token {
     DocComment,
     #[regex(r#"/\*\*"#, block_comment)]
     BlockDocComment,
-    #[regex("%?([a-zA-Z0-9_])+")]
+    #[regex("%?[a-zA-Z0-9_]+(-[a-zA-Z0-9_]+)*")]
     Ident,
 
     // operators
@@ -87,6 +87,8 @@ pub enum Token {
     Resource,
     #[token("func")]
     Func,
+    #[token("event")]
+    Event,
     #[token("u8")]
     U8,
     #[token("u16")]
@@ -137,30 +139,41 @@ pub enum Token {
     Interface,
     #[token("tuple")]
     Tuple,
-
-    // reserved but currently unused
     #[token("use")]
     Use,
     #[token("as")]
     As,
     #[token("from")]
     From,
+    #[token("world")]
+    World,
+    #[token("import")]
+    Import,
     #[token("static")]
     Static,
+    #[token("constructor")]
+    Constructor,
+    #[token("own")]
+    Own,
+    #[token("borrow")]
+    Borrow,
 }
 
 impl Token {
-    pub const IFACE_ITEM_KEYWORD: [Token; 8] = [
+    pub const IFACE_ITEM_KEYWORD: [Token; 10] = [
         Token::Enum,
         Token::Flags,
         Token::Func,
+        Token::Event,
         Token::Record,
         Token::Type,
         Token::Union,
         Token::Variant,
         Token::Resource,
+        Token::Use,
     ];
-    pub const TYPE_KEYWORD: [Token; 20] = [
+    pub const DOCUMENT_ITEM_KEYWORD: [Token; 2] = [Token::Interface, Token::World];
+    pub const TYPE_KEYWORD: [Token; 22] = [
         Token::U8,
         Token::U16,
         Token::U32,
@@ -180,6 +193,8 @@ impl Token {
         Token::Result,
         Token::List,
         Token::Tuple,
+        Token::Own,
+        Token::Borrow,
         Token::Ident,
     ];
     pub fn as_str(&self) -> &str {
@@ -203,6 +218,7 @@ impl Token {
             Token::Type => "'type'",
             Token::Resource => "'resource'",
             Token::Func => "'func'",
+            Token::Event => "'event'",
             Token::U8 => "'u8'",
             Token::U16 => "'u16'",
             Token::U32 => "'u32'",
@@ -231,7 +247,12 @@ impl Token {
             Token::Use => "'use'",
             Token::As => "'as'",
             Token::From => "'from'",
+            Token::World => "'world'",
+            Token::Import => "'import'",
             Token::Static => "'static'",
+            Token::Constructor => "'constructor'",
+            Token::Own => "'own'",
+            Token::Borrow => "'borrow'",
         }
     }
 }
@@ -277,5 +298,14 @@ mod test {
 
         let mut lex = Token::lexer("%foo_bar");
         assert_eq!(lex.next(), Some(Ok(Token::Ident)));
+
+        let mut lex = Token::lexer("foo-bar");
+        assert_eq!(lex.next(), Some(Ok(Token::Ident)));
+        assert_eq!(lex.next(), None);
+
+        let mut lex = Token::lexer("foo->bar");
+        assert_eq!(lex.next(), Some(Ok(Token::Ident)));
+        assert_eq!(lex.next(), Some(Ok(Token::RArrow)));
+        assert_eq!(lex.next(), Some(Ok(Token::Ident)));
     }
 }
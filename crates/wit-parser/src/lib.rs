@@ -1,71 +1,194 @@
 #![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
 
 mod error;
-mod lex;
-mod parse;
+pub mod lex;
+pub mod lint;
+pub mod names;
+mod package;
+pub mod parse;
+mod printer;
+mod source_map;
 pub mod typecheck;
 mod util;
 
 pub use error::Error;
+pub use printer::format;
+pub use source_map::{SourceMap, Span};
 pub(crate) type Result<T> = std::result::Result<T, error::Error>;
 
 pub type TypeDefId = Id<TypeDef>;
 pub type TypeDefArena = Arena<TypeDef>;
 
 use id_arena::{Arena, Id};
-use logos::Logos;
 use miette::{ErrReport, IntoDiagnostic, NamedSource};
+use package::Package;
 use parse::FromTokens;
 use schemars::JsonSchema;
 use serde::{ser::SerializeSeq, Serialize};
 use std::path::Path;
-use typecheck::Resolver;
 use util::detect_invalid_input;
 
+/// Parses and resolves a document that defines exactly one interface.
 #[inline]
 pub fn parse_and_resolve_str(
     input: impl AsRef<str>,
     skip: impl Fn(&str) -> bool,
 ) -> miette::Result<Interface> {
-    let iface = parse_and_resolve(input.as_ref(), skip).map_err(|error: ErrReport| {
+    single_interface(parse_and_resolve_document_str(input, skip)?)
+}
+
+/// Parses and resolves a document that defines exactly one interface.
+///
+/// Interfaces the document imports types from are loaded from the other files in the same directory.
+#[inline]
+pub fn parse_and_resolve_file(
+    path: impl AsRef<Path>,
+    skip: impl Fn(&str) -> bool,
+) -> miette::Result<Interface> {
+    single_interface(parse_and_resolve_document_file(path, skip)?)
+}
+
+#[inline]
+pub fn parse_and_resolve_document_str(
+    input: impl AsRef<str>,
+    skip: impl Fn(&str) -> bool,
+) -> miette::Result<Document> {
+    let document = parse_and_resolve(input.as_ref(), None, skip).map_err(|error: ErrReport| {
         error.with_source_code(NamedSource::new("virtual file", input.as_ref().to_string()))
     })?;
 
-    Ok(iface)
+    Ok(document)
 }
 
+/// Parses and resolves a document.
+///
+/// Interfaces the document references, but doesn't define, are loaded from the other files in the same directory.
 #[inline]
-pub fn parse_and_resolve_file(
+pub fn parse_and_resolve_document_file(
     path: impl AsRef<Path>,
     skip: impl Fn(&str) -> bool,
-) -> miette::Result<Interface> {
+) -> miette::Result<Document> {
     let path = path.as_ref();
     let input = std::fs::read_to_string(path).into_diagnostic()?;
 
-    let iface = parse_and_resolve(&input, skip).map_err(|error: ErrReport| {
+    let document = parse_and_resolve(&input, Some(path), skip).map_err(|error: ErrReport| {
         error.with_source_code(NamedSource::new(path.to_string_lossy(), input))
     })?;
 
-    Ok(iface)
+    Ok(document)
 }
 
+/// Parses and resolves `input` as the contents of the file at `path`.
+///
+/// This behaves like [`parse_and_resolve_document_file`], but uses `input` instead of reading `path`,
+/// which lets editors check documents that have unsaved changes.
 #[inline]
-fn parse_and_resolve(input: &str, skip: impl Fn(&str) -> bool) -> miette::Result<Interface> {
-    let iface = parse(input, skip)?;
+pub fn parse_and_resolve_document_source(
+    input: impl AsRef<str>,
+    path: impl AsRef<Path>,
+    skip: impl Fn(&str) -> bool,
+) -> miette::Result<Document> {
+    let (input, path) = (input.as_ref(), path.as_ref());
+
+    let document = parse_and_resolve(input, Some(path), skip).map_err(|error: ErrReport| {
+        error.with_source_code(NamedSource::new(path.to_string_lossy(), input.to_string()))
+    })?;
 
-    let (resolver, rest_data) = Resolver::new(input, iface);
-    resolver.resolve(rest_data).map_err(Into::into)
+    Ok(document)
 }
 
+fn single_interface(document: Document) -> miette::Result<Interface> {
+    let mut interfaces = document.interfaces;
+
+    if interfaces.len() != 1 {
+        miette::bail!(
+            help = "use `parse_and_resolve_document_file` to resolve documents with multiple interfaces",
+            "expected exactly one interface, but the document defines {}",
+            interfaces.len()
+        );
+    }
+
+    Ok(interfaces.remove(0))
+}
+
+/// Parses and resolves a document.
+///
+/// When `path` is given, interfaces referenced by the document are loaded from the other files in the same directory.
 #[inline]
-pub fn parse(input: &str, _skip: impl Fn(&str) -> bool) -> miette::Result<parse::Interface> {
-    detect_invalid_input(input)?;
+fn parse_and_resolve(
+    input: &str,
+    path: Option<&Path>,
+    skip: impl Fn(&str) -> bool,
+) -> miette::Result<Document> {
+    let document = parse(input)?;
+
+    let load_dir = document.external_references(input).next().is_some();
+
+    let roots: Vec<_> = document
+        .interfaces
+        .iter()
+        .map(|iface| {
+            input[iface.ident.clone()]
+                .trim_start_matches('%')
+                .to_string()
+        })
+        .collect();
+
+    let name = path.map_or_else(
+        || "virtual file".to_string(),
+        |path| path.to_string_lossy().to_string(),
+    );
+
+    let mut package = Package::default();
+    let source = package.add_document(name, input.to_string(), document.interfaces)?;
+
+    if let Some(path) = path.filter(|_| load_dir) {
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+
+        package.add_dir(dir, path)?;
+    }
 
-    let mut tokens = lex::Token::lexer(input).spanned().peekable();
+    package.resolve(&roots, source, document.worlds, &skip)
+}
 
-    let iface = parse::Interface::parse(&mut tokens)?;
+/// Parses a document, reporting all syntax errors at once.
+#[inline]
+pub fn parse(input: &str) -> miette::Result<parse::Document> {
+    let (document, mut errors) = parse_partial(input);
 
-    Ok(iface)
+    match errors.len() {
+        0 => Ok(document),
+        1 => Err(errors.remove(0).into()),
+        _ => Err(Error::Multi { errors }.into()),
+    }
+}
+
+/// Parses a document, recovering from syntax errors.
+///
+/// The parser resyncs at the next item after a syntax error,
+/// so this returns the document with all items that could be parsed along with every syntax error encountered.
+#[must_use]
+pub fn parse_partial(input: &str) -> (parse::Document, Vec<Error>) {
+    if let Err(err) = detect_invalid_input(input) {
+        return (parse::Document::default(), vec![err]);
+    }
+
+    let mut tokens = parse::Tokens::new(input);
+
+    let document = match parse::Document::parse(&mut tokens) {
+        Ok(document) => document,
+        Err(err) => {
+            let mut errors = tokens.take_errors();
+            errors.push(err);
+
+            return (parse::Document::default(), errors);
+        }
+    };
+
+    (document, tokens.take_errors())
 }
 
 pub enum Int {
@@ -76,6 +199,60 @@ pub enum Int {
     U128,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct Document {
+    pub interfaces: Vec<Interface>,
+    pub worlds: Vec<World>,
+}
+
+impl Document {
+    /// Returns the interfaces to generate bindings for.
+    ///
+    /// These are the imports of the given world, or of the only world if no name is given.
+    /// Documents without worlds generate bindings for all interfaces they define.
+    pub fn select_world(self, name: Option<&str>) -> miette::Result<Vec<Interface>> {
+        let mut worlds = self.worlds;
+
+        match name {
+            Some(name) => {
+                let Some(pos) = worlds.iter().position(|world| world.ident == name) else {
+                    let suggestions =
+                        util::find_similar(worlds.iter().map(|world| &world.ident), name);
+
+                    if suggestions.is_empty() {
+                        miette::bail!("world `{name}` is not defined");
+                    }
+                    miette::bail!(
+                        help = format!("Did you mean \"{}\"?", util::print_list(suggestions)),
+                        "world `{name}` is not defined"
+                    );
+                };
+
+                Ok(worlds.swap_remove(pos).imports)
+            }
+            None if worlds.is_empty() => Ok(self.interfaces),
+            None if worlds.len() == 1 => Ok(worlds.remove(0).imports),
+            None => miette::bail!(
+                help = format!(
+                    "Select one of {}",
+                    util::print_list(worlds.iter().map(|world| &world.ident))
+                ),
+                "the document defines multiple worlds"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct World {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub docs: String,
+    pub ident: String,
+    pub imports: Vec<Interface>,
+    #[serde(skip)]
+    pub span: Option<Span>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
 pub struct Interface {
     #[serde(skip_serializing_if = "String::is_empty")]
@@ -85,6 +262,13 @@ pub struct Interface {
     #[schemars(with = "Vec<TypeDef>")]
     pub typedefs: Arena<TypeDef>,
     pub functions: Vec<Function>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<Event>,
+    #[serde(skip)]
+    pub span: Option<Span>,
+    /// The files the spans of this interface and all of its nodes point into.
+    #[serde(skip)]
+    pub source_map: SourceMap,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
@@ -112,6 +296,14 @@ pub enum Type {
         ok: Option<Box<Type>>,
         err: Option<Box<Type>>,
     },
+    /// A handle to a resource that is handed over, the sender can't use it afterwards.
+    #[serde(serialize_with = "serialize_id")]
+    #[schemars(with = "u32")]
+    Own(Id<TypeDef>),
+    /// A handle to a resource that is only lent for the duration of a call.
+    #[serde(serialize_with = "serialize_id")]
+    #[schemars(with = "u32")]
+    Borrow(Id<TypeDef>),
     #[serde(serialize_with = "serialize_id")]
     #[schemars(with = "u32")]
     Id(Id<TypeDef>),
@@ -124,6 +316,8 @@ pub struct TypeDef {
     pub ident: String,
     #[serde(flatten)]
     pub kind: TypeDefKind,
+    #[serde(skip)]
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
@@ -144,6 +338,8 @@ pub struct RecordField {
     pub docs: String,
     pub id: String,
     pub ty: Type,
+    #[serde(skip)]
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
@@ -151,6 +347,8 @@ pub struct FlagsField {
     #[serde(skip_serializing_if = "String::is_empty")]
     pub docs: String,
     pub id: String,
+    #[serde(skip)]
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
@@ -159,6 +357,8 @@ pub struct VariantCase {
     pub docs: String,
     pub id: String,
     pub ty: Option<Type>,
+    #[serde(skip)]
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
@@ -166,6 +366,8 @@ pub struct EnumCase {
     #[serde(skip_serializing_if = "String::is_empty")]
     pub docs: String,
     pub id: String,
+    #[serde(skip)]
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
@@ -173,6 +375,8 @@ pub struct UnionCase {
     #[serde(skip_serializing_if = "String::is_empty")]
     pub docs: String,
     pub ty: Type,
+    #[serde(skip)]
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
@@ -184,6 +388,38 @@ pub struct Function {
     #[schemars(with = "Vec<NamedType>")]
     pub params: NamedTypeList,
     pub result: Option<FunctionResult>,
+    #[serde(skip_serializing_if = "FunctionKind::is_freestanding")]
+    pub kind: FunctionKind,
+    #[serde(skip)]
+    pub span: Option<Span>,
+}
+
+/// How a function is called.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum FunctionKind {
+    /// A function of the interface.
+    #[default]
+    Freestanding,
+    /// A function of a resource, called on a handle.
+    Method,
+    /// A function of a resource, called without a handle.
+    Static,
+    /// Creates a resource, its result is a handle to the new resource.
+    Constructor,
+}
+
+impl FunctionKind {
+    #[must_use]
+    pub fn is_freestanding(&self) -> bool {
+        *self == FunctionKind::Freestanding
+    }
+
+    /// Whether the function is called on a resource handle, which is sent as its first parameter.
+    #[must_use]
+    pub fn is_method(&self) -> bool {
+        *self == FunctionKind::Method
+    }
 }
 
 impl Function {
@@ -197,6 +433,19 @@ impl Function {
     }
 }
 
+/// An event the host emits to the guest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct Event {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub docs: String,
+    pub id: String,
+    #[serde(serialize_with = "serialize_named_type_list")]
+    #[schemars(with = "Vec<NamedType>")]
+    pub payload: NamedTypeList,
+    #[serde(skip)]
+    pub span: Option<Span>,
+}
+
 pub type NamedTypeList = Vec<(String, Type)>;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
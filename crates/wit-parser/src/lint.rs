@@ -0,0 +1,631 @@
+//! Warnings for interfaces that resolve fine, but are likely to cause trouble.
+//!
+//! Every check is a [`Lint`] that can be allowed, turning it off, or denied, turning its warnings into errors.
+
+use crate::{
+    util::{find_similar, print_list},
+    Event, Function, FunctionResult, Interface, Span, Type, TypeDefKind,
+};
+use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use miette::{Diagnostic, LabeledSpan, NamedSource, Severity, SourceCode};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+/// Flags are represented as at most 64 bit integers, this many fields leave little room to grow.
+const FLAGS_WARN_THRESHOLD: usize = 56;
+
+/// Strict and reserved keywords of Rust 2021.
+pub const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "do", "dyn", "else",
+    "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
+/// Reserved words of JavaScript, including those only reserved in strict mode.
+pub const JS_KEYWORDS: &[&str] = &[
+    "arguments",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// Identifiers should be written in kebab-case, generators derive the names in other languages from it.
+    NonKebabCase,
+    /// Functions and events should be documented, their docs end up in the generated bindings.
+    MissingDocs,
+    /// `result` without an error type gives callers no way to tell what went wrong.
+    ResultWithoutError,
+    /// Flags with almost 64 fields can't grow much further.
+    FlagsNearLimit,
+    /// Identifiers that differ, but end up with the same name in generated code.
+    CaseCollision,
+    /// Identifiers that are reserved words in one of the languages bindings are generated for.
+    ReservedWord,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 6] = [
+        Lint::NonKebabCase,
+        Lint::MissingDocs,
+        Lint::ResultWithoutError,
+        Lint::FlagsNearLimit,
+        Lint::CaseCollision,
+        Lint::ReservedWord,
+    ];
+
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::NonKebabCase => "non-kebab-case",
+            Lint::MissingDocs => "missing-docs",
+            Lint::ResultWithoutError => "result-without-error",
+            Lint::FlagsNearLimit => "flags-near-limit",
+            Lint::CaseCollision => "case-collision",
+            Lint::ReservedWord => "reserved-word",
+        }
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Lint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Lint::ALL
+            .into_iter()
+            .find(|lint| lint.name() == s)
+            .ok_or_else(|| {
+                let suggestions = find_similar(Lint::ALL.map(|lint| lint.name()), s);
+
+                if suggestions.is_empty() {
+                    format!(
+                        "unknown lint `{s}`, expected one of {}",
+                        print_list(Lint::ALL)
+                    )
+                } else {
+                    format!(
+                        "unknown lint `{s}`, did you mean {}?",
+                        print_list(suggestions)
+                    )
+                }
+            })
+    }
+}
+
+/// Which lints are reported, and how.
+///
+/// Lints are reported as warnings unless they are allowed or denied.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    allow: HashSet<Lint>,
+    deny: HashSet<Lint>,
+}
+
+impl Config {
+    /// Turns `lint` off.
+    #[must_use]
+    pub fn allow(mut self, lint: Lint) -> Self {
+        self.deny.remove(&lint);
+        self.allow.insert(lint);
+        self
+    }
+
+    /// Reports `lint` as an error.
+    #[must_use]
+    pub fn deny(mut self, lint: Lint) -> Self {
+        self.allow.remove(&lint);
+        self.deny.insert(lint);
+        self
+    }
+
+    /// The severity warnings of `lint` are reported with, or `None` if the lint is allowed.
+    #[must_use]
+    pub fn severity(&self, lint: Lint) -> Option<Severity> {
+        if self.allow.contains(&lint) {
+            None
+        } else if self.deny.contains(&lint) {
+            Some(Severity::Error)
+        } else {
+            Some(Severity::Warning)
+        }
+    }
+}
+
+/// A lint that was triggered.
+#[derive(Debug, thiserror::Error)]
+#[error("{message}")]
+pub struct Warning {
+    pub lint: Lint,
+    pub severity: Severity,
+    pub message: String,
+    pub help: Option<String>,
+    source_code: Option<NamedSource<String>>,
+    location: Option<Span>,
+}
+
+impl Warning {
+    /// The location the warning points to.
+    #[must_use]
+    pub fn span(&self) -> Option<&Span> {
+        self.location.as_ref()
+    }
+}
+
+impl Diagnostic for Warning {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(format!("wit_parser::lint::{}", self.lint)))
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.source_code
+            .as_ref()
+            .map(|source| source as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let location = self.location.as_ref()?;
+
+        Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+            None, location,
+        ))))
+    }
+}
+
+/// Runs all lints that aren't allowed by `config` over the given interfaces.
+///
+/// Types interfaces import from each other are only reported once.
+#[must_use]
+pub fn lint(interfaces: &[Interface], config: &Config) -> Vec<Warning> {
+    let mut linter = Linter {
+        config,
+        iface: None,
+        seen: HashSet::new(),
+        warnings: Vec::new(),
+    };
+
+    for iface in interfaces {
+        linter.iface = Some(iface);
+        linter.interface(iface);
+    }
+
+    linter.warnings
+}
+
+struct Linter<'a> {
+    config: &'a Config,
+    iface: Option<&'a Interface>,
+    seen: HashSet<(Lint, Option<Span>, String)>,
+    warnings: Vec<Warning>,
+}
+
+impl<'a> Linter<'a> {
+    fn report(&mut self, lint: Lint, span: Option<&Span>, message: String, help: Option<String>) {
+        let Some(severity) = self.config.severity(lint) else {
+            return;
+        };
+
+        if !self.seen.insert((lint, span.cloned(), message.clone())) {
+            return;
+        }
+
+        let source_code = self
+            .iface
+            .zip(span)
+            .and_then(|(iface, span)| iface.source_map.named_source(span));
+
+        self.warnings.push(Warning {
+            lint,
+            severity,
+            message,
+            help,
+            source_code,
+            location: span.cloned(),
+        });
+    }
+
+    fn interface(&mut self, iface: &'a Interface) {
+        self.ident(&iface.ident, "interface", iface.span.as_ref());
+
+        let mut type_names = Names::default();
+
+        for (_, typedef) in &iface.typedefs {
+            let span = typedef.span.as_ref();
+
+            self.ident(&typedef.ident, "type", span);
+            self.reserved(&typedef.ident.to_upper_camel_case(), "type", span);
+            type_names.insert(
+                self,
+                &typedef.ident.to_upper_camel_case(),
+                &typedef.ident,
+                span,
+            );
+
+            match &typedef.kind {
+                TypeDefKind::Alias(ty) => self.ty(ty, span),
+                TypeDefKind::Record(fields) => {
+                    let mut names = Names::default();
+
+                    for field in fields {
+                        let span = field.span.as_ref().or(span);
+
+                        self.ident(&field.id, "field", span);
+                        self.reserved_member(&field.id, "field", span);
+                        names.insert(self, &field.id.to_snake_case(), &field.id, span);
+                        self.ty(&field.ty, span);
+                    }
+                }
+                TypeDefKind::Flags(fields) => {
+                    let mut names = Names::default();
+
+                    for field in fields {
+                        let span = field.span.as_ref().or(span);
+
+                        self.ident(&field.id, "flag", span);
+                        names.insert(self, &field.id.to_shouty_snake_case(), &field.id, span);
+                    }
+
+                    if fields.len() >= FLAGS_WARN_THRESHOLD {
+                        self.report(
+                            Lint::FlagsNearLimit,
+                            span,
+                            format!(
+                                "flags {} has {} fields, the limit is 64",
+                                typedef.ident,
+                                fields.len()
+                            ),
+                            Some("consider splitting the flags up".to_string()),
+                        );
+                    }
+                }
+                TypeDefKind::Variant(cases) => {
+                    let mut names = Names::default();
+
+                    for case in cases {
+                        let span = case.span.as_ref().or(span);
+
+                        self.ident(&case.id, "case", span);
+                        names.insert(self, &case.id.to_upper_camel_case(), &case.id, span);
+                        if let Some(ty) = &case.ty {
+                            self.ty(ty, span);
+                        }
+                    }
+                }
+                TypeDefKind::Enum(cases) => {
+                    let mut names = Names::default();
+
+                    for case in cases {
+                        let span = case.span.as_ref().or(span);
+
+                        self.ident(&case.id, "case", span);
+                        names.insert(self, &case.id.to_upper_camel_case(), &case.id, span);
+                    }
+                }
+                TypeDefKind::Union(cases) => {
+                    for case in cases {
+                        self.ty(&case.ty, case.span.as_ref().or(span));
+                    }
+                }
+                TypeDefKind::Resource(methods) => {
+                    let mut names = Names::default();
+
+                    for method in methods {
+                        self.function(method, &mut names);
+                    }
+                }
+            }
+        }
+
+        let mut function_names = Names::default();
+        for func in &iface.functions {
+            self.function(func, &mut function_names);
+        }
+
+        let mut event_names = Names::default();
+        for event in &iface.events {
+            self.event(event, &mut event_names);
+        }
+    }
+
+    fn event(&mut self, event: &Event, names: &mut Names) {
+        let span = event.span.as_ref();
+
+        self.ident(&event.id, "event", span);
+        self.reserved_member(&event.id, "event", span);
+        names.insert(self, &event.id.to_snake_case(), &event.id, span);
+
+        if event.docs.is_empty() {
+            self.report(
+                Lint::MissingDocs,
+                span,
+                format!("event {} is not documented", event.id),
+                Some("add a doc comment using `///`".to_string()),
+            );
+        }
+
+        let mut fields = Names::default();
+        for (ident, ty) in &event.payload {
+            self.ident(ident, "payload field", span);
+            self.reserved_member(ident, "payload field", span);
+            fields.insert(self, &ident.to_snake_case(), ident, span);
+            self.ty(ty, span);
+        }
+    }
+
+    fn function(&mut self, func: &Function, names: &mut Names) {
+        let span = func.span.as_ref();
+
+        self.ident(&func.id, "function", span);
+        self.reserved_member(&func.id, "function", span);
+        names.insert(self, &func.id.to_snake_case(), &func.id, span);
+
+        if func.docs.is_empty() {
+            self.report(
+                Lint::MissingDocs,
+                span,
+                format!("function {} is not documented", func.id),
+                Some("add a doc comment using `///`".to_string()),
+            );
+        }
+
+        let mut params = Names::default();
+        for (ident, ty) in &func.params {
+            self.ident(ident, "parameter", span);
+            self.reserved_member(ident, "parameter", span);
+            params.insert(self, &ident.to_snake_case(), ident, span);
+            self.ty(ty, span);
+        }
+
+        match &func.result {
+            Some(FunctionResult::Anon(ty)) => self.ty(ty, span),
+            Some(FunctionResult::Named(results)) => {
+                for (ident, ty) in results {
+                    self.ident(ident, "result", span);
+                    self.reserved_member(ident, "result", span);
+                    self.ty(ty, span);
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn ty(&mut self, ty: &Type, span: Option<&Span>) {
+        match ty {
+            Type::List(ty) | Type::Option(ty) => self.ty(ty, span),
+            Type::Tuple(types) => {
+                for ty in types {
+                    self.ty(ty, span);
+                }
+            }
+            Type::Result { ok, err } => {
+                if err.is_none() {
+                    self.report(
+                        Lint::ResultWithoutError,
+                        span,
+                        "result without an error type".to_string(),
+                        Some("add an error type, e.g. `result<T, string>`".to_string()),
+                    );
+                }
+
+                for ty in ok.iter().chain(err) {
+                    self.ty(ty, span);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn ident(&mut self, ident: &str, what: &str, span: Option<&Span>) {
+        let kebab = ident.to_kebab_case();
+
+        if kebab != ident {
+            self.report(
+                Lint::NonKebabCase,
+                span,
+                format!("{what} {ident} should be written in kebab-case"),
+                Some(format!("rename it to `{kebab}`")),
+            );
+        }
+    }
+
+    /// Checks names of fields, functions and parameters, which are snake_case in Rust and camelCase in JavaScript.
+    fn reserved_member(&mut self, ident: &str, what: &str, span: Option<&Span>) {
+        self.reserved(&ident.to_snake_case(), what, span);
+        self.reserved(&ident.to_lower_camel_case(), what, span);
+    }
+
+    fn reserved(&mut self, name: &str, what: &str, span: Option<&Span>) {
+        let languages: Vec<_> = [("Rust", RUST_KEYWORDS), ("JavaScript", JS_KEYWORDS)]
+            .into_iter()
+            .filter(|(_, keywords)| keywords.contains(&name))
+            .map(|(language, _)| language)
+            .collect();
+
+        if !languages.is_empty() {
+            self.report(
+                Lint::ReservedWord,
+                span,
+                format!(
+                    "{what} name `{name}` is a reserved word in {}",
+                    languages.join(" and ")
+                ),
+                Some("choose a different name".to_string()),
+            );
+        }
+    }
+}
+
+/// The names generated for the members of one namespace, used to detect collisions.
+#[derive(Default)]
+struct Names {
+    generated: HashMap<String, String>,
+}
+
+impl Names {
+    fn insert(&mut self, linter: &mut Linter, generated: &str, ident: &str, span: Option<&Span>) {
+        match self.generated.get(generated) {
+            Some(previous) if previous != ident => linter.report(
+                Lint::CaseCollision,
+                span,
+                format!("{ident} and {previous} both turn into `{generated}` in generated code"),
+                Some(format!(
+                    "rename {ident} so it doesn't clash with {previous}"
+                )),
+            ),
+            Some(_) => {}
+            None => {
+                self.generated
+                    .insert(generated.to_string(), ident.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        parse::{self, FromTokens},
+        typecheck::Resolver,
+    };
+
+    fn lints(source: &str, config: &Config) -> Vec<(Lint, String)> {
+        // resolving a package would already reject the colliding names some lints are tested with
+        let mut tokens = parse::Tokens::new(source);
+        let iface = parse::Interface::parse(&mut tokens).unwrap();
+        let (resolver, rest_data) = Resolver::new(source, iface);
+        let iface = resolver.resolve(rest_data).unwrap();
+
+        lint(&[iface], config)
+            .into_iter()
+            .map(|warning| (warning.lint, warning.message))
+            .collect()
+    }
+
+    #[test]
+    fn lints_() {
+        let source = "interface lints {
+            flags f { a, b }
+            record r { x: u32, X: u32 }
+            /// Documented
+            func fetch-data(%type: string) -> result<u8>
+            func g(f: f, r: r)
+        }";
+
+        assert_eq!(
+            lints(source, &Config::default()),
+            [
+                (
+                    Lint::NonKebabCase,
+                    "field X should be written in kebab-case".to_string()
+                ),
+                (
+                    Lint::CaseCollision,
+                    "X and x both turn into `x` in generated code".to_string()
+                ),
+                (
+                    Lint::ReservedWord,
+                    "parameter name `type` is a reserved word in Rust".to_string()
+                ),
+                (
+                    Lint::ResultWithoutError,
+                    "result without an error type".to_string()
+                ),
+                (
+                    Lint::MissingDocs,
+                    "function g is not documented".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn config() {
+        let source = "interface lints {
+            func g() -> result<u8>
+        }";
+
+        let config = Config::default()
+            .allow(Lint::MissingDocs)
+            .deny(Lint::ResultWithoutError);
+        let warnings = lint(
+            &[crate::parse_and_resolve_str(source, |_| false).unwrap()],
+            &config,
+        );
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].lint, Lint::ResultWithoutError);
+        assert_eq!(warnings[0].severity, Severity::Error);
+
+        assert_eq!("missing-docs".parse(), Ok(Lint::MissingDocs));
+        assert!("missing-doc"
+            .parse::<Lint>()
+            .unwrap_err()
+            .contains("did you mean missing-docs?"));
+    }
+}
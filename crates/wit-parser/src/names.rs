@@ -0,0 +1,296 @@
+//! Names of interface items in the generated code.
+//!
+//! Generators convert identifiers through these functions instead of changing the case themselves,
+//! so keywords are escaped the same way everywhere and the resolver can reject interfaces whose names
+//! would collide after conversion.
+
+use crate::{
+    lint::{JS_KEYWORDS, RUST_KEYWORDS},
+    Error, Function, FunctionKind, Interface, Span, TypeDefKind,
+};
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use std::collections::HashMap;
+
+/// Keywords that can't be used as raw identifiers and get a trailing underscore instead.
+const RUST_NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// The name of a type, variant case or enum case in Rust, e.g. `FooBar`.
+#[must_use]
+pub fn rust_type_name(ident: &str) -> String {
+    escape_rust(ident.to_upper_camel_case())
+}
+
+/// The name of a module, function, parameter or field in Rust, e.g. `foo_bar` or `r#type`.
+#[must_use]
+pub fn rust_ident(ident: &str) -> String {
+    escape_rust(ident.to_snake_case())
+}
+
+/// The name of a flag in Rust, e.g. `FOO_BAR`.
+#[must_use]
+pub fn rust_const_name(ident: &str) -> String {
+    ident.to_shouty_snake_case()
+}
+
+/// The name of a type, flag, variant case or enum case in JavaScript and TypeScript, e.g. `FooBar`.
+#[must_use]
+pub fn js_type_name(ident: &str) -> String {
+    ident.to_upper_camel_case()
+}
+
+/// The name of a function, parameter or field in JavaScript and TypeScript, e.g. `fooBar` or `class_`.
+#[must_use]
+pub fn js_ident(ident: &str) -> String {
+    let name = ident.to_lower_camel_case();
+
+    if JS_KEYWORDS.contains(&name.as_str()) {
+        name + "_"
+    } else {
+        name
+    }
+}
+
+/// The id a host gives a constructor or static function of a resource to define it next to the
+/// interface's functions, e.g. `new-file` or `file-open`.
+#[must_use]
+pub fn resource_function_id(resource: &str, func: &Function) -> String {
+    if func.kind == FunctionKind::Constructor {
+        format!("new-{resource}")
+    } else {
+        format!("{resource}-{}", func.id)
+    }
+}
+
+fn escape_rust(name: String) -> String {
+    if RUST_NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        name + "_"
+    } else if RUST_KEYWORDS.contains(&name.as_str()) {
+        format!("r#{name}")
+    } else {
+        name
+    }
+}
+
+type Mangle = fn(&str) -> String;
+
+const TYPE_NAMES: &[(&str, Mangle)] = &[("Rust", rust_type_name), ("JavaScript", js_type_name)];
+const IDENTS: &[(&str, Mangle)] = &[("Rust", rust_ident), ("JavaScript", js_ident)];
+const FLAGS: &[(&str, Mangle)] = &[("Rust", rust_const_name), ("JavaScript", js_type_name)];
+
+/// Checks that no two items of the same scope turn into the same name in any target language.
+///
+/// Returns the error along with the index of the source it points into.
+pub(crate) fn check_collisions(interface: &Interface) -> Result<(), (usize, Error)> {
+    let mut types = Scope::new(interface, TYPE_NAMES);
+
+    for (_, typedef) in &interface.typedefs {
+        let span = typedef.span.as_ref();
+        types.insert(&typedef.ident, span)?;
+
+        match &typedef.kind {
+            TypeDefKind::Record(fields) => {
+                let mut scope = Scope::new(interface, IDENTS);
+
+                for field in fields {
+                    scope.insert(&field.id, field.span.as_ref().or(span))?;
+                }
+            }
+            TypeDefKind::Flags(fields) => {
+                let mut scope = Scope::new(interface, FLAGS);
+
+                for field in fields {
+                    scope.insert(&field.id, field.span.as_ref().or(span))?;
+                }
+            }
+            TypeDefKind::Variant(cases) => {
+                let mut scope = Scope::new(interface, TYPE_NAMES);
+
+                for case in cases {
+                    scope.insert(&case.id, case.span.as_ref().or(span))?;
+                }
+            }
+            TypeDefKind::Enum(cases) => {
+                let mut scope = Scope::new(interface, TYPE_NAMES);
+
+                for case in cases {
+                    scope.insert(&case.id, case.span.as_ref().or(span))?;
+                }
+            }
+            TypeDefKind::Resource(methods) => {
+                check_functions(
+                    interface,
+                    methods.iter().map(|func| (func.id.as_str(), func)),
+                )?;
+            }
+            TypeDefKind::Alias(_) | TypeDefKind::Union(_) => {}
+        }
+    }
+
+    // hosts define the constructors and static functions of resources next to the interface's functions,
+    // they come first so a collision is reported at the freestanding function
+    let resource_functions: Vec<_> = interface
+        .typedefs
+        .iter()
+        .filter_map(|(_, typedef)| match &typedef.kind {
+            TypeDefKind::Resource(functions) => Some((typedef, functions)),
+            _ => None,
+        })
+        .flat_map(|(typedef, functions)| {
+            functions
+                .iter()
+                .filter(|func| !func.kind.is_method())
+                .map(|func| (resource_function_id(&typedef.ident, func), func))
+        })
+        .collect();
+
+    check_functions(
+        interface,
+        resource_functions
+            .iter()
+            .map(|(id, func)| (id.as_str(), *func))
+            .chain(
+                interface
+                    .functions
+                    .iter()
+                    .map(|func| (func.id.as_str(), func)),
+            ),
+    )?;
+
+    let mut events = Scope::new(interface, IDENTS);
+
+    for event in &interface.events {
+        let span = event.span.as_ref();
+        events.insert(&event.id, span)?;
+
+        let mut payload = Scope::new(interface, IDENTS);
+        for (ident, _) in &event.payload {
+            payload.insert(ident, span)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_functions<'a>(
+    interface: &'a Interface,
+    functions: impl IntoIterator<Item = (&'a str, &'a Function)>,
+) -> Result<(), (usize, Error)> {
+    let mut scope = Scope::new(interface, IDENTS);
+
+    for (id, func) in functions {
+        let span = func.span.as_ref();
+        scope.insert(id, span)?;
+
+        let mut params = Scope::new(interface, IDENTS);
+        for (ident, _) in &func.params {
+            params.insert(ident, span)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The names generated for the members of one scope, per language.
+struct Scope<'a> {
+    interface: &'a Interface,
+    languages: &'static [(&'static str, Mangle)],
+    names: HashMap<(&'static str, String), &'a str>,
+}
+
+impl<'a> Scope<'a> {
+    fn new(interface: &'a Interface, languages: &'static [(&'static str, Mangle)]) -> Self {
+        Self {
+            interface,
+            languages,
+            names: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, ident: &'a str, span: Option<&Span>) -> Result<(), (usize, Error)> {
+        for (language, mangle) in self.languages {
+            let name = mangle(ident);
+
+            match self.names.get(&(*language, name.clone())) {
+                Some(previous) => {
+                    let (source, location) = span
+                        .or(self.interface.span.as_ref())
+                        .map_or((0, 0..0), |span| (span.source, span.range.clone()));
+
+                    return Err((
+                        source,
+                        Error::name_collision(location, ident, *previous, name, language),
+                    ));
+                }
+                None => {
+                    self.names.insert((*language, name), ident);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escape() {
+        assert_eq!(rust_ident("type"), "r#type");
+        assert_eq!(rust_ident("self"), "self_");
+        assert_eq!(rust_ident("foo-bar"), "foo_bar");
+        assert_eq!(rust_type_name("self"), "Self_");
+        assert_eq!(rust_type_name("type"), "Type");
+        assert_eq!(rust_const_name("foo-bar"), "FOO_BAR");
+        assert_eq!(js_ident("class"), "class_");
+        assert_eq!(js_ident("type"), "type");
+        assert_eq!(js_ident("foo-bar"), "fooBar");
+        assert_eq!(js_type_name("foo-bar"), "FooBar");
+    }
+
+    fn check(source: &str) -> miette::Result<()> {
+        crate::parse_and_resolve_str(source, |_| false).map(|_| ())
+    }
+
+    #[test]
+    fn collisions() {
+        assert!(check("interface a { func foo-bar(a: u8, b: u8) }").is_ok());
+
+        let err = check("interface a { func foo-bar() func foo_bar() }").unwrap_err();
+        assert_eq!(
+            err.code().map(|code| code.to_string()).as_deref(),
+            Some("wit_parser::name_collision")
+        );
+        assert!(err.to_string().contains("foo_bar and foo-bar"));
+        assert!(err.to_string().contains("`foo_bar`"));
+
+        assert!(check("interface a { func f(x-y: u8, x_y: u8) }").is_err());
+        assert!(
+            check("interface a { record r { foo-bar: u8, foo_bar: u8 } func f(r: r) }").is_err()
+        );
+        assert!(check("interface a { enum e { a-b, a_b } func f(e: e) }").is_err());
+    }
+
+    #[test]
+    fn resource_function_collisions() {
+        assert!(check(
+            "interface a { resource r { constructor() static func f() } func g() -> r }"
+        )
+        .is_ok());
+
+        let err =
+            check("interface a { resource r { constructor() } func new-r() -> r }").unwrap_err();
+        assert!(err.to_string().contains("new-r"));
+        assert!(
+            check("interface a { resource r { static func f() } func r-f() func g() -> r }")
+                .is_err()
+        );
+        assert!(
+            check("interface a { resource r { static func f() } func r_f() func g() -> r }")
+                .is_err()
+        );
+        // methods are called on the resource, not defined next to the interface's functions
+        assert!(check("interface a { resource r { func f() } func r-f() func g() -> r }").is_ok());
+    }
+}
@@ -0,0 +1,356 @@
+use crate::{
+    names, parse,
+    typecheck::Resolver,
+    util::{find_similar, print_list},
+    Document, Error, Interface, SourceMap, Span, World,
+};
+use miette::{IntoDiagnostic, NamedSource};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// A file that contributes interfaces to a package.
+struct Source {
+    name: String,
+    contents: String,
+}
+
+/// A parsed but not yet resolved interface that is part of a package.
+struct PendingInterface {
+    source: usize,
+    iface: parse::Interface,
+}
+
+/// All the interfaces defined by a document and, optionally, the other `*.wit` files in the same directory.
+///
+/// Interfaces can import types from every other interface in the same package through `use` items.
+#[derive(Default)]
+pub(crate) struct Package {
+    sources: Vec<Source>,
+    pending: HashMap<String, PendingInterface>,
+    resolved: HashMap<String, Interface>,
+    /// The files of the package that haven't been parsed yet, they are only loaded when an interface is missing.
+    unloaded: Vec<PathBuf>,
+    /// The syntax errors of files that were loaded while looking for an interface.
+    syntax_errors: Vec<miette::Report>,
+}
+
+impl Package {
+    /// Adds the interfaces defined by a document to the package and returns the index of its source.
+    pub fn add_document(
+        &mut self,
+        name: String,
+        contents: String,
+        interfaces: Vec<parse::Interface>,
+    ) -> miette::Result<usize> {
+        let source = self.sources.len();
+
+        for iface in interfaces {
+            let ident = contents[iface.ident.clone()]
+                .trim_start_matches('%')
+                .to_string();
+
+            if let Some(previous) = self.pending.get(&ident) {
+                if previous.source == source {
+                    let err = Error::already_defined(iface.ident, previous.iface.ident.clone());
+
+                    return Err(miette::Report::from(err)
+                        .with_source_code(NamedSource::new(name, contents)));
+                }
+
+                miette::bail!(
+                    "interface {ident} is defined in both {} and {name}",
+                    self.sources[previous.source].name,
+                );
+            }
+
+            self.pending
+                .insert(ident, PendingInterface { source, iface });
+        }
+
+        self.sources.push(Source { name, contents });
+
+        Ok(source)
+    }
+
+    /// Adds every `*.wit` file in `dir`, except for `exclude`, to the package without parsing it yet.
+    pub fn add_dir(&mut self, dir: &Path, exclude: &Path) -> miette::Result<()> {
+        let exclude = exclude.canonicalize().into_diagnostic()?;
+
+        let mut paths = std::fs::read_dir(dir)
+            .into_diagnostic()?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()
+            .into_diagnostic()?;
+        // keep error reporting stable across platforms
+        paths.sort();
+
+        for path in paths {
+            if path.extension().map_or(true, |ext| ext != "wit")
+                || path.canonicalize().into_diagnostic()? == exclude
+            {
+                continue;
+            }
+
+            self.unloaded.push(path);
+        }
+
+        Ok(())
+    }
+
+    /// Parses the files of the package until one of them defines the interface `ident`, starting with `<ident>.wit`.
+    ///
+    /// Files with syntax errors are skipped, their errors are only reported when no other file defines `ident`.
+    fn load(&mut self, ident: &str) -> miette::Result<()> {
+        if self.pending.contains_key(ident) || self.resolved.contains_key(ident) {
+            return Ok(());
+        }
+
+        if let Some(i) = self
+            .unloaded
+            .iter()
+            .position(|path| path.file_stem().is_some_and(|stem| stem == ident))
+        {
+            let path = self.unloaded.remove(i);
+            self.unloaded.insert(0, path);
+        }
+
+        while !self.pending.contains_key(ident) && !self.unloaded.is_empty() {
+            let path = self.unloaded.remove(0);
+            let name = path.to_string_lossy().to_string();
+            let contents = std::fs::read_to_string(&path).into_diagnostic()?;
+
+            match crate::parse(&contents) {
+                Ok(document) => {
+                    self.add_document(name, contents, document.interfaces)?;
+                }
+                Err(error) => self
+                    .syntax_errors
+                    .push(error.with_source_code(NamedSource::new(name, contents))),
+            }
+        }
+
+        if !self.pending.contains_key(ident) && !self.syntax_errors.is_empty() {
+            // the interface might be defined in one of the broken files
+            return Err(self.syntax_errors.remove(0));
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the given interfaces and worlds, along with all interfaces they depend on.
+    ///
+    /// `worlds` are defined in the source at index `source`.
+    pub fn resolve(
+        mut self,
+        roots: &[String],
+        source: usize,
+        worlds: Vec<parse::World>,
+        skip: &dyn Fn(&str) -> bool,
+    ) -> miette::Result<Document> {
+        // resolve everything the roots depend on first,
+        // this way roots other roots depend on are resolved as dependencies
+        for root in roots {
+            if let Some(pending) = self.pending.remove(root) {
+                self.resolve_dependencies(
+                    pending.source,
+                    &pending.iface,
+                    &mut vec![root.clone()],
+                    skip,
+                )?;
+                self.pending.insert(root.clone(), pending);
+            }
+        }
+
+        for root in roots {
+            if let Some(pending) = self.pending.remove(root) {
+                self.resolve_pending(pending, &mut vec![root.clone()], false, skip)?;
+            }
+        }
+
+        let interfaces: Vec<Interface> = roots
+            .iter()
+            .filter_map(|root| self.resolved.get(root).cloned())
+            .collect();
+
+        let worlds: Vec<World> = worlds
+            .into_iter()
+            .map(|world| self.resolve_world(source, world, skip))
+            .collect::<miette::Result<_>>()?;
+
+        for iface in interfaces
+            .iter()
+            .chain(worlds.iter().flat_map(|world| &world.imports))
+        {
+            names::check_collisions(iface)
+                .map_err(|(source, err)| self.with_source(source, err.into()))?;
+        }
+
+        let source_map = SourceMap::new(
+            self.sources
+                .into_iter()
+                .map(|source| (source.name, source.contents)),
+        );
+
+        let mut document = Document { interfaces, worlds };
+        for iface in document.interfaces.iter_mut().chain(
+            document
+                .worlds
+                .iter_mut()
+                .flat_map(|world| &mut world.imports),
+        ) {
+            iface.source_map = source_map.clone();
+        }
+
+        Ok(document)
+    }
+
+    fn resolve_world(
+        &mut self,
+        source: usize,
+        world: parse::World,
+        skip: &dyn Fn(&str) -> bool,
+    ) -> miette::Result<World> {
+        let contents = &self.sources[source].contents;
+
+        let docs = resolve_docs(contents, &world.docs);
+        let span = Span {
+            source,
+            range: world.ident.clone(),
+        };
+        let ident = contents[world.ident.clone()]
+            .trim_start_matches('%')
+            .to_string();
+
+        let mut spans: HashMap<String, logos::Span> = HashMap::new();
+        let mut imports = Vec::with_capacity(world.items.len());
+        for item in world.items {
+            let contents = &self.sources[source].contents;
+            let name = contents[item.ident.clone()]
+                .trim_start_matches('%')
+                .to_string();
+
+            if let Some(previous) = spans.insert(name.clone(), item.ident.clone()) {
+                let err = Error::already_defined(item.ident, previous);
+
+                return Err(self.with_source(source, err.into()));
+            }
+
+            self.load(&name)?;
+
+            if let Some(pending) = self.pending.remove(&name) {
+                self.resolve_pending(pending, &mut vec![name.clone()], true, skip)?;
+            }
+
+            let Some(iface) = self.resolved.get(&name) else {
+                let suggestions = find_similar(self.resolved.keys(), &name);
+
+                let err = if suggestions.is_empty() {
+                    Error::not_defined(item.ident)
+                } else {
+                    Error::not_defined_with_help(
+                        item.ident,
+                        format!("Did you mean \"{}\"?", print_list(suggestions)),
+                    )
+                };
+
+                return Err(self.with_source(source, err.into()));
+            };
+
+            imports.push(iface.clone());
+        }
+
+        Ok(World {
+            docs,
+            ident,
+            imports,
+            span: Some(span),
+        })
+    }
+
+    fn resolve_dependencies(
+        &mut self,
+        source: usize,
+        iface: &parse::Interface,
+        stack: &mut Vec<String>,
+        skip: &dyn Fn(&str) -> bool,
+    ) -> miette::Result<()> {
+        for span in iface.dependencies() {
+            let ident = self.sources[source].contents[span.clone()]
+                .trim_start_matches('%')
+                .to_string();
+
+            if stack.contains(&ident) {
+                let err = Error::cyclic_dependency(span.clone(), ident);
+
+                return Err(self.with_source(source, err.into()));
+            }
+
+            self.load(&ident)?;
+
+            // interfaces that are not part of the package are reported by the resolver
+            let Some(pending) = self.pending.remove(&ident) else {
+                continue;
+            };
+
+            stack.push(ident);
+            self.resolve_pending(pending, stack, true, skip)?;
+            stack.pop();
+        }
+
+        Ok(())
+    }
+
+    /// Resolves an interface after all of its dependencies.
+    ///
+    /// Interfaces other interfaces depend on are resolved with [`Resolver::resolve_as_dependency`].
+    fn resolve_pending(
+        &mut self,
+        pending: PendingInterface,
+        stack: &mut Vec<String>,
+        as_dependency: bool,
+        skip: &dyn Fn(&str) -> bool,
+    ) -> miette::Result<()> {
+        let PendingInterface { source, iface } = pending;
+
+        self.resolve_dependencies(source, &iface, stack, skip)?;
+
+        let contents = &self.sources[source].contents;
+        let (resolver, rest_data) = Resolver::new(contents, iface);
+        let resolver = resolver
+            .with_source_id(source)
+            .with_dependencies(self.resolved.values())
+            .with_skip(skip);
+
+        let res = if as_dependency {
+            resolver.resolve_as_dependency(rest_data)
+        } else {
+            resolver.resolve(rest_data)
+        };
+
+        let iface = res.map_err(|err| self.with_source(source, err.into()))?;
+        self.resolved.insert(iface.ident.clone(), iface);
+
+        Ok(())
+    }
+
+    fn with_source(&self, source: usize, error: miette::Report) -> miette::Report {
+        let source = &self.sources[source];
+
+        error.with_source_code(NamedSource::new(&source.name, source.contents.clone()))
+    }
+}
+
+fn resolve_docs(source: &str, docs: &[logos::Span]) -> String {
+    docs.iter()
+        .map(|span| {
+            let str = &source[span.clone()];
+            let str = str.strip_prefix("///").unwrap_or(str);
+            let str = str.strip_prefix("/**").unwrap_or(str);
+
+            str.trim()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
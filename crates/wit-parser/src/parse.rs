@@ -1,14 +1,98 @@
 #![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
 
 use crate::{
-    lex::Token,
+    lex::{self, Token},
     util::{find_similar, print_list},
-    Error, Result,
+    Error, FunctionKind, Result,
 };
-use logos::{Span, SpannedIter};
+use logos::{Logos, Span, SpannedIter};
 use std::iter::Peekable;
 
-pub type Tokens<'a> = Peekable<SpannedIter<'a, Token>>;
+/// The token stream the parser consumes.
+///
+/// Besides the tokens themselves this keeps track of how deeply nested in braces the parser currently is,
+/// which is used to resync after syntax errors, and collects the errors the parser recovered from.
+pub struct Tokens<'a> {
+    inner: Peekable<SpannedIter<'a, Token>>,
+    depth: usize,
+    consumed: usize,
+    errors: Vec<Error>,
+}
+
+impl<'a> Tokens<'a> {
+    #[must_use]
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            inner: Token::lexer(source).spanned().peekable(),
+            depth: 0,
+            consumed: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn peek(&mut self) -> Option<&(std::result::Result<Token, lex::Error>, Span)> {
+        self.inner.peek()
+    }
+
+    fn next_if(
+        &mut self,
+        func: impl FnOnce(&(std::result::Result<Token, lex::Error>, Span)) -> bool,
+    ) -> Option<(std::result::Result<Token, lex::Error>, Span)> {
+        if func(self.peek()?) {
+            self.next()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the syntax errors the parser recovered from so far.
+    pub fn take_errors(&mut self) -> Vec<Error> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Records a syntax error and skips ahead to the next item that can be parsed.
+    ///
+    /// Tokens are skipped until one of `sync` is found at nesting `depth`, or the block at `depth` is closed.
+    fn recover(&mut self, err: Error, depth: usize, sync: &[Token], start: usize) {
+        log::trace!("recovering from syntax error {err:?}");
+        self.errors.push(err);
+
+        // make sure we always make progress, otherwise we would report the same error forever
+        if self.consumed == start {
+            self.next();
+        }
+
+        while self.depth >= depth {
+            let at_depth = self.depth == depth;
+
+            match self.inner.peek() {
+                None => break,
+                Some((Ok(token), _)) if at_depth && sync.contains(token) => break,
+                Some((Ok(Token::RightBrace), _)) if at_depth && depth > 0 => break,
+                _ => {
+                    self.next();
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = (std::result::Result<Token, lex::Error>, Span);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+
+        match item.0 {
+            Ok(Token::LeftBrace) => self.depth += 1,
+            Ok(Token::RightBrace) => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+        self.consumed += 1;
+
+        Some(item)
+    }
+}
 
 trait TokensExt {
     fn next_if_token(&mut self, token: Token) -> Result<Option<(Token, Span)>>;
@@ -40,6 +124,26 @@ where
     fn parse(tokens: &mut Tokens<'a>) -> Result<Self>;
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Document {
+    pub interfaces: Vec<Interface>,
+    pub worlds: Vec<World>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct World {
+    pub ident: Span,
+    pub docs: Vec<Span>,
+    pub items: Vec<WorldItem>,
+}
+
+/// An `import` item, the item's `ident` is the imported interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorldItem {
+    pub docs: Vec<Span>,
+    pub ident: Span,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Interface {
     pub ident: Span,
@@ -63,7 +167,25 @@ pub enum InterfaceItemInner {
     Enum(Vec<EnumCase>),
     Union(Vec<UnionCase>),
     Func(Func),
+    /// An event the host emits to the guest, with its payload.
+    Event(NamedTypeList),
     Resource(Vec<Method>),
+    /// A `use` item, the item's `ident` is the interface the names are imported from.
+    Use(Vec<UseName>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UseName {
+    pub ident: Span,
+    pub alias: Option<Span>,
+}
+
+impl UseName {
+    /// The span of the name this item is available under in the importing interface.
+    #[must_use]
+    pub fn local(&self) -> &Span {
+        self.alias.as_ref().unwrap_or(&self.ident)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -109,13 +231,17 @@ pub struct EnumCase {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnionCase {
     pub docs: Vec<Span>,
+    /// The span of the first token of the case's type.
+    pub span: Span,
     pub ty: Type,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Method {
+    /// The method's name, or the `constructor` keyword.
     pub ident: Span,
     pub docs: Vec<Span>,
+    pub kind: FunctionKind,
     pub inner: Func,
 }
 
@@ -143,25 +269,158 @@ pub enum Type {
         ok: Option<Box<Type>>,
         err: Option<Box<Type>>,
     },
+    Own(Span),
+    Borrow(Span),
     Id(Span),
 }
 
+impl<'a> FromTokens<'a> for Document {
+    fn parse(tokens: &mut Tokens<'a>) -> Result<Self> {
+        let mut document = Document::default();
+
+        while tokens.peek().is_some() {
+            let start = tokens.consumed;
+
+            if let Err(err) = parse_document_item(tokens, &mut document) {
+                tokens.recover(err, 0, &Token::DOCUMENT_ITEM_KEYWORD, start);
+            }
+        }
+
+        log::debug!("finished parsing document");
+
+        Ok(document)
+    }
+}
+
+fn parse_document_item(tokens: &mut Tokens<'_>, document: &mut Document) -> Result<()> {
+    let docs = parse_docs(tokens);
+
+    match tokens.next().ok_or(Error::UnexpectedEof)? {
+        (Ok(Token::Interface), _) => {
+            document
+                .interfaces
+                .push(Interface::parse_rest(tokens, docs)?);
+        }
+        (Ok(Token::World), _) => document.worlds.push(World::parse_rest(tokens, docs)?),
+        (Ok(found), span) => {
+            return Err(Error::unexpected_token(
+                span,
+                Token::DOCUMENT_ITEM_KEYWORD,
+                found,
+            ))
+        }
+        (Err(err), _) => return Err(Error::Lex(err)),
+    }
+
+    Ok(())
+}
+
+impl Document {
+    /// Returns the spans of all interfaces that are referenced, but not defined, by this document.
+    pub fn external_references<'s>(&'s self, source: &'s str) -> impl Iterator<Item = &'s Span> {
+        let defined: Vec<_> = self
+            .interfaces
+            .iter()
+            .map(|iface| source[iface.ident.clone()].trim_start_matches('%'))
+            .collect();
+
+        self.interfaces
+            .iter()
+            .flat_map(Interface::dependencies)
+            .chain(
+                self.worlds
+                    .iter()
+                    .flat_map(|world| world.items.iter().map(|item| &item.ident)),
+            )
+            .filter(move |span| !defined.contains(&source[(*span).clone()].trim_start_matches('%')))
+    }
+}
+
+impl World {
+    fn parse_rest(tokens: &mut Tokens<'_>, docs: Vec<Span>) -> Result<Self> {
+        let (_, ident) = tokens.expect(Token::Ident)?;
+
+        log::trace!("parsing world items...");
+
+        let items = parse_list(tokens, Token::LeftBrace, Token::RightBrace, None)?;
+
+        log::debug!("successfully parsed world");
+
+        Ok(World { ident, docs, items })
+    }
+}
+
+impl<'a> FromTokens<'a> for WorldItem {
+    fn parse(tokens: &mut Tokens<'a>) -> Result<Self> {
+        let docs = parse_docs(tokens);
+
+        tokens.expect(Token::Import)?;
+
+        let (_, ident) = tokens.expect(Token::Ident)?;
+
+        Ok(WorldItem { docs, ident })
+    }
+}
+
 impl<'a> FromTokens<'a> for Interface {
     fn parse(tokens: &mut Tokens<'a>) -> Result<Self> {
         let docs = parse_docs(tokens);
 
         tokens.expect(Token::Interface)?;
 
+        Self::parse_rest(tokens, docs)
+    }
+}
+
+impl Interface {
+    fn parse_rest(tokens: &mut Tokens<'_>, docs: Vec<Span>) -> Result<Self> {
         let (_, ident) = tokens.expect(Token::Ident)?;
 
         log::trace!("parsing interface items...");
 
-        let items = parse_list(tokens, Token::LeftBrace, Token::RightBrace, None)?;
+        tokens.expect(Token::LeftBrace)?;
+        let depth = tokens.depth;
 
-        log::debug!("successfully parsed interface");
+        // items are parsed one by one so we can recover from errors in individual items,
+        // this way all syntax errors are reported at once.
+        let mut items = Vec::new();
+        loop {
+            if tokens.next_if_token(Token::RightBrace)?.is_some() {
+                break;
+            }
+
+            if tokens.peek().is_none() {
+                tokens.errors.push(Error::UnexpectedEof);
+                break;
+            }
+
+            let start = tokens.consumed;
+            match InterfaceItem::parse(tokens) {
+                Ok(item) => items.push(item),
+                Err(err) => tokens.recover(err, depth, &Token::IFACE_ITEM_KEYWORD, start),
+            }
+
+            // the error consumed the closing brace of the interface
+            if tokens.depth < depth {
+                break;
+            }
+        }
+
+        log::debug!("finished parsing interface");
 
         Ok(Interface { ident, docs, items })
     }
+
+    /// Returns the spans of all interfaces this interface imports names from.
+    pub fn dependencies(&self) -> impl Iterator<Item = &Span> {
+        self.items.iter().filter_map(|item| {
+            if let InterfaceItemInner::Use(_) = item.inner {
+                Some(&item.ident)
+            } else {
+                None
+            }
+        })
+    }
 }
 
 impl<'a> FromTokens<'a> for InterfaceItem {
@@ -169,10 +428,30 @@ impl<'a> FromTokens<'a> for InterfaceItem {
         let docs = parse_docs(tokens);
 
         let (kind, kind_span) = tokens.next().ok_or(Error::UnexpectedEof)?;
+        let kind = kind?;
+
+        if kind == Token::Use {
+            let names = parse_list(
+                tokens,
+                Token::LeftBrace,
+                Token::RightBrace,
+                Some(Token::Comma),
+            )?;
+
+            tokens.expect(Token::From)?;
+
+            let (_, ident) = tokens.expect(Token::Ident)?;
+
+            return Ok(InterfaceItem {
+                docs,
+                ident,
+                inner: InterfaceItemInner::Use(names),
+            });
+        }
 
         let (_, ident) = tokens.expect(Token::Ident)?;
 
-        let inner = match kind? {
+        let inner = match kind {
             Token::Record => {
                 let inner = parse_list(
                     tokens,
@@ -233,12 +512,16 @@ impl<'a> FromTokens<'a> for InterfaceItem {
 
                 InterfaceItemInner::Func(inner)
             }
+            Token::Event => {
+                let inner = NamedTypeList::parse(tokens)?;
+
+                InterfaceItemInner::Event(inner)
+            }
             Token::Resource => {
                 let inner = parse_list(tokens, Token::LeftBrace, Token::RightBrace, None)?;
 
                 InterfaceItemInner::Resource(inner)
             }
-            Token::Use => todo!(),
             found => {
                 let suggestions = find_similar(
                     Token::IFACE_ITEM_KEYWORD.iter().map(ToString::to_string),
@@ -265,6 +548,22 @@ impl<'a> FromTokens<'a> for InterfaceItem {
     }
 }
 
+impl<'a> FromTokens<'a> for UseName {
+    fn parse(tokens: &mut Tokens<'a>) -> Result<Self> {
+        let (_, ident) = tokens.expect(Token::Ident)?;
+
+        let alias = if tokens.next_if_token(Token::As)?.is_some() {
+            let (_, alias) = tokens.expect(Token::Ident)?;
+
+            Some(alias)
+        } else {
+            None
+        };
+
+        Ok(UseName { ident, alias })
+    }
+}
+
 impl<'a> FromTokens<'a> for Func {
     fn parse(tokens: &mut Tokens<'a>) -> Result<Self> {
         let params = NamedTypeList::parse(tokens)?;
@@ -369,9 +668,14 @@ impl<'a> FromTokens<'a> for UnionCase {
     fn parse(tokens: &mut Tokens<'a>) -> Result<Self> {
         let docs = parse_docs(tokens);
 
+        let span = tokens
+            .peek()
+            .map(|(_, span)| span.clone())
+            .ok_or(Error::UnexpectedEof)?;
+
         let ty = Type::parse(tokens)?;
 
-        Ok(UnionCase { docs, ty })
+        Ok(UnionCase { docs, span, ty })
     }
 }
 
@@ -379,13 +683,47 @@ impl<'a> FromTokens<'a> for Method {
     fn parse(tokens: &mut Tokens<'a>) -> Result<Self> {
         let docs = parse_docs(tokens);
 
-        tokens.expect(Token::Func)?;
+        let (token, span) = tokens.next().ok_or(Error::UnexpectedEof)?;
+
+        let kind = match token? {
+            Token::Func => FunctionKind::Method,
+            Token::Static => {
+                tokens.expect(Token::Func)?;
+
+                FunctionKind::Static
+            }
+            Token::Constructor => {
+                let params = NamedTypeList::parse(tokens)?;
+
+                return Ok(Method {
+                    ident: span,
+                    docs,
+                    kind: FunctionKind::Constructor,
+                    inner: Func {
+                        params,
+                        result: None,
+                    },
+                });
+            }
+            found => {
+                return Err(Error::unexpected_token(
+                    span,
+                    [Token::Func, Token::Static, Token::Constructor],
+                    found,
+                ))
+            }
+        };
 
         let (_, ident) = tokens.expect(Token::Ident)?;
 
         let inner = Func::parse(tokens)?;
 
-        Ok(Method { ident, docs, inner })
+        Ok(Method {
+            ident,
+            docs,
+            kind,
+            inner,
+        })
     }
 }
 
@@ -453,6 +791,20 @@ impl<'a> FromTokens<'a> for Type {
 
                 Ok(Self::Result { ok, err })
             }
+            Token::Own => {
+                tokens.expect(Token::LessThan)?;
+                let (_, span) = tokens.expect(Token::Ident)?;
+                tokens.expect(Token::GreaterThan)?;
+
+                Ok(Self::Own(span))
+            }
+            Token::Borrow => {
+                tokens.expect(Token::LessThan)?;
+                let (_, span) = tokens.expect(Token::Ident)?;
+                tokens.expect(Token::GreaterThan)?;
+
+                Ok(Self::Borrow(span))
+            }
             Token::Ident => Ok(Self::Id(span)),
             found => Err(Error::unexpected_token(span, Token::TYPE_KEYWORD, found)),
         }
@@ -507,12 +859,11 @@ fn parse_docs(tokens: &mut Tokens) -> Vec<Span> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use logos::Lexer;
     use pretty_assertions::assert_eq;
 
     #[test]
     fn feature() -> Result<()> {
-        let mut tokens = Lexer::new("record foo {}").spanned().peekable();
+        let mut tokens = Tokens::new("record foo {}");
 
         let ty = InterfaceItem::parse(&mut tokens)?;
 
@@ -523,7 +874,7 @@ mod test {
 
     #[test]
     fn option() -> Result<()> {
-        let mut tokens = Lexer::new("option<u8>").spanned().peekable();
+        let mut tokens = Tokens::new("option<u8>");
 
         let ty = Type::parse(&mut tokens)?;
 
@@ -532,9 +883,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn handles() -> Result<()> {
+        let mut tokens = Tokens::new("tuple<own<a>, borrow<a>>");
+
+        let ty = Type::parse(&mut tokens)?;
+
+        assert_eq!(
+            ty,
+            Type::Tuple(vec![Type::Own(10..11), Type::Borrow(21..22)])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn result() -> Result<()> {
-        let mut tokens = Lexer::new("result<u8, string>").spanned().peekable();
+        let mut tokens = Tokens::new("result<u8, string>");
 
         let ty = Type::parse(&mut tokens)?;
 
@@ -551,7 +916,7 @@ mod test {
 
     #[test]
     fn result2() -> Result<()> {
-        let mut tokens = Lexer::new("result<_, string>").spanned().peekable();
+        let mut tokens = Tokens::new("result<_, string>");
 
         let ty = Type::parse(&mut tokens)?;
 
@@ -568,9 +933,7 @@ mod test {
 
     #[test]
     fn typedef_() -> Result<()> {
-        let mut tokens = Lexer::new("type foo = result<u8, string>")
-            .spanned()
-            .peekable();
+        let mut tokens = Tokens::new("type foo = result<u8, string>");
 
         let ty = InterfaceItem::parse(&mut tokens)?;
 
@@ -587,7 +950,7 @@ mod test {
 
     #[test]
     fn enum__() -> Result<()> {
-        let mut tokens = Lexer::new(
+        let mut tokens = Tokens::new(
             "enum color {
             red,
             green,
@@ -596,9 +959,7 @@ mod test {
             other,
         }
         ",
-        )
-        .spanned()
-        .peekable();
+        );
 
         let _ty = InterfaceItem::parse(&mut tokens)?;
 
@@ -616,15 +977,13 @@ mod test {
 
     #[test]
     fn flags_() -> Result<()> {
-        let mut tokens = Lexer::new(
+        let mut tokens = Tokens::new(
             "flags properties {
             lego,
             marvel_superhero,
             supervillain,
         }",
-        )
-        .spanned()
-        .peekable();
+        );
 
         let _ty = InterfaceItem::parse(&mut tokens)?;
 
@@ -641,15 +1000,13 @@ mod test {
 
     #[test]
     fn union_() -> Result<()> {
-        let mut tokens = Lexer::new(
+        let mut tokens = Tokens::new(
             "union configuration {
             string,
             list<string>,
         }
         ",
-        )
-        .spanned()
-        .peekable();
+        );
 
         let _ty = InterfaceItem::parse(&mut tokens)?;
 
@@ -663,16 +1020,14 @@ mod test {
 
     #[test]
     fn variant_() -> Result<()> {
-        let mut tokens = Lexer::new(
+        let mut tokens = Tokens::new(
             "variant filter {
             all,
             none,
             some(list<string>),
         }
         ",
-        )
-        .spanned()
-        .peekable();
+        );
 
         let _ty = InterfaceItem::parse(&mut tokens)?;
 
@@ -689,18 +1044,112 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn use_() -> Result<()> {
+        let source = "use { a, b as c } from other-interface";
+        let mut tokens = Tokens::new(source);
+
+        let item = InterfaceItem::parse(&mut tokens)?;
+
+        assert_eq!(&source[item.ident], "other-interface");
+        assert_eq!(
+            item.inner,
+            InterfaceItemInner::Use(vec![
+                UseName {
+                    ident: 6..7,
+                    alias: None
+                },
+                UseName {
+                    ident: 9..10,
+                    alias: Some(14..15)
+                },
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn document() -> Result<()> {
+        let source = "interface a {}
+            interface b {}
+            /// The app
+            world app {
+                import a
+                import b
+            }";
+        let mut tokens = Tokens::new(source);
+
+        let document = Document::parse(&mut tokens)?;
+
+        assert_eq!(document.interfaces.len(), 2);
+        assert_eq!(document.worlds.len(), 1);
+
+        let world = &document.worlds[0];
+        assert_eq!(&source[world.ident.clone()], "app");
+        assert_eq!(
+            world
+                .items
+                .iter()
+                .map(|item| &source[item.ident.clone()])
+                .collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn recover() -> Result<()> {
+        let source = "interface foo {
+            func a(x: ) -> u32
+            record b {
+                x: u32,
+                y
+            }
+            resource c {
+                func d(
+            }
+            func f() -> string
+            fnc e()
+            record g {
+                x: u32
+            }
+        }
+        interface bar {
+            func h(x: u32 -> u32
+            func i()
+        }";
+        let mut tokens = Tokens::new(source);
+
+        let document = Document::parse(&mut tokens)?;
+        let errors = tokens.take_errors();
+
+        assert_eq!(errors.len(), 5, "{errors:#?}");
+
+        let idents = |iface: &Interface| {
+            iface
+                .items
+                .iter()
+                .map(|item| &source[item.ident.clone()])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(idents(&document.interfaces[0]), ["f", "g"]);
+        assert_eq!(idents(&document.interfaces[1]), ["i"]);
+
+        Ok(())
+    }
+
     #[test]
     fn interface_() -> Result<()> {
-        let mut tokens = Lexer::new(
+        let mut tokens = Tokens::new(
             "interface chars {
             /// A function that accepts a character
             func take_char(x: char)
             /// A function that returns a character
             func return_char() -> char
           }",
-        )
-        .spanned()
-        .peekable();
+        );
 
         let iface = Interface::parse(&mut tokens)?;
 
@@ -713,7 +1162,7 @@ mod test {
     fn full() -> Result<()> {
         let input = include_str!("test.wit");
 
-        let mut tokens = Lexer::new(input).spanned().peekable();
+        let mut tokens = Tokens::new(input);
 
         let iface = Interface::parse(&mut tokens)?;
 
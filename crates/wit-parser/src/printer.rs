@@ -0,0 +1,602 @@
+use crate::parse::{
+    Document, Func, FuncResult, Interface, InterfaceItem, InterfaceItemInner, NamedTypeList, Type,
+    World,
+};
+use crate::FunctionKind;
+use logos::Span;
+
+/// Signatures longer than this put every parameter on its own line.
+const MAX_WIDTH: usize = 100;
+const INDENT: &str = "  ";
+
+/// Formats a document in the canonical style.
+///
+/// Doc comments are kept as they are. Regular comments are kept too,
+/// but are moved onto their own line in front of the next item unless they trail a line.
+pub fn format(input: &str) -> miette::Result<String> {
+    let document = crate::parse(input)?;
+
+    let mut printer = Printer {
+        source: input,
+        comments: comments(input),
+        next_comment: 0,
+        out: String::new(),
+        indent: 0,
+    };
+    printer.document(&document);
+
+    Ok(printer.out)
+}
+
+/// A top-level item of a document.
+enum DocumentItem<'a> {
+    Interface(&'a Interface),
+    World(&'a World),
+}
+
+struct Printer<'a> {
+    source: &'a str,
+    /// The regular comments of the document, these aren't part of the syntax tree.
+    comments: Vec<Span>,
+    next_comment: usize,
+    out: String,
+    indent: usize,
+}
+
+impl<'a> Printer<'a> {
+    fn document(&mut self, document: &Document) {
+        let mut items: Vec<_> = document
+            .interfaces
+            .iter()
+            .map(|iface| {
+                (
+                    self.anchor(&iface.docs, &iface.ident),
+                    DocumentItem::Interface(iface),
+                )
+            })
+            .chain(document.worlds.iter().map(|world| {
+                (
+                    self.anchor(&world.docs, &world.ident),
+                    DocumentItem::World(world),
+                )
+            }))
+            .collect();
+        items.sort_by_key(|(anchor, _)| *anchor);
+
+        for (i, (anchor, item)) in items.iter().enumerate() {
+            if i > 0 {
+                self.blank_line();
+            }
+            self.comments_before(*anchor);
+            if self.blank_line_before(*anchor) {
+                self.blank_line();
+            }
+
+            let end = items
+                .get(i + 1)
+                .map_or(self.source.len(), |(anchor, _)| *anchor);
+
+            match item {
+                DocumentItem::Interface(iface) => self.interface(iface, end),
+                DocumentItem::World(world) => self.world(world, end),
+            }
+        }
+
+        self.comments_before(self.source.len());
+    }
+
+    fn interface(&mut self, iface: &Interface, end: usize) {
+        self.docs(&iface.docs);
+
+        let close = self.block_end(end);
+        let anchors: Vec<_> = iface
+            .items
+            .iter()
+            .map(|item| self.item_anchor(item))
+            .collect();
+
+        self.block(
+            &format!("interface {}", self.str(&iface.ident)),
+            close,
+            &anchors,
+            |this, i| {
+                let end = anchors.get(i + 1).copied().unwrap_or(close);
+                this.item(&iface.items[i], end);
+            },
+        );
+    }
+
+    fn world(&mut self, world: &World, end: usize) {
+        self.docs(&world.docs);
+
+        let close = self.block_end(end);
+        let anchors: Vec<_> = world
+            .items
+            .iter()
+            .map(|item| self.anchor(&item.docs, &item.ident))
+            .collect();
+
+        self.block(
+            &format!("world {}", self.str(&world.ident)),
+            close,
+            &anchors,
+            |this, i| {
+                let item = &world.items[i];
+
+                this.docs(&item.docs);
+                this.line(&format!("import {}", this.str(&item.ident)));
+            },
+        );
+    }
+
+    fn item(&mut self, item: &InterfaceItem, end: usize) {
+        self.docs(&item.docs);
+
+        let ident = self.str(&item.ident);
+
+        match &item.inner {
+            InterfaceItemInner::Alias(ty) => {
+                let line = format!("type {ident} = {}", self.ty(ty));
+                self.line(&line);
+            }
+            InterfaceItemInner::Record(fields) => {
+                let close = self.block_end(end);
+                let anchors: Vec<_> = fields
+                    .iter()
+                    .map(|field| self.anchor(&field.docs, &field.ident))
+                    .collect();
+
+                self.block(&format!("record {ident}"), close, &anchors, |this, i| {
+                    let field = &fields[i];
+
+                    this.docs(&field.docs);
+                    let line = format!("{}: {},", this.str(&field.ident), this.ty(&field.ty));
+                    this.line(&line);
+                });
+            }
+            InterfaceItemInner::Flags(fields) => {
+                let close = self.block_end(end);
+                let anchors: Vec<_> = fields
+                    .iter()
+                    .map(|field| self.anchor(&field.docs, &field.ident))
+                    .collect();
+
+                self.block(&format!("flags {ident}"), close, &anchors, |this, i| {
+                    let field = &fields[i];
+
+                    this.docs(&field.docs);
+                    this.line(&format!("{},", this.str(&field.ident)));
+                });
+            }
+            InterfaceItemInner::Variant(cases) => {
+                let close = self.block_end(end);
+                let anchors: Vec<_> = cases
+                    .iter()
+                    .map(|case| self.anchor(&case.docs, &case.ident))
+                    .collect();
+
+                self.block(&format!("variant {ident}"), close, &anchors, |this, i| {
+                    let case = &cases[i];
+
+                    this.docs(&case.docs);
+                    let line = match &case.ty {
+                        Some(ty) => format!("{}({}),", this.str(&case.ident), this.ty(ty)),
+                        None => format!("{},", this.str(&case.ident)),
+                    };
+                    this.line(&line);
+                });
+            }
+            InterfaceItemInner::Enum(cases) => {
+                let close = self.block_end(end);
+                let anchors: Vec<_> = cases
+                    .iter()
+                    .map(|case| self.anchor(&case.docs, &case.ident))
+                    .collect();
+
+                self.block(&format!("enum {ident}"), close, &anchors, |this, i| {
+                    let case = &cases[i];
+
+                    this.docs(&case.docs);
+                    this.line(&format!("{},", this.str(&case.ident)));
+                });
+            }
+            InterfaceItemInner::Union(cases) => {
+                let close = self.block_end(end);
+                let anchors: Vec<_> = cases
+                    .iter()
+                    .map(|case| case.docs.first().unwrap_or(&case.span).start)
+                    .collect();
+
+                self.block(&format!("union {ident}"), close, &anchors, |this, i| {
+                    let case = &cases[i];
+
+                    this.docs(&case.docs);
+                    let line = format!("{},", this.ty(&case.ty));
+                    this.line(&line);
+                });
+            }
+            InterfaceItemInner::Func(func) => self.func(&format!("func {ident}"), func),
+            InterfaceItemInner::Event(payload) => {
+                self.signature(&format!("event {ident}"), payload, "");
+            }
+            InterfaceItemInner::Resource(methods) => {
+                let close = self.block_end(end);
+                let anchors: Vec<_> = methods
+                    .iter()
+                    .map(|method| self.anchor(&method.docs, &method.ident))
+                    .collect();
+
+                self.block(&format!("resource {ident}"), close, &anchors, |this, i| {
+                    let method = &methods[i];
+
+                    this.docs(&method.docs);
+
+                    let ident = this.str(&method.ident);
+                    let head = match method.kind {
+                        FunctionKind::Constructor => ident.to_string(),
+                        FunctionKind::Static => format!("static func {ident}"),
+                        FunctionKind::Method | FunctionKind::Freestanding => {
+                            format!("func {ident}")
+                        }
+                    };
+                    this.func(&head, &method.inner);
+                });
+            }
+            InterfaceItemInner::Use(names) => {
+                let names: Vec<_> = names
+                    .iter()
+                    .map(|name| match &name.alias {
+                        Some(alias) => format!("{} as {}", self.str(&name.ident), self.str(alias)),
+                        None => self.str(&name.ident).to_string(),
+                    })
+                    .collect();
+
+                let line = if names.is_empty() {
+                    format!("use {{}} from {ident}")
+                } else {
+                    format!("use {{ {} }} from {ident}", names.join(", "))
+                };
+                self.line(&line);
+            }
+        }
+    }
+
+    /// Prints a function, `head` is everything in front of the parameters, e.g. `func name`.
+    fn func(&mut self, head: &str, func: &Func) {
+        let result = match &func.result {
+            Some(FuncResult::Anon(ty)) => format!(" -> {}", self.ty(ty)),
+            Some(FuncResult::Named(results)) => {
+                let results: Vec<_> = results
+                    .iter()
+                    .map(|(ident, ty)| format!("{}: {}", self.str(ident), self.ty(ty)))
+                    .collect();
+
+                format!(" -> ({})", results.join(", "))
+            }
+            None => String::new(),
+        };
+
+        self.signature(head, &func.params, &result);
+    }
+
+    /// Prints `head(params)result`, with one parameter per line if it doesn't fit on one.
+    fn signature(&mut self, head: &str, params: &NamedTypeList, result: &str) {
+        let params: Vec<_> = params
+            .iter()
+            .map(|(ident, ty)| format!("{}: {}", self.str(ident), self.ty(ty)))
+            .collect();
+
+        let line = format!("{head}({}){result}", params.join(", "));
+
+        if params.is_empty() || self.indent * INDENT.len() + line.len() <= MAX_WIDTH {
+            self.line(&line);
+        } else {
+            self.line(&format!("{head}("));
+            self.indent += 1;
+            for param in params {
+                self.line(&format!("{param},"));
+            }
+            self.indent -= 1;
+            self.line(&format!("){result}"));
+        }
+    }
+
+    fn ty(&self, ty: &Type) -> String {
+        match ty {
+            Type::Bool => "bool".to_string(),
+            Type::U8 => "u8".to_string(),
+            Type::U16 => "u16".to_string(),
+            Type::U32 => "u32".to_string(),
+            Type::U64 => "u64".to_string(),
+            Type::U128 => "u128".to_string(),
+            Type::S8 => "s8".to_string(),
+            Type::S16 => "s16".to_string(),
+            Type::S32 => "s32".to_string(),
+            Type::S64 => "s64".to_string(),
+            Type::S128 => "s128".to_string(),
+            Type::Float32 => "float32".to_string(),
+            Type::Float64 => "float64".to_string(),
+            Type::Char => "char".to_string(),
+            Type::String => "string".to_string(),
+            Type::List(ty) => format!("list<{}>", self.ty(ty)),
+            Type::Tuple(types) => {
+                let types: Vec<_> = types.iter().map(|ty| self.ty(ty)).collect();
+
+                format!("tuple<{}>", types.join(", "))
+            }
+            Type::Option(ty) => format!("option<{}>", self.ty(ty)),
+            Type::Result { ok, err } => match (ok, err) {
+                (None, None) => "result".to_string(),
+                (Some(ok), None) => format!("result<{}>", self.ty(ok)),
+                (None, Some(err)) => format!("result<_, {}>", self.ty(err)),
+                (Some(ok), Some(err)) => format!("result<{}, {}>", self.ty(ok), self.ty(err)),
+            },
+            Type::Own(span) => format!("own<{}>", self.str(span)),
+            Type::Borrow(span) => format!("borrow<{}>", self.str(span)),
+            Type::Id(span) => self.str(span).to_string(),
+        }
+    }
+
+    /// Prints `header` followed by a braced block of members.
+    ///
+    /// `close` is the offset of the closing brace and `anchors` hold the offsets members start at,
+    /// they're used to place the comments in between members.
+    fn block(
+        &mut self,
+        header: &str,
+        close: usize,
+        anchors: &[usize],
+        mut member: impl FnMut(&mut Self, usize),
+    ) {
+        let has_comments = self
+            .comments
+            .get(self.next_comment)
+            .is_some_and(|comment| comment.start < close);
+
+        if anchors.is_empty() && !has_comments {
+            self.line(&format!("{header} {{}}"));
+            return;
+        }
+
+        self.line(&format!("{header} {{"));
+        self.indent += 1;
+
+        let block_start = self.out.len();
+        for (i, anchor) in anchors.iter().enumerate() {
+            self.comments_before(*anchor);
+            if self.out.len() > block_start && self.blank_line_before(*anchor) {
+                self.blank_line();
+            }
+
+            member(self, i);
+        }
+        self.comments_before(close);
+
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    /// Prints all comments that start before `offset`.
+    fn comments_before(&mut self, offset: usize) {
+        while let Some(comment) = self.comments.get(self.next_comment).cloned() {
+            if comment.start >= offset {
+                break;
+            }
+            self.next_comment += 1;
+
+            let text = self.source[comment.clone()].trim_end();
+            let line_start = self.source[..comment.start]
+                .rfind('\n')
+                .map_or(0, |idx| idx + 1);
+            let trailing = !self.source[line_start..comment.start].trim().is_empty();
+
+            if trailing && self.out.ends_with('\n') {
+                self.out.pop();
+                self.out.push(' ');
+                self.out.push_str(text);
+                self.out.push('\n');
+            } else {
+                if self.blank_line_before(comment.start) && !self.at_block_start() {
+                    self.blank_line();
+                }
+                self.line(text);
+            }
+        }
+    }
+
+    fn docs(&mut self, docs: &[Span]) {
+        for span in docs {
+            let doc = self.source[span.clone()].trim_end();
+            self.line(doc);
+        }
+    }
+
+    fn line(&mut self, line: &str) {
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+        self.out.push_str(line);
+        self.out.push('\n');
+    }
+
+    fn blank_line(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn at_block_start(&self) -> bool {
+        self.out.is_empty() || self.out.trim_end().ends_with('{')
+    }
+
+    /// Whether there is an empty line right in front of `offset`.
+    fn blank_line_before(&self, offset: usize) -> bool {
+        let before = &self.source[..offset];
+        let whitespace = &before[before.trim_end().len()..];
+
+        whitespace.matches('\n').count() > 1
+    }
+
+    /// The offset an item with the given docs and identifier starts at.
+    ///
+    /// This is the first doc comment, or the keyword in front of the identifier.
+    fn anchor(&self, docs: &[Span], ident: &Span) -> usize {
+        docs.first()
+            .map_or_else(|| self.token_before(ident.start), |doc| doc.start)
+    }
+
+    fn item_anchor(&self, item: &InterfaceItem) -> usize {
+        if let Some(doc) = item.docs.first() {
+            return doc.start;
+        }
+
+        match &item.inner {
+            // `use { name } from ident`
+            InterfaceItemInner::Use(names) => match names.first() {
+                Some(name) => self.token_before(self.token_before(name.ident.start)),
+                None => {
+                    let mut offset = item.ident.start;
+                    for _ in 0..4 {
+                        offset = self.token_before(offset);
+                    }
+                    offset
+                }
+            },
+            _ => self.token_before(item.ident.start),
+        }
+    }
+
+    /// The start of the token in front of `offset`.
+    fn token_before(&self, offset: usize) -> usize {
+        let before = self.source[..offset].trim_end();
+        let is_ident = |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | '%');
+
+        match before.chars().next_back() {
+            Some(c) if is_ident(c) => before.trim_end_matches(is_ident).len(),
+            Some(c) => before.len() - c.len_utf8(),
+            None => 0,
+        }
+    }
+
+    /// The offset of the closing brace of the block that ends before `end`.
+    fn block_end(&self, end: usize) -> usize {
+        self.source[..end]
+            .rmatch_indices('}')
+            .map(|(idx, _)| idx)
+            .find(|idx| !self.comments.iter().any(|comment| comment.contains(idx)))
+            .unwrap_or(end)
+    }
+
+    fn str(&self, span: &Span) -> &'a str {
+        &self.source[span.clone()]
+    }
+}
+
+/// Finds the regular, non-doc, comments in `input`.
+fn comments(input: &str) -> Vec<Span> {
+    let bytes = input.as_bytes();
+    let mut comments = Vec::new();
+
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        match (bytes[i], bytes[i + 1]) {
+            (b'/', b'/') => {
+                let end = input[i..].find('\n').map_or(input.len(), |idx| i + idx);
+
+                if bytes.get(i + 2) != Some(&b'/') {
+                    comments.push(i..end);
+                }
+                i = end;
+            }
+            (b'/', b'*') => {
+                let start = i;
+                let mut depth = 0;
+
+                while i + 1 < bytes.len() {
+                    match (bytes[i], bytes[i + 1]) {
+                        (b'/', b'*') => {
+                            depth += 1;
+                            i += 2;
+                        }
+                        (b'*', b'/') => {
+                            depth -= 1;
+                            i += 2;
+
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => i += 1,
+                    }
+                }
+
+                if bytes.get(start + 2) != Some(&b'*') {
+                    comments.push(start..i);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    comments
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_() {
+        let input = r#"
+// the greeter
+interface   greet{
+  /// A greeting
+  record greeting{text:string,lang : option<string>}
+  use {a,b as c} from other
+
+
+  // say hi
+  func greet(name:string)->greeting  // hi!
+  func long-function-name(first-argument: list<tuple<u8, u16>>, second-argument: result<_, string>) -> (a: u8, b: result)
+  resource r {}
+  union u { u8, /** bigger */ u16 }
+  // the end
+}
+world w { import greet }
+"#;
+
+        let expected = r#"// the greeter
+interface greet {
+  /// A greeting
+  record greeting {
+    text: string,
+    lang: option<string>,
+  }
+  use { a, b as c } from other
+
+  // say hi
+  func greet(name: string) -> greeting // hi!
+  func long-function-name(
+    first-argument: list<tuple<u8, u16>>,
+    second-argument: result<_, string>,
+  ) -> (a: u8, b: result)
+  resource r {}
+  union u {
+    u8,
+    /** bigger */
+    u16,
+  }
+  // the end
+}
+
+world w {
+  import greet
+}
+"#;
+
+        let output = format(input).unwrap();
+        assert_eq!(output, expected);
+        assert_eq!(format(&output).unwrap(), expected);
+    }
+}
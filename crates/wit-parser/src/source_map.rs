@@ -0,0 +1,85 @@
+use miette::{NamedSource, SourceSpan};
+use std::{ops::Range, sync::Arc};
+
+/// A location in one of the files of a [`SourceMap`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Index of the file in the [`SourceMap`].
+    pub source: usize,
+    /// Byte range in the file.
+    pub range: Range<usize>,
+}
+
+impl From<&Span> for SourceSpan {
+    fn from(span: &Span) -> Self {
+        span.range.clone().into()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct SourceFile {
+    name: String,
+    contents: String,
+}
+
+/// The files an interface was resolved from.
+///
+/// This is a cheap to clone handle that lets downstream tools map [`Span`]s back to the `*.wit` file they point into.
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    files: Arc<Vec<SourceFile>>,
+}
+
+impl SourceMap {
+    pub(crate) fn new(files: impl IntoIterator<Item = (String, String)>) -> Self {
+        let files = files
+            .into_iter()
+            .map(|(name, contents)| SourceFile { name, contents })
+            .collect();
+
+        Self {
+            files: Arc::new(files),
+        }
+    }
+
+    /// The names of all files, in the order their [`Span::source`] indices refer to them.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.files.iter().map(|file| file.name.as_str())
+    }
+
+    /// The name of the file `span` points into.
+    #[must_use]
+    pub fn name(&self, span: &Span) -> Option<&str> {
+        self.files.get(span.source).map(|file| file.name.as_str())
+    }
+
+    /// The full contents of the file `span` points into.
+    #[must_use]
+    pub fn contents(&self, span: &Span) -> Option<&str> {
+        self.files
+            .get(span.source)
+            .map(|file| file.contents.as_str())
+    }
+
+    /// The source text `span` covers.
+    #[must_use]
+    pub fn snippet(&self, span: &Span) -> Option<&str> {
+        self.contents(span)?.get(span.range.clone())
+    }
+
+    /// The file `span` points into, ready to be attached to a [`miette::Diagnostic`].
+    #[must_use]
+    pub fn named_source(&self, span: &Span) -> Option<NamedSource<String>> {
+        let file = self.files.get(span.source)?;
+
+        Some(NamedSource::new(&file.name, file.contents.clone()))
+    }
+}
+
+impl PartialEq for SourceMap {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.files, &other.files) || self.files == other.files
+    }
+}
+
+impl Eq for SourceMap {}
@@ -3,8 +3,8 @@
 use crate::{
     lex,
     util::{find_similar, print_list, IteratorExt},
-    EnumCase, Error, FlagsField, Function, FunctionResult, Interface, RecordField, Result, Type,
-    TypeDef, TypeDefKind, UnionCase, VariantCase,
+    EnumCase, Error, Event, FlagsField, Function, FunctionKind, FunctionResult, Interface,
+    RecordField, Result, SourceMap, Type, TypeDef, TypeDefKind, UnionCase, VariantCase,
 };
 use id_arena::{Arena, Id};
 use logos::Span;
@@ -12,39 +12,98 @@ use std::collections::{HashMap, HashSet};
 
 use crate::parse;
 
+/// Names of the functions the generators add to resources: `drop` and `dispose` release a handle,
+/// `new` and `create` are the constructor in Rust and JavaScript guests.
+const RESERVED_RESOURCE_FUNCTIONS: &[&str] = &["drop", "new", "create", "dispose"];
+
 pub struct RestInterface {
     ident: Span,
     docs: Vec<Span>,
     functions: Vec<parse::InterfaceItem>,
 }
 
+/// A name brought into scope by a `use` item.
+struct UsedName {
+    /// The interface the name is imported from.
+    from: Span,
+    /// The name of the type in the interface it is imported from.
+    ident: Span,
+    /// The name the type is available under in the importing interface.
+    local: Span,
+}
+
 pub struct Resolver<'a> {
     source: &'a str,
     iface_typedefs: HashMap<&'a str, parse::InterfaceItem>,
+    iface_uses: HashMap<&'a str, UsedName>,
+    ident2span: HashMap<&'a str, Span>,
+    dependencies: HashMap<&'a str, &'a Interface>,
+    skip: Option<&'a dyn Fn(&str) -> bool>,
+    source_id: usize,
 
     ident2id: HashMap<&'a str, Id<TypeDef>>,
+    imported: HashMap<(&'a str, Id<TypeDef>), Id<TypeDef>>,
     typedefs: Arena<TypeDef>,
 }
 
 impl<'a> Resolver<'a> {
     #[must_use]
     pub fn new(source: &'a str, interface: parse::Interface) -> (Self, RestInterface) {
-        let (iface_funcs, iface_typedefs): (Vec<_>, Vec<_>) = interface
-            .items
+        let (iface_funcs, iface_typedefs): (Vec<_>, Vec<_>) =
+            interface.items.into_iter().partition(|item| {
+                matches!(
+                    item.inner,
+                    parse::InterfaceItemInner::Func(_) | parse::InterfaceItemInner::Event(_)
+                )
+            });
+
+        let (iface_uses, iface_typedefs): (Vec<_>, Vec<_>) = iface_typedefs
             .into_iter()
-            .partition(|item| matches!(item.inner, parse::InterfaceItemInner::Func(_)));
+            .partition(|item| matches!(item.inner, parse::InterfaceItemInner::Use(_)));
 
         let iface_typedefs: HashMap<_, _> = iface_typedefs
             .into_iter()
-            .map(|item| (&source[item.ident.clone()], item))
+            .map(|item| (source[item.ident.clone()].trim_start_matches('%'), item))
+            .collect();
+
+        let iface_uses: HashMap<_, _> = iface_uses
+            .into_iter()
+            .flat_map(|item| {
+                let parse::InterfaceItemInner::Use(names) = item.inner else {
+                    unreachable!()
+                };
+
+                names.into_iter().map(move |name| {
+                    let local = name.local().clone();
+
+                    let used = UsedName {
+                        from: item.ident.clone(),
+                        ident: name.ident,
+                        local: local.clone(),
+                    };
+
+                    (source[local].trim_start_matches('%'), used)
+                })
+            })
+            .collect();
+
+        let ident2span = iface_typedefs
+            .iter()
+            .map(|(ident, item)| (*ident, item.ident.clone()))
             .collect();
 
         let this = Self {
-            ident2id: HashMap::with_capacity(iface_typedefs.len()),
-            typedefs: Arena::with_capacity(iface_typedefs.len()),
+            ident2id: HashMap::with_capacity(iface_typedefs.len() + iface_uses.len()),
+            imported: HashMap::new(),
+            typedefs: Arena::with_capacity(iface_typedefs.len() + iface_uses.len()),
 
             source,
             iface_typedefs,
+            iface_uses,
+            ident2span,
+            dependencies: HashMap::new(),
+            skip: None,
+            source_id: 0,
         };
 
         let rest = RestInterface {
@@ -56,6 +115,51 @@ impl<'a> Resolver<'a> {
         (this, rest)
     }
 
+    /// Makes the types of the given interfaces available to `use` items.
+    #[must_use]
+    pub fn with_dependencies(
+        mut self,
+        dependencies: impl IntoIterator<Item = &'a Interface>,
+    ) -> Self {
+        self.dependencies.extend(
+            dependencies
+                .into_iter()
+                .map(|iface| (iface.ident.as_str(), iface)),
+        );
+
+        self
+    }
+
+    /// Drops functions and resource methods matching `skip` from the resolved interface.
+    ///
+    /// Functions are matched by their name, resource methods as `resource::method`.
+    /// Skipped functions are still type-checked, types only they use are not reported as unused.
+    #[must_use]
+    pub fn with_skip(mut self, skip: &'a dyn Fn(&str) -> bool) -> Self {
+        self.skip = Some(skip);
+
+        self
+    }
+
+    /// Sets the index of the resolved file in the [`crate::SourceMap`] the resolved spans point into.
+    #[must_use]
+    pub fn with_source_id(mut self, source_id: usize) -> Self {
+        self.source_id = source_id;
+
+        self
+    }
+
+    fn span(&self, span: &Span) -> Option<crate::Span> {
+        Some(crate::Span {
+            source: self.source_id,
+            range: span.clone(),
+        })
+    }
+
+    fn is_skipped(&self, name: &str) -> bool {
+        self.skip.is_some_and(|skip| skip(name))
+    }
+
     fn read_span(&self, span: &Span) -> &'a str {
         &self.source[span.clone()]
     }
@@ -87,6 +191,10 @@ impl<'a> Resolver<'a> {
 
         let docs = self.resolve_docs(&typedef.docs);
 
+        if let parse::InterfaceItemInner::Resource(methods) = &typedef.inner {
+            return self.resolve_resource(ident, docs, &typedef.ident, methods);
+        }
+
         let kind = match &typedef.inner {
             parse::InterfaceItemInner::Alias(ty) => {
                 let ty = self.resolve_type(ty)?;
@@ -105,6 +213,7 @@ impl<'a> Resolver<'a> {
                             docs,
                             id: ident,
                             ty,
+                            span: self.span(&field.ident),
                         })
                     })
                     .transponse_result::<Vec<_>, _>()?;
@@ -116,7 +225,11 @@ impl<'a> Resolver<'a> {
                     let docs = self.resolve_docs(&field.docs);
                     let ident = self.resolve_ident(&field.ident).to_string();
 
-                    FlagsField { docs, id: ident }
+                    FlagsField {
+                        docs,
+                        id: ident,
+                        span: self.span(&field.ident),
+                    }
                 });
 
                 TypeDefKind::Flags(fields.collect())
@@ -137,6 +250,7 @@ impl<'a> Resolver<'a> {
                             docs,
                             id: ident,
                             ty,
+                            span: self.span(&case.ident),
                         })
                     })
                     .transponse_result::<Vec<_>, _>()?;
@@ -148,7 +262,11 @@ impl<'a> Resolver<'a> {
                     let docs = self.resolve_docs(&case.docs);
                     let ident = self.resolve_ident(&case.ident).to_string();
 
-                    EnumCase { docs, id: ident }
+                    EnumCase {
+                        docs,
+                        id: ident,
+                        span: self.span(&case.ident),
+                    }
                 });
 
                 TypeDefKind::Enum(cases.collect())
@@ -160,27 +278,29 @@ impl<'a> Resolver<'a> {
                         let docs = self.resolve_docs(&case.docs);
                         let ty = self.resolve_type(&case.ty)?;
 
-                        Ok(UnionCase { docs, ty })
+                        Ok(UnionCase {
+                            docs,
+                            ty,
+                            span: self.span(&case.span),
+                        })
                     })
                     .transponse_result::<Vec<_>, _>()?;
 
                 TypeDefKind::Union(inner)
             }
-            parse::InterfaceItemInner::Resource(methods) => {
-                let functions = methods
-                    .iter()
-                    .map(|method| self.resolve_func(&method.docs, &method.ident, &method.inner))
-                    .transponse_result::<Vec<_>, _>()?;
-
-                TypeDefKind::Resource(functions)
+            parse::InterfaceItemInner::Func(_)
+            | parse::InterfaceItemInner::Resource(_)
+            | parse::InterfaceItemInner::Event(_)
+            | parse::InterfaceItemInner::Use(_) => {
+                unreachable!()
             }
-            parse::InterfaceItemInner::Func(_) => unreachable!(),
         };
 
         let id = self.typedefs.alloc(TypeDef {
             docs,
             ident: ident.to_string(),
             kind,
+            span: self.span(&typedef.ident),
         });
         self.ident2id.insert(ident, id);
         self.iface_typedefs.remove(ident);
@@ -188,6 +308,57 @@ impl<'a> Resolver<'a> {
         Ok(id)
     }
 
+    /// Resolves a resource, which is defined before its functions so they can refer to it.
+    fn resolve_resource(
+        &mut self,
+        ident: &'a str,
+        docs: String,
+        span: &Span,
+        methods: &[parse::Method],
+    ) -> Result<Id<TypeDef>> {
+        let id = self.typedefs.alloc(TypeDef {
+            docs,
+            ident: ident.to_string(),
+            kind: TypeDefKind::Resource(Vec::new()),
+            span: self.span(span),
+        });
+        self.ident2id.insert(ident, id);
+        self.iface_typedefs.remove(ident);
+
+        let mut names: HashMap<&str, &Span> = HashMap::new();
+        let mut functions = Vec::new();
+
+        for method in methods {
+            let name = self.resolve_ident(&method.ident);
+
+            if RESERVED_RESOURCE_FUNCTIONS.contains(&name) {
+                return Err(Error::reserved_name(method.ident.clone(), name));
+            }
+
+            if let Some(previous) = names.insert(name, &method.ident) {
+                return Err(Error::already_defined(
+                    method.ident.clone(),
+                    previous.clone(),
+                ));
+            }
+
+            let mut func =
+                self.resolve_func(&method.docs, &method.ident, &method.inner, method.kind)?;
+
+            if method.kind == FunctionKind::Constructor {
+                func.result = Some(FunctionResult::Anon(Type::Id(id)));
+            }
+
+            if !self.is_skipped(&format!("{ident}::{}", func.id)) {
+                functions.push(func);
+            }
+        }
+
+        self.typedefs[id].kind = TypeDefKind::Resource(functions);
+
+        Ok(id)
+    }
+
     fn resolve_type(&mut self, ty: &parse::Type) -> Result<Type> {
         let ty = match ty {
             parse::Type::Bool => Type::Bool,
@@ -225,40 +396,298 @@ impl<'a> Resolver<'a> {
                     err: err.map(Box::new),
                 }
             }
-            parse::Type::Id(span) => {
-                let ident = self.resolve_ident(span);
+            parse::Type::Own(span) => Type::Own(self.resolve_resource_id(span)?),
+            parse::Type::Borrow(span) => Type::Borrow(self.resolve_resource_id(span)?),
+            parse::Type::Id(span) => Type::Id(self.resolve_id(span)?),
+        };
+
+        Ok(ty)
+    }
+
+    fn resolve_id(&mut self, span: &Span) -> Result<Id<TypeDef>> {
+        let ident = self.resolve_ident(span);
+
+        if let Some(id) = self.ident2id.get(ident) {
+            Ok(*id)
+        } else if let Some(typedef) = self.iface_typedefs.get(ident) {
+            self.resolve_typedef(&typedef.clone()) // TODO: avoid clone
+        } else if let Some(used) = self.iface_uses.remove(ident) {
+            self.resolve_use(&used)
+        } else {
+            let expected = lex::Token::TYPE_KEYWORD
+                .iter()
+                .map(lex::Token::as_str)
+                .chain(self.iface_typedefs.keys().map(|str| &**str))
+                .chain(self.iface_uses.keys().map(|str| &**str));
+
+            let suggestions = find_similar(expected, ident);
+
+            if suggestions.is_empty() {
+                Err(Error::not_defined(span.clone()))
+            } else {
+                Err(Error::not_defined_with_help(
+                    span.clone(),
+                    format!("Did you mean \"{}\"?", print_list(suggestions)),
+                ))
+            }
+        }
+    }
+
+    /// Resolves the resource an `own` or `borrow` handle refers to.
+    fn resolve_resource_id(&mut self, span: &Span) -> Result<Id<TypeDef>> {
+        let id = self.resolve_id(span)?;
+
+        if let TypeDefKind::Resource(_) = self.typedefs[id].kind {
+            Ok(id)
+        } else {
+            Err(Error::not_a_resource(span.clone()))
+        }
+    }
+
+    fn resolve_use(&mut self, used: &UsedName) -> Result<Id<TypeDef>> {
+        let from = self.resolve_ident(&used.from);
+
+        let iface = *self.dependencies.get(from).ok_or_else(|| {
+            let suggestions = find_similar(self.dependencies.keys(), from);
+
+            if suggestions.is_empty() {
+                Error::not_defined(used.from.clone())
+            } else {
+                Error::not_defined_with_help(
+                    used.from.clone(),
+                    format!("Did you mean \"{}\"?", print_list(suggestions)),
+                )
+            }
+        })?;
 
-                if let Some(id) = self.ident2id.get(ident) {
-                    Type::Id(*id)
+        let ident = self.resolve_ident(&used.ident);
+
+        let (id, _) = iface
+            .typedefs
+            .iter()
+            .find(|(_, typedef)| typedef.ident == ident)
+            .ok_or_else(|| {
+                let expected = iface.typedefs.iter().map(|(_, typedef)| &typedef.ident);
+
+                let suggestions = find_similar(expected, ident);
+
+                if suggestions.is_empty() {
+                    Error::not_defined(used.ident.clone())
                 } else {
-                    let typedef = self.iface_typedefs.get(ident).ok_or_else(|| {
-                        let expected = lex::Token::TYPE_KEYWORD
-                            .iter()
-                            .map(lex::Token::as_str)
-                            .chain(self.iface_typedefs.keys().map(|str| &**str));
-
-                        let suggestions = find_similar(expected, ident);
-
-                        if suggestions.is_empty() {
-                            Error::not_defined(span.clone())
-                        } else {
-                            Error::not_defined_with_help(
-                                span.clone(),
-                                format!("Did you mean \"{}\"?", print_list(suggestions)),
-                            )
-                        }
-                    })?;
+                    Error::not_defined_with_help(
+                        used.ident.clone(),
+                        format!("Did you mean \"{}\"?", print_list(suggestions)),
+                    )
+                }
+            })?;
+
+        let local = self.resolve_ident(&used.local);
+
+        let id = self.import_typedef(iface, id, Some(local), &used.local)?;
+        self.ident2id.insert(local, id);
+
+        Ok(id)
+    }
 
-                    let id = self.resolve_typedef(&typedef.clone())?; // TODO: avoid clone
+    /// Copies a type definition from a dependency into this interface's arena.
+    ///
+    /// Types the definition refers to are imported as well, under their original names.
+    fn import_typedef(
+        &mut self,
+        iface: &'a Interface,
+        id: Id<TypeDef>,
+        alias: Option<&'a str>,
+        location: &Span,
+    ) -> Result<Id<TypeDef>> {
+        if let Some(id) = self.imported.get(&(iface.ident.as_str(), id)) {
+            return Ok(*id);
+        }
+
+        let typedef = &iface.typedefs[id];
+        let ident = alias.unwrap_or(typedef.ident.as_str());
+
+        if alias.is_none() {
+            // the name is free if it's not taken at all, or if it is taken by a `use` of this very type
+            let taken_by_use = self.iface_uses.get(ident).is_some_and(|used| {
+                self.resolve_ident(&used.from) != iface.ident
+                    || self.resolve_ident(&used.ident) != typedef.ident
+            });
+
+            if taken_by_use
+                || self.ident2id.contains_key(ident)
+                || self.iface_typedefs.contains_key(ident)
+            {
+                return Err(Error::import_conflict(location.clone(), ident));
+            }
+        }
+
+        let kind = match &typedef.kind {
+            TypeDefKind::Alias(ty) => TypeDefKind::Alias(self.import_type(iface, ty, location)?),
+            TypeDefKind::Record(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        Ok(RecordField {
+                            docs: field.docs.clone(),
+                            id: field.id.clone(),
+                            ty: self.import_type(iface, &field.ty, location)?,
+                            span: field.span.clone(),
+                        })
+                    })
+                    .transponse_result::<Vec<_>, _>()?;
+
+                TypeDefKind::Record(fields)
+            }
+            TypeDefKind::Flags(fields) => TypeDefKind::Flags(fields.clone()),
+            TypeDefKind::Variant(cases) => {
+                let cases = cases
+                    .iter()
+                    .map(|case| {
+                        let ty = case
+                            .ty
+                            .as_ref()
+                            .map(|ty| self.import_type(iface, ty, location))
+                            .transpose()?;
+
+                        Ok(VariantCase {
+                            docs: case.docs.clone(),
+                            id: case.id.clone(),
+                            ty,
+                            span: case.span.clone(),
+                        })
+                    })
+                    .transponse_result::<Vec<_>, _>()?;
+
+                TypeDefKind::Variant(cases)
+            }
+            TypeDefKind::Enum(cases) => TypeDefKind::Enum(cases.clone()),
+            TypeDefKind::Union(cases) => {
+                let cases = cases
+                    .iter()
+                    .map(|case| {
+                        Ok(UnionCase {
+                            docs: case.docs.clone(),
+                            ty: self.import_type(iface, &case.ty, location)?,
+                            span: case.span.clone(),
+                        })
+                    })
+                    .transponse_result::<Vec<_>, _>()?;
+
+                TypeDefKind::Union(cases)
+            }
+            TypeDefKind::Resource(functions) => {
+                // the resource is defined before its functions, so they can refer to it
+                let new_id = self.typedefs.alloc(TypeDef {
+                    docs: typedef.docs.clone(),
+                    ident: ident.to_string(),
+                    kind: TypeDefKind::Resource(Vec::new()),
+                    span: typedef.span.clone(),
+                });
+                self.imported.insert((iface.ident.as_str(), id), new_id);
+
+                let functions = functions
+                    .iter()
+                    .map(|func| self.import_function(iface, func, location))
+                    .transponse_result::<Vec<_>, _>()?;
+
+                self.typedefs[new_id].kind = TypeDefKind::Resource(functions);
+
+                return Ok(new_id);
+            }
+        };
+
+        let new_id = self.typedefs.alloc(TypeDef {
+            docs: typedef.docs.clone(),
+            ident: ident.to_string(),
+            kind,
+            span: typedef.span.clone(),
+        });
+        self.imported.insert((iface.ident.as_str(), id), new_id);
+
+        Ok(new_id)
+    }
+
+    fn import_type(&mut self, iface: &'a Interface, ty: &Type, location: &Span) -> Result<Type> {
+        let ty = match ty {
+            Type::List(ty) => Type::List(Box::new(self.import_type(iface, ty, location)?)),
+            Type::Option(ty) => Type::Option(Box::new(self.import_type(iface, ty, location)?)),
+            Type::Tuple(types) => {
+                let types = types
+                    .iter()
+                    .map(|ty| self.import_type(iface, ty, location))
+                    .transponse_result::<Vec<_>, _>()?;
+
+                Type::Tuple(types)
+            }
+            Type::Result { ok, err } => {
+                let ok = ok
+                    .as_ref()
+                    .map(|ty| self.import_type(iface, ty, location))
+                    .transpose()?;
+                let err = err
+                    .as_ref()
+                    .map(|ty| self.import_type(iface, ty, location))
+                    .transpose()?;
 
-                    Type::Id(id)
+                Type::Result {
+                    ok: ok.map(Box::new),
+                    err: err.map(Box::new),
                 }
             }
+            Type::Own(id) => Type::Own(self.import_typedef(iface, *id, None, location)?),
+            Type::Borrow(id) => Type::Borrow(self.import_typedef(iface, *id, None, location)?),
+            Type::Id(id) => Type::Id(self.import_typedef(iface, *id, None, location)?),
+            ty => ty.clone(),
         };
 
         Ok(ty)
     }
 
+    fn import_function(
+        &mut self,
+        iface: &'a Interface,
+        func: &Function,
+        location: &Span,
+    ) -> Result<Function> {
+        let params = func
+            .params
+            .iter()
+            .map(|(ident, ty)| Ok((ident.clone(), self.import_type(iface, ty, location)?)))
+            .transponse_result::<Vec<_>, _>()?;
+
+        let result = match &func.result {
+            None => None,
+            Some(FunctionResult::Anon(ty)) => {
+                Some(FunctionResult::Anon(self.import_type(iface, ty, location)?))
+            }
+            Some(FunctionResult::Named(types)) => {
+                let types = types
+                    .iter()
+                    .map(|(ident, ty)| Ok((ident.clone(), self.import_type(iface, ty, location)?)))
+                    .transponse_result::<Vec<_>, _>()?;
+
+                Some(FunctionResult::Named(types))
+            }
+        };
+
+        if result.as_ref().is_some_and(|result| {
+            result
+                .types()
+                .any(|ty| self.contains_borrow(ty, &mut HashSet::new()))
+        }) {
+            return Err(Error::borrowed_result(location.clone()));
+        }
+
+        Ok(Function {
+            docs: func.docs.clone(),
+            id: func.id.clone(),
+            params,
+            result,
+            kind: func.kind,
+            span: func.span.clone(),
+        })
+    }
+
     fn resolve_named_types(
         &mut self,
         named_types: &[(Span, parse::Type)],
@@ -271,15 +700,19 @@ impl<'a> Resolver<'a> {
 
                 Ok((ident, ty))
             })
-            .transponse_result::<Vec<_>, _>()}
+            .transponse_result::<Vec<_>, _>()
+    }
 
     fn resolve_func(
         &mut self,
         docs: &[Span],
         ident: &Span,
         func: &parse::Func,
+        kind: FunctionKind,
     ) -> Result<Function> {
         let docs = self.resolve_docs(docs);
+        let span = self.span(ident);
+        let location = ident;
         let ident = self.resolve_ident(ident).to_string();
 
         let params = self.resolve_named_types(&func.params)?;
@@ -296,14 +729,52 @@ impl<'a> Resolver<'a> {
             }
         };
 
+        if result.as_ref().is_some_and(|result| {
+            result
+                .types()
+                .any(|ty| self.contains_borrow(ty, &mut HashSet::new()))
+        }) {
+            return Err(Error::borrowed_result(location.clone()));
+        }
+
         Ok(Function {
             docs,
             id: ident,
             params,
             result,
+            kind,
+            span,
         })
     }
 
+    /// Whether `ty` contains a borrowed handle, which can only be passed to a function, not returned.
+    fn contains_borrow(&self, ty: &Type, visited: &mut HashSet<Id<TypeDef>>) -> bool {
+        match ty {
+            Type::Borrow(_) => true,
+            Type::List(ty) | Type::Option(ty) => self.contains_borrow(ty, visited),
+            Type::Tuple(types) => types.iter().any(|ty| self.contains_borrow(ty, visited)),
+            Type::Result { ok, err } => ok
+                .iter()
+                .chain(err)
+                .any(|ty| self.contains_borrow(ty, visited)),
+            Type::Id(id) if visited.insert(*id) => match &self.typedefs[*id].kind {
+                TypeDefKind::Alias(ty) => self.contains_borrow(ty, visited),
+                TypeDefKind::Record(fields) => fields
+                    .iter()
+                    .any(|field| self.contains_borrow(&field.ty, visited)),
+                TypeDefKind::Variant(cases) => cases
+                    .iter()
+                    .filter_map(|case| case.ty.as_ref())
+                    .any(|ty| self.contains_borrow(ty, visited)),
+                TypeDefKind::Union(cases) => cases
+                    .iter()
+                    .any(|case| self.contains_borrow(&case.ty, visited)),
+                TypeDefKind::Flags(_) | TypeDefKind::Enum(_) | TypeDefKind::Resource(_) => false,
+            },
+            _ => false,
+        }
+    }
+
     fn verify_not_recursive(
         &self,
         ident: Span,
@@ -374,46 +845,124 @@ impl<'a> Resolver<'a> {
         Ok(())
     }
 
+    /// Resolves an interface that other interfaces import types from.
+    ///
+    /// Unlike [`Resolver::resolve`] this resolves every type definition, whether the interface uses it or not.
+    pub fn resolve_as_dependency(mut self, rest_data: RestInterface) -> Result<Interface> {
+        let mut typedefs: Vec<_> = self.iface_typedefs.values().cloned().collect();
+        typedefs.sort_by_key(|item| item.ident.start);
+
+        for typedef in typedefs {
+            self.resolve_typedef(&typedef)?;
+        }
+
+        let mut uses: Vec<_> = self.iface_uses.drain().map(|(_, used)| used).collect();
+        uses.sort_by_key(|used| used.local.start);
+
+        for used in uses {
+            self.resolve_use(&used)?;
+        }
+
+        self.resolve(rest_data)
+    }
+
     pub fn resolve(mut self, rest_data: RestInterface) -> Result<Interface> {
         let docs = self.resolve_docs(&rest_data.docs);
+        let span = self.span(&rest_data.ident);
         let ident = self.resolve_ident(&rest_data.ident).to_string();
 
-        let ident2span = self
-            .iface_typedefs
-            .iter()
-            .map(|(ident, item)| (*ident, item.ident.clone()))
-            .collect::<HashMap<_, _>>();
-
         let mut functions = Vec::new();
+        let mut events = Vec::new();
         for item in rest_data.functions {
-            if let parse::InterfaceItemInner::Func(func) = &item.inner {
-                let func = self.resolve_func(&item.docs, &item.ident, func)?;
-                functions.push(func);
+            match &item.inner {
+                parse::InterfaceItemInner::Func(func) => {
+                    let func = self.resolve_func(
+                        &item.docs,
+                        &item.ident,
+                        func,
+                        FunctionKind::Freestanding,
+                    )?;
+
+                    if !self.is_skipped(&func.id) {
+                        functions.push(func);
+                    }
+                }
+                parse::InterfaceItemInner::Event(payload) => {
+                    let event = Event {
+                        docs: self.resolve_docs(&item.docs),
+                        id: self.resolve_ident(&item.ident).to_string(),
+                        payload: self.resolve_named_types(payload)?,
+                        span: self.span(&item.ident),
+                    };
+
+                    if event
+                        .payload
+                        .iter()
+                        .any(|(_, ty)| self.contains_borrow(ty, &mut HashSet::new()))
+                    {
+                        return Err(Error::borrowed_result(item.ident.clone()));
+                    }
+
+                    if !self.is_skipped(&event.id) {
+                        events.push(event);
+                    }
+                }
+                _ => {}
             }
         }
 
+        // resources with a constructor or static functions are usable even if no function refers to them
+        let mut resources: Vec<_> = self
+            .iface_typedefs
+            .values()
+            .filter(|item| {
+                matches!(&item.inner, parse::InterfaceItemInner::Resource(methods)
+                    if methods.iter().any(|method| !method.kind.is_method()))
+            })
+            .cloned()
+            .collect();
+        resources.sort_by_key(|item| item.ident.start);
+
+        for resource in resources {
+            self.resolve_typedef(&resource)?;
+        }
+
         let mut visiting = HashSet::new();
         let mut valid_types = HashSet::new();
         for (id, typedef) in &self.typedefs {
-            let ident = ident2span[typedef.ident.as_str()].clone();
+            // imported types have already been checked by the interface that defines them
+            let Some(ident) = self.ident2span.get(typedef.ident.as_str()) else {
+                continue;
+            };
 
-            self.verify_not_recursive(ident, id, &mut visiting, &mut valid_types)?;
+            self.verify_not_recursive(ident.clone(), id, &mut visiting, &mut valid_types)?;
         }
 
-        if !self.iface_typedefs.is_empty() {
+        if !self.iface_typedefs.is_empty() || !self.iface_uses.is_empty() {
             // we use `partition_result` here to aggregate all errors before throwing them,
             // this way all errors are reported together instead of one by one.
             self.iface_typedefs
                 .values()
                 .map(|item| Err(Error::unused_type(item.ident.clone())))
-                .transponse_result::<Vec<_>, &parse::InterfaceItem>()?;
+                .chain(self.iface_uses.iter().map(|(ident, used)| {
+                    // a `use` shadowed by a local type never gets resolved
+                    if self.ident2span.contains_key(ident) {
+                        Err(Error::import_conflict(used.local.clone(), *ident))
+                    } else {
+                        Err(Error::unused_type(used.local.clone()))
+                    }
+                }))
+                .transponse_result::<Vec<_>, ()>()?;
         }
 
         Ok(Interface {
             docs,
             ident,
             functions,
+            events,
             typedefs: self.typedefs,
+            span,
+            source_map: SourceMap::default(),
         })
     }
 }
@@ -421,7 +970,6 @@ impl<'a> Resolver<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use logos::Lexer;
     use parse::FromTokens;
 
     #[test]
@@ -432,7 +980,7 @@ mod test {
             /// A function that returns a character
             func return_char() -> char
           }";
-        let mut tokens = Lexer::new(source).spanned().peekable();
+        let mut tokens = parse::Tokens::new(source);
 
         let iface = parse::Interface::parse(&mut tokens)?;
         let (resolver, rest_data) = Resolver::new(source, iface);
@@ -454,7 +1002,7 @@ mod test {
             }
             func fn(x: a) -> nested
           }";
-        let mut tokens = Lexer::new(source).spanned().peekable();
+        let mut tokens = parse::Tokens::new(source);
 
         let iface = parse::Interface::parse(&mut tokens)?;
         let (resolver, rest_data) = Resolver::new(source, iface);
@@ -469,7 +1017,7 @@ mod test {
     fn full() -> Result<()> {
         let source = include_str!("test.wit");
 
-        let mut tokens = Lexer::new(source).spanned().peekable();
+        let mut tokens = parse::Tokens::new(source);
 
         let iface = parse::Interface::parse(&mut tokens)?;
         let (resolver, rest_data) = Resolver::new(source, iface);
@@ -477,4 +1025,262 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn use_() -> Result<()> {
+        let dependency = "interface types {
+            record point {
+                x: u32,
+                y: dimension
+            }
+            type dimension = u32
+            func unused()
+          }";
+        let mut tokens = parse::Tokens::new(dependency);
+
+        let iface = parse::Interface::parse(&mut tokens)?;
+        let (resolver, rest_data) = Resolver::new(dependency, iface);
+        let dependency = resolver.resolve_as_dependency(rest_data)?;
+
+        let source = "interface test {
+            use { point as p } from types
+            func fn(x: p)
+          }";
+        let mut tokens = parse::Tokens::new(source);
+
+        let iface = parse::Interface::parse(&mut tokens)?;
+        let (resolver, rest_data) = Resolver::new(source, iface);
+        let iface = resolver
+            .with_dependencies([&dependency])
+            .resolve(rest_data)?;
+
+        let idents: Vec<_> = iface
+            .typedefs
+            .iter()
+            .map(|(_, typedef)| typedef.ident.as_str())
+            .collect();
+        assert_eq!(idents, ["dimension", "p"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn use_conflict() -> Result<()> {
+        let dependency = "interface types {
+            record point {
+                x: dimension
+            }
+            type dimension = u32
+          }";
+        let mut tokens = parse::Tokens::new(dependency);
+
+        let iface = parse::Interface::parse(&mut tokens)?;
+        let (resolver, rest_data) = Resolver::new(dependency, iface);
+        let dependency = resolver.resolve_as_dependency(rest_data)?;
+
+        let source = "interface test {
+            use { point } from types
+            type dimension = string
+            func fn(x: point, y: dimension)
+          }";
+        let mut tokens = parse::Tokens::new(source);
+
+        let iface = parse::Interface::parse(&mut tokens)?;
+        let (resolver, rest_data) = Resolver::new(source, iface);
+        let err = resolver
+            .with_dependencies([&dependency])
+            .resolve(rest_data)
+            .unwrap_err();
+
+        // errors are aggregated, so the conflict is nested in one or more `Error::Multi`
+        assert!(format!("{err:?}").contains("ImportConflict"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn skip() -> Result<()> {
+        let source = "interface test {
+            record debug-info {
+                verbose: bool
+            }
+            resource handle {
+                func close()
+                func dump() -> debug-info
+            }
+            func open() -> handle
+            func dump(info: debug-info)
+          }";
+        let mut tokens = parse::Tokens::new(source);
+
+        let iface = parse::Interface::parse(&mut tokens)?;
+        let (resolver, rest_data) = Resolver::new(source, iface);
+        let skip = |name: &str| name == "dump" || name == "handle::dump";
+        let iface = resolver.with_skip(&skip).resolve(rest_data)?;
+
+        assert_eq!(
+            iface
+                .functions
+                .iter()
+                .map(|func| func.id.as_str())
+                .collect::<Vec<_>>(),
+            ["open"]
+        );
+
+        let (_, handle) = iface
+            .typedefs
+            .iter()
+            .find(|(_, typedef)| typedef.ident == "handle")
+            .unwrap();
+        let TypeDefKind::Resource(methods) = &handle.kind else {
+            panic!("expected a resource")
+        };
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0].id, "close");
+
+        Ok(())
+    }
+
+    #[test]
+    fn resource_functions() -> Result<()> {
+        let source = "interface test {
+            resource file {
+                constructor(path: string)
+                static func open(path: string) -> result<file>
+                func read() -> list<u8>
+            }
+          }";
+        let mut tokens = parse::Tokens::new(source);
+
+        let iface = parse::Interface::parse(&mut tokens)?;
+        let (resolver, rest_data) = Resolver::new(source, iface);
+        let iface = resolver.resolve(rest_data)?;
+
+        let (id, file) = iface.typedefs.iter().next().unwrap();
+        let TypeDefKind::Resource(functions) = &file.kind else {
+            panic!("expected a resource")
+        };
+
+        assert_eq!(
+            functions
+                .iter()
+                .map(|func| (func.id.as_str(), func.kind))
+                .collect::<Vec<_>>(),
+            [
+                ("constructor", FunctionKind::Constructor),
+                ("open", FunctionKind::Static),
+                ("read", FunctionKind::Method)
+            ]
+        );
+        assert_eq!(
+            functions[0].result,
+            Some(FunctionResult::Anon(Type::Id(id)))
+        );
+        assert_eq!(
+            functions[1].result,
+            Some(FunctionResult::Anon(Type::Result {
+                ok: Some(Box::new(Type::Id(id))),
+                err: None
+            }))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_resource_functions() {
+        let check = |source: &str| {
+            let mut tokens = parse::Tokens::new(source);
+            let iface = parse::Interface::parse(&mut tokens).unwrap();
+            let (resolver, rest_data) = Resolver::new(source, iface);
+
+            resolver.resolve(rest_data)
+        };
+
+        for name in RESERVED_RESOURCE_FUNCTIONS {
+            assert!(matches!(
+                check(&format!(
+                    "interface test {{ resource r {{ func {name}() }} func f() -> r }}"
+                )),
+                Err(Error::ReservedName { .. })
+            ));
+        }
+        assert!(matches!(
+            check("interface test { resource r { static func new() } func f() -> r }"),
+            Err(Error::ReservedName { .. })
+        ));
+        assert!(matches!(
+            check(
+                "interface test { resource r { constructor() constructor(a: u8) } func f() -> r }"
+            ),
+            Err(Error::AlreadyDefined { .. })
+        ));
+    }
+
+    #[test]
+    fn handles() {
+        let check = |source: &str| {
+            let mut tokens = parse::Tokens::new(source);
+            let iface = parse::Interface::parse(&mut tokens).unwrap();
+            let (resolver, rest_data) = Resolver::new(source, iface);
+
+            resolver.resolve(rest_data)
+        };
+
+        let iface =
+            check("interface test { resource r {} func f(a: borrow<r>, b: own<r>) -> own<r> }")
+                .unwrap();
+        let (id, _) = iface.typedefs.iter().next().unwrap();
+        assert_eq!(
+            iface.functions[0].params,
+            [
+                ("a".to_string(), Type::Borrow(id)),
+                ("b".to_string(), Type::Own(id))
+            ]
+        );
+        assert_eq!(
+            iface.functions[0].result,
+            Some(FunctionResult::Anon(Type::Own(id)))
+        );
+
+        assert!(matches!(
+            check("interface test { record r { a: u8 } func f(a: own<r>) }"),
+            Err(Error::Multi { errors }) if matches!(errors[..], [Error::NotAResource { .. }])
+        ));
+        assert!(matches!(
+            check("interface test { resource r {} type x = option<borrow<r>> func f() -> x }"),
+            Err(Error::BorrowedResult { .. })
+        ));
+        assert!(matches!(
+            check("interface test { resource r {} event e(a: borrow<r>) }"),
+            Err(Error::BorrowedResult { .. })
+        ));
+    }
+
+    #[test]
+    fn events() -> Result<()> {
+        let source = "interface test {
+            record point {
+                x: u32
+            }
+            /// Emitted when something moved
+            event moved(id: string, to: point)
+            event tick()
+          }";
+        let mut tokens = parse::Tokens::new(source);
+
+        let iface = parse::Interface::parse(&mut tokens)?;
+        let (resolver, rest_data) = Resolver::new(source, iface);
+        let iface = resolver.resolve(rest_data)?;
+
+        assert!(iface.functions.is_empty());
+        assert_eq!(iface.events.len(), 2);
+        assert_eq!(iface.events[0].id, "moved");
+        assert_eq!(iface.events[0].docs, "Emitted when something moved");
+        assert_eq!(iface.events[0].payload[0], ("id".to_string(), Type::String));
+        assert!(matches!(iface.events[0].payload[1].1, Type::Id(_)));
+        assert!(iface.events[1].payload.is_empty());
+
+        Ok(())
+    }
 }
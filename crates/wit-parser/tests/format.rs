@@ -0,0 +1,76 @@
+use std::path::{Path, PathBuf};
+
+fn fixtures() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    for dir in ["../../wit", "tests/package"] {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.extension().is_some_and(|ext| ext == "wit") {
+                paths.push(path);
+            }
+        }
+    }
+
+    paths.sort();
+    paths
+}
+
+/// Resolves `input` as if it was stored at `path` and serializes the result,
+/// the serialized form leaves out spans since those change when formatting.
+fn resolve(input: &str, path: &Path) -> miette::Result<serde_json::Value> {
+    let document = wit_parser::parse_and_resolve_document_source(input, path, |_| false)?;
+
+    Ok(serde_json::to_value(document).unwrap())
+}
+
+#[test]
+fn formatting_keeps_the_resolved_interface() {
+    for path in fixtures() {
+        let input = std::fs::read_to_string(&path).unwrap();
+        let formatted = wit_parser::format(&input).unwrap();
+
+        // some package members only resolve as dependencies of other documents
+        let Ok(expected) = resolve(&input, &path) else {
+            continue;
+        };
+
+        assert_eq!(
+            expected,
+            resolve(&formatted, &path).unwrap_or_else(|err| panic!("{err:?}")),
+            "formatting {} changed its meaning",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn formatting_is_idempotent() {
+    for path in fixtures() {
+        let input = std::fs::read_to_string(&path).unwrap();
+        let formatted = wit_parser::format(&input).unwrap();
+
+        assert_eq!(
+            formatted,
+            wit_parser::format(&formatted).unwrap(),
+            "formatting {} twice gives a different result",
+            path.display()
+        );
+    }
+}
+
+/// The same check as `tauri-bindgen fmt --check`.
+#[test]
+fn fixtures_are_formatted() {
+    for path in fixtures() {
+        let input = std::fs::read_to_string(&path).unwrap();
+
+        assert_eq!(
+            wit_parser::format(&input).unwrap(),
+            input,
+            "{} is not formatted",
+            path.display()
+        );
+    }
+}
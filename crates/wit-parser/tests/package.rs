@@ -0,0 +1,162 @@
+use wit_parser::*;
+
+#[test]
+fn resolves_types_from_sibling_files() -> miette::Result<()> {
+    let iface = parse_and_resolve_file("tests/package/geometry.wit", |_| false)?;
+
+    let mut idents: Vec<_> = iface
+        .typedefs
+        .iter()
+        .map(|(_, typedef)| typedef.ident.as_str())
+        .collect();
+    idents.sort_unstable();
+
+    assert_eq!(idents, ["extent", "point", "rect", "unit"]);
+    assert_eq!(iface.functions.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn rejects_cyclic_dependencies() {
+    let err = parse_and_resolve_file("tests/package-cycle/a.wit", |_| false).unwrap_err();
+
+    assert_eq!(
+        err.code().map(|code| code.to_string()).as_deref(),
+        Some("wit_parser::cyclic_dependency")
+    );
+}
+
+#[test]
+fn ignores_unrelated_broken_files() -> miette::Result<()> {
+    let iface = parse_and_resolve_file("tests/package-broken/main.wit", |_| false)?;
+
+    assert_eq!(iface.functions.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn reports_broken_files_when_interface_is_missing() {
+    let err = parse_and_resolve_file("tests/package-broken/missing.wit", |_| false).unwrap_err();
+
+    let source = err.source_code().unwrap();
+    let name = source
+        .read_span(&(0..0).into(), 0, 0)
+        .unwrap()
+        .name()
+        .map(ToString::to_string);
+    assert!(name.unwrap().ends_with("broken.wit"));
+}
+
+#[test]
+fn reports_unknown_interfaces() {
+    let source = "interface foo {
+            use { bar } from baz
+            func f(x: bar)
+        }";
+    let err = parse_and_resolve_str(source, |_| false).unwrap_err();
+
+    // errors are reported together, this is the only one
+    let related: Vec<_> = err.related().unwrap().collect();
+    assert_eq!(related.len(), 1);
+    assert_eq!(
+        related[0].code().map(|code| code.to_string()).as_deref(),
+        Some("wit_parser::not_defined")
+    );
+
+    let labels: Vec<_> = related[0].labels().unwrap().collect();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].offset(), source.find("baz").unwrap());
+    assert_eq!(labels[0].len(), "baz".len());
+}
+
+#[test]
+fn resolves_worlds() -> miette::Result<()> {
+    let document = parse_and_resolve_document_file("tests/package/app.wit", |_| false)?;
+
+    assert_eq!(document.interfaces.len(), 1);
+    assert_eq!(document.worlds.len(), 1);
+    assert_eq!(document.worlds[0].docs, "The application");
+
+    let imports: Vec<_> = document
+        .select_world(Some("app"))?
+        .into_iter()
+        .map(|iface| iface.ident)
+        .collect();
+    assert_eq!(imports, ["logging", "geometry"]);
+
+    Ok(())
+}
+
+#[test]
+fn resolves_multiple_interfaces() -> miette::Result<()> {
+    let document = parse_and_resolve_document_str(
+        "interface a {
+            record foo {
+                x: u32
+            }
+        }
+        interface b {
+            use { foo } from a
+            func f(x: foo)
+        }",
+        |_| false,
+    )?;
+
+    let idents: Vec<_> = document
+        .select_world(None)?
+        .into_iter()
+        .map(|iface| iface.ident)
+        .collect();
+    assert_eq!(idents, ["a", "b"]);
+
+    Ok(())
+}
+
+#[test]
+fn requires_world_selection() -> miette::Result<()> {
+    let document = parse_and_resolve_document_str(
+        "interface a {}
+        world foo {
+            import a
+        }
+        world bar {
+            import a
+        }",
+        |_| false,
+    )?;
+
+    assert!(document.clone().select_world(None).is_err());
+    assert!(document.clone().select_world(Some("baz")).is_err());
+    assert_eq!(document.select_world(Some("bar"))?.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn keeps_spans_of_imported_types() -> miette::Result<()> {
+    let iface = parse_and_resolve_file("tests/package/geometry.wit", |_| false)?;
+
+    let (_, point) = iface
+        .typedefs
+        .iter()
+        .find(|(_, typedef)| typedef.ident == "point")
+        .unwrap();
+    let span = point.span.as_ref().unwrap();
+
+    assert!(iface.source_map.name(span).unwrap().ends_with("shapes.wit"));
+    assert_eq!(iface.source_map.snippet(span), Some("point"));
+
+    let area = &iface.functions[0];
+    let span = area.span.as_ref().unwrap();
+
+    assert!(iface
+        .source_map
+        .name(span)
+        .unwrap()
+        .ends_with("geometry.wit"));
+    assert_eq!(iface.source_map.snippet(span), Some("area"));
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ mod completions;
 mod logger;
 
 use clap::{ArgAction, Parser};
-use miette::{bail, IntoDiagnostic, Result, WrapErr};
+use miette::{bail, IntoDiagnostic, NamedSource, Result, WrapErr};
 use std::{
     collections::HashSet,
     path::{Path, PathBuf},
@@ -33,6 +33,37 @@ enum Command {
     Check {
         #[clap(flatten)]
         world: WorldOpt,
+        /// Also warn about bad practices, like undocumented functions or identifiers that aren't kebab-case.
+        #[clap(long)]
+        lint: bool,
+        /// Lints to turn off.
+        #[clap(long, value_name = "LINT", requires = "lint")]
+        allow: Vec<wit_parser::lint::Lint>,
+        /// Lints to report as errors.
+        #[clap(long, value_name = "LINT", requires = "lint")]
+        deny: Vec<wit_parser::lint::Lint>,
+    },
+    /// Format definition files in the canonical style.
+    Fmt {
+        /// The files to format.
+        #[clap(required = true)]
+        files: Vec<PathBuf>,
+        /// Don't write the files, but fail if any of them isn't formatted.
+        #[clap(long)]
+        check: bool,
+    },
+    /// Compare two versions of a definition file and report changes that break the wire format.
+    Diff {
+        /// The previous version of the definition file.
+        old: PathBuf,
+        /// The new version of the definition file.
+        new: PathBuf,
+        /// Name of the world to compare, in both versions.
+        #[clap(long)]
+        world: Option<String>,
+        /// How to print the changes.
+        #[clap(long, value_enum, default_value_t = DiffFormat::Text)]
+        format: DiffFormat,
     },
     /// Generator for creating bindings that are exposed to the WebView.
     Host(HostGenerator),
@@ -59,6 +90,14 @@ enum Command {
     },
 }
 
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum DiffFormat {
+    /// One line per change.
+    Text,
+    /// A JSON array of changes.
+    Json,
+}
+
 #[derive(Debug, Parser)]
 struct HostGenerator {
     #[clap(flatten)]
@@ -97,9 +136,14 @@ struct WorldOpt {
     // #[clap(value_name = "DOCUMENT", value_parser = parse_interface)]
     /// Generate bindings for the WIT document.
     wit: PathBuf,
-    /// Names of functions to skip generating bindings for.
+    /// Names of functions to skip generating bindings for, resource methods are named `resource::method`.
     #[clap(long)]
     skip: Vec<String>,
+    /// Name of the world to generate bindings for.
+    ///
+    /// Defaults to the only world defined by the document, or all interfaces if it doesn't define any.
+    #[clap(long)]
+    world: Option<String>,
 }
 
 #[derive(Debug, Parser, Clone)]
@@ -116,6 +160,7 @@ struct Common {
 fn main() {
     if let Err(err) = run() {
         log::error!("{err:?}");
+        std::process::exit(1);
     }
 }
 
@@ -128,35 +173,60 @@ fn run() -> Result<()> {
 
     let out_dir = &opt.common.out_dir.unwrap_or_default();
     match opt.cmd {
-        Command::Check { world } => check_interface(world)?,
+        Command::Check {
+            world,
+            lint,
+            allow,
+            deny,
+        } => {
+            let lints = lint.then(|| {
+                let config = allow
+                    .into_iter()
+                    .fold(wit_parser::lint::Config::default(), |config, lint| {
+                        config.allow(lint)
+                    });
+
+                deny.into_iter()
+                    .fold(config, |config, lint| config.deny(lint))
+            });
+
+            check_interface(world, lints)?;
+        }
+        Command::Fmt { files, check } => format_files(&files, check)?,
+        Command::Diff {
+            old,
+            new,
+            world,
+            format,
+        } => diff(&old, &new, world.as_deref(), format)?,
         Command::Host(HostGenerator { builder, world, .. }) => {
-            let (path, contents) = gen_interface(builder, world)?;
-
-            write_file(out_dir, &path, &contents)?;
+            for (path, contents) in gen_world(builder, world)? {
+                write_file(out_dir, &path, &contents)?;
+            }
         }
         Command::Guest(GuestGenerator::Rust { builder, world, .. }) => {
-            let (path, contents) = gen_interface(builder, world)?;
-
-            write_file(out_dir, &path, &contents)?;
+            for (path, contents) in gen_world(builder, world)? {
+                write_file(out_dir, &path, &contents)?;
+            }
         }
         Command::Guest(GuestGenerator::Javascript { builder, world, .. }) => {
-            let (path, contents) = gen_interface(builder, world)?;
-
-            write_file(out_dir, &path, &contents)?;
+            for (path, contents) in gen_world(builder, world)? {
+                write_file(out_dir, &path, &contents)?;
+            }
         }
         Command::Guest(GuestGenerator::Typescript { builder, world, .. }) => {
-            let (path, contents) = gen_interface(builder, world)?;
-
-            write_file(out_dir, &path, &contents)?;
+            for (path, contents) in gen_world(builder, world)? {
+                write_file(out_dir, &path, &contents)?;
+            }
         }
         Command::Completions(opts) => {
             completions::run(&opts)?;
         }
         #[cfg(feature = "unstable")]
         Command::Markdown { builder, world } => {
-            let (path, contents) = gen_interface(builder, world)?;
-
-            write_file(out_dir, &path, &contents)?;
+            for (path, contents) in gen_world(builder, world)? {
+                write_file(out_dir, &path, &contents)?;
+            }
         }
         #[cfg(feature = "unstable")]
         Command::Json { world, pretty } => {
@@ -167,13 +237,14 @@ fn run() -> Result<()> {
             let skipset: HashSet<String, std::collections::hash_map::RandomState> =
                 world.skip.into_iter().collect();
 
-            let iface = wit_parser::parse_and_resolve_file(&world.wit, |t| skipset.contains(t))?;
+            let document =
+                wit_parser::parse_and_resolve_document_file(&world.wit, |t| skipset.contains(t))?;
 
             let stdout = std::io::stdout().lock();
             if pretty {
-                serde_json::to_writer_pretty(stdout, &iface).into_diagnostic()?;
+                serde_json::to_writer_pretty(stdout, &document).into_diagnostic()?;
             } else {
-                serde_json::to_writer(stdout, &iface).into_diagnostic()?;
+                serde_json::to_writer(stdout, &document).into_diagnostic()?;
             }
             println!(); // print a newline for formatting
         }
@@ -200,9 +271,9 @@ fn write_file(out_dir: &Path, path: &Path, contents: &str) -> Result<()> {
     Ok(())
 }
 
-fn gen_interface<B>(builder: B, opts: WorldOpt) -> Result<(PathBuf, String)>
+fn gen_world<B>(builder: B, opts: WorldOpt) -> Result<Vec<(PathBuf, String)>>
 where
-    B: GeneratorBuilder,
+    B: GeneratorBuilder + Clone,
 {
     if !opts.wit.is_file() {
         bail!("wit file `{}` does not exist", opts.wit.display());
@@ -211,14 +282,20 @@ where
     let skipset: HashSet<String, std::collections::hash_map::RandomState> =
         opts.skip.into_iter().collect();
 
-    let iface = wit_parser::parse_and_resolve_file(&opts.wit, |t| skipset.contains(t))?;
+    let document = wit_parser::parse_and_resolve_document_file(&opts.wit, |t| skipset.contains(t))?;
 
-    let mut gen = builder.build(iface);
+    document
+        .select_world(opts.world.as_deref())?
+        .into_iter()
+        .map(|iface| {
+            tauri_bindgen_core::check_interface(&iface)?;
 
-    Ok(gen.to_file())
+            Ok(builder.clone().build(iface).to_file())
+        })
+        .collect()
 }
 
-fn check_interface(opts: WorldOpt) -> Result<()> {
+fn check_interface(opts: WorldOpt, lints: Option<wit_parser::lint::Config>) -> Result<()> {
     log::info!(action = "Checking"; "{}", opts.wit.to_string_lossy());
 
     if !opts.wit.is_file() {
@@ -228,7 +305,112 @@ fn check_interface(opts: WorldOpt) -> Result<()> {
     let skipset: HashSet<String, std::collections::hash_map::RandomState> =
         opts.skip.into_iter().collect();
 
-    wit_parser::parse_and_resolve_file(&opts.wit, |t| skipset.contains(t))?;
+    let document = wit_parser::parse_and_resolve_document_file(&opts.wit, |t| skipset.contains(t))?;
+
+    let interfaces = if opts.world.is_some() {
+        document.select_world(opts.world.as_deref())?
+    } else {
+        // worlds can import interfaces that are only defined in other files of the package
+        let mut seen = HashSet::new();
+
+        document
+            .interfaces
+            .into_iter()
+            .chain(document.worlds.into_iter().flat_map(|world| world.imports))
+            .filter(|iface| seen.insert(iface.ident.clone()))
+            .collect()
+    };
+
+    for iface in &interfaces {
+        tauri_bindgen_core::check_interface(iface)?;
+    }
+
+    let Some(config) = lints else {
+        return Ok(());
+    };
+
+    let mut denied = 0;
+    for warning in wit_parser::lint::lint(&interfaces, &config) {
+        if warning.severity == miette::Severity::Error {
+            denied += 1;
+            log::error!("{:?}", miette::Report::new(warning));
+        } else {
+            log::warn!("{:?}", miette::Report::new(warning));
+        }
+    }
+
+    if denied > 0 {
+        bail!("{denied} denied lints were triggered");
+    }
+
+    Ok(())
+}
+
+fn format_files(files: &[PathBuf], check: bool) -> Result<()> {
+    let mut unformatted = 0;
+
+    for path in files {
+        let input = std::fs::read_to_string(path)
+            .into_diagnostic()
+            .wrap_err(format!("failed to read {path:?}"))?;
+
+        let output = wit_parser::format(&input).map_err(|err| {
+            err.with_source_code(NamedSource::new(path.to_string_lossy(), input.clone()))
+        })?;
+
+        if output == input {
+            continue;
+        }
+
+        if check {
+            log::warn!("{} is not formatted", path.display());
+            unformatted += 1;
+        } else {
+            log::info!(action = "Formatting"; "{}", path.display());
+            std::fs::write(path, output)
+                .into_diagnostic()
+                .wrap_err(format!("failed to write {path:?}"))?;
+        }
+    }
+
+    if unformatted > 0 {
+        bail!("{unformatted} of {} files are not formatted", files.len());
+    }
+
+    Ok(())
+}
+
+fn diff(old: &Path, new: &Path, world: Option<&str>, format: DiffFormat) -> Result<()> {
+    let resolve = |path: &Path| -> Result<Vec<wit_parser::Interface>> {
+        if !path.is_file() {
+            bail!("wit file `{}` does not exist", path.display());
+        }
+
+        wit_parser::parse_and_resolve_document_file(path, |_| false)?.select_world(world)
+    };
+
+    let changes = tauri_bindgen_core::diff::diff(&resolve(old)?, &resolve(new)?);
+
+    match format {
+        DiffFormat::Text => {
+            for change in &changes {
+                println!("{change}");
+            }
+        }
+        DiffFormat::Json => {
+            serde_json::to_writer_pretty(std::io::stdout().lock(), &changes).into_diagnostic()?;
+            println!(); // print a newline for formatting
+        }
+    }
+
+    let breaking = changes
+        .iter()
+        .filter(|change| change.compatibility == tauri_bindgen_core::diff::Compatibility::Breaking)
+        .count();
+
+    if breaking > 0 {
+        bail!("found {breaking} breaking changes");
+    }
 
     Ok(())
 }
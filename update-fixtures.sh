@@ -1,10 +1,16 @@
 cargo build --features unstable
-for i in wit/*.wit; do target/debug/tauri-bindgen host --tracing --out-dir crates/gen-host/tests/sync --fmt $i; done
-for i in wit/*.wit; do target/debug/tauri-bindgen host --tracing --async --out-dir crates/gen-host/tests/async --fmt $i; done
+for i in wit/*.wit; do target/debug/tauri-bindgen host --out-dir crates/gen-host/tests/sync --fmt $i; done
+for i in wit/*.wit; do target/debug/tauri-bindgen host --async --out-dir crates/gen-host/tests/async --fmt $i; done
+target/debug/tauri-bindgen host --tracing --out-dir crates/gen-host/tests/sync-tracing --fmt wit/resources.wit
+target/debug/tauri-bindgen host --tracing --async --out-dir crates/gen-host/tests/async-tracing --fmt wit/resources.wit
+target/debug/tauri-bindgen host --tracing --manifest --out-dir crates/gen-host/tests/manifest --fmt wit/resources.wit
+target/debug/tauri-bindgen host --tracing --async --context --out-dir crates/gen-host/tests/context --fmt wit/resources.wit
 
 for i in wit/*.wit; do target/debug/tauri-bindgen markdown --out-dir crates/gen-markdown/tests $i; done
 
 for i in wit/*.wit; do target/debug/tauri-bindgen guest rust --out-dir crates/gen-guest-rust/tests --fmt $i; done
+target/debug/tauri-bindgen guest rust --unchecked --out-dir crates/gen-guest-rust/tests/unchecked --fmt wit/resources.wit
+target/debug/tauri-bindgen guest rust --no-std --out-dir crates/gen-guest-rust/tests/no_std --fmt wit/lists.wit
 for i in wit/*.wit; do target/debug/tauri-bindgen guest javascript --out-dir crates/gen-guest-js/tests $i; done
 for i in wit/*.wit; do target/debug/tauri-bindgen guest typescript --out-dir crates/gen-guest-ts/tests $i; done
 # for i in wit/*.wit; do target/debug/tauri-bindgen guest rescript --fmt --out-dir crates/gen-guest-rescript/tests $i; done